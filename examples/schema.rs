@@ -1,22 +1,30 @@
 use std::env::current_dir;
-use std::fs::create_dir_all;
+use std::fs::{create_dir_all, write};
 
-use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
-use group_member_approval_smart_contract::store::contract_state::ContractState;
-use group_member_approval_smart_contract::types::core::msg::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-};
+use cosmwasm_schema::remove_schemas;
+use group_member_approval_smart_contract::types::core::api::contract_api;
 
 fn main() {
     let mut out_dir = current_dir().expect("Could not fetch current directory");
     out_dir.push("schema");
     create_dir_all(&out_dir).expect("Could not create output directory");
     remove_schemas(&out_dir).expect("Could not remove existing schemas in output directory");
-    // Top-level Msg values
-    export_schema(&schema_for!(ExecuteMsg), &out_dir);
-    export_schema(&schema_for!(InstantiateMsg), &out_dir);
-    export_schema(&schema_for!(QueryMsg), &out_dir);
-    export_schema(&schema_for!(MigrateMsg), &out_dir);
-    // Result data
-    export_schema(&schema_for!(ContractState), &out_dir);
+    let api = contract_api().render();
+    // The combined API document, including the response type of each query
+    let path = out_dir.join(concat!(env!("CARGO_PKG_NAME"), ".json"));
+    write(
+        &path,
+        api.to_string().expect("Could not serialize API") + "\n",
+    )
+    .expect("Could not write API schema");
+    println!("Exported {}", path.display());
+    // Individual schemas for each message and query response
+    let raw_dir = out_dir.join("raw");
+    create_dir_all(&raw_dir).expect("Could not create raw output directory");
+    remove_schemas(&raw_dir).expect("Could not remove existing raw schemas");
+    for (file_name, json) in api.to_schema_files().expect("Could not serialize schemas") {
+        let path = raw_dir.join(file_name);
+        write(&path, json + "\n").expect("Could not write schema");
+        println!("Exported {}", path.display());
+    }
 }
@@ -1,10 +1,50 @@
+use crate::execute::acknowledge_group_terms::acknowledge_group_terms;
+use crate::execute::add_hook::add_hook;
+use crate::execute::allow_ibc_connection::allow_ibc_connection;
+use crate::execute::approve_all_current_groups::approve_all_current_groups;
 use crate::execute::approve_group_membership::approve_group_membership;
+use crate::execute::approve_group_policy::approve_group_policy;
+use crate::execute::block_account::block_account;
+use crate::execute::close_ibc_channel::close_ibc_channel;
+use crate::execute::disallow_ibc_connection::disallow_ibc_connection;
+use crate::execute::force_revoke_approval::{force_revoke_approval, force_revoke_policy_approval};
+use crate::execute::publish_group_terms::publish_group_terms;
+use crate::execute::remove_hook::remove_hook;
+use crate::execute::revoke_group_membership::revoke_group_membership;
+use crate::execute::revoke_group_policy::revoke_group_policy;
+use crate::execute::set_attribute_query_mode::set_attribute_query_mode;
+use crate::execute::set_group_member_cap::set_group_member_cap;
+use crate::execute::unblock_account::unblock_account;
+use crate::execute::update_approval_limits::update_approval_limits;
+use crate::ibc::channel::{close_channel, connect_channel, open_channel};
+use crate::ibc::packet::{acknowledge_packet, receive_packet, timeout_packet};
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::migrate::contract_upgrade::contract_upgrade;
+use crate::query::query_approval_events::query_approval_events;
+use crate::query::query_attribute_diagnostics::query_attribute_diagnostics;
+use crate::query::query_blocked_accounts::query_blocked_accounts;
+use crate::query::query_contract_info::query_contract_info;
 use crate::query::query_contract_state::query_contract_state;
+use crate::query::query_group_capacity::query_group_capacity;
+use crate::query::query_group_consent_status::query_group_consent_status;
+use crate::query::query_group_stats::{query_all_group_stats, query_group_stats};
+use crate::query::query_group_terms::{query_group_terms, query_terms_acceptance};
+use crate::query::query_history::query_history;
+use crate::query::query_hooks::query_hooks;
+use crate::query::query_ibc_channels::query_ibc_channels;
+use crate::reply::hook_failure::handle_hook_failure;
+use crate::store::approval_events::ApprovalEventFilter;
+use crate::store::hooks::HOOK_REPLY_ID;
 use crate::types::core::error::ContractError;
 use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    Binary, Deps, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, MessageInfo, Reply, Response,
+};
+use result_extensions::ResultExtensions;
 
 /// The entry point used when an account instantiates a stored code wasm payload of this contract on
 /// the Provenance Blockchain.
@@ -12,14 +52,14 @@ use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Respons
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom instantiation message defined by this contract for creating the initial
-/// configuration used by the contract.
-#[entry_point]
+///   configuration used by the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     env: Env,
@@ -35,15 +75,15 @@ pub fn instantiate(
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom execution message enum defined by this contract to allow multiple different
-/// processes to be defined for the singular execution route entry point allowed by the
-/// cosmwasm framework.
-#[entry_point]
+///   processes to be defined for the singular execution route entry point allowed by the
+///   cosmwasm framework.
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
@@ -51,8 +91,64 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::ApproveGroupMembership { group_id } => {
-            approve_group_membership(deps, env, info, group_id)
+        ExecuteMsg::ApproveGroupMembership {
+            group_id,
+            memo,
+            terms_hash,
+        } => approve_group_membership(deps, env, info, group_id, memo, terms_hash),
+        ExecuteMsg::ApproveGroupPolicy { policy_address } => {
+            approve_group_policy(deps, env, info, policy_address)
+        }
+        ExecuteMsg::ApproveAllCurrentGroups {} => approve_all_current_groups(deps, env, info),
+        ExecuteMsg::AcknowledgeGroupTerms {
+            group_id,
+            terms_hash,
+        } => acknowledge_group_terms(deps, env, info, group_id, terms_hash),
+        ExecuteMsg::RevokeGroupMembership { group_id } => {
+            revoke_group_membership(deps, env, info, group_id)
+        }
+        ExecuteMsg::RevokeGroupPolicy { policy_address } => {
+            revoke_group_policy(deps, env, info, policy_address)
+        }
+        ExecuteMsg::BlockAccount { account, reason } => {
+            block_account(deps, env, info, account, reason)
+        }
+        ExecuteMsg::UnblockAccount { account } => unblock_account(deps, env, info, account),
+        ExecuteMsg::SetGroupMemberCap {
+            group_id,
+            max_members,
+        } => set_group_member_cap(deps, env, info, group_id, max_members),
+        ExecuteMsg::PublishGroupTerms {
+            group_id,
+            uri,
+            content_hash,
+        } => publish_group_terms(deps, env, info, group_id, uri, content_hash),
+        ExecuteMsg::UpdateApprovalLimits { approval_limits } => {
+            update_approval_limits(deps, env, info, approval_limits)
+        }
+        ExecuteMsg::SetAttributeQueryMode { mode } => {
+            set_attribute_query_mode(deps, env, info, mode)
+        }
+        ExecuteMsg::ForceRevokeApproval {
+            account,
+            group_id,
+            reason,
+        } => force_revoke_approval(deps, env, info, account, group_id, reason),
+        ExecuteMsg::ForceRevokePolicyApproval {
+            account,
+            policy_address,
+            reason,
+        } => force_revoke_policy_approval(deps, env, info, account, policy_address, reason),
+        ExecuteMsg::AddHook { contract } => add_hook(deps, env, info, contract),
+        ExecuteMsg::RemoveHook { contract } => remove_hook(deps, env, info, contract),
+        ExecuteMsg::AllowIbcConnection { connection_id } => {
+            allow_ibc_connection(deps, env, info, connection_id)
+        }
+        ExecuteMsg::DisallowIbcConnection { connection_id } => {
+            disallow_ibc_connection(deps, env, info, connection_id)
+        }
+        ExecuteMsg::CloseIbcChannel { channel_id } => {
+            close_ibc_channel(deps, env, info, channel_id)
         }
     }
 }
@@ -63,16 +159,62 @@ pub fn execute(
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successfully defined query entrypoint.
+///   details, as well as blockchain information at the time of the transaction.  Unused by this
+///   function, but required by cosmwasm for successfully defined query entrypoint.
 /// * `msg` A custom query message enum defined by this contract to allow multiple different results
-/// to be determined for this route.
-#[entry_point]
+///   to be determined for this route.
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::QueryContractState {} => query_contract_state(deps),
+        QueryMsg::QueryContractInfo {} => query_contract_info(deps),
+        QueryMsg::QueryHistory { start_after, limit } => query_history(deps, start_after, limit),
+        QueryMsg::QueryGroupStats { group_id } => query_group_stats(deps, group_id),
+        QueryMsg::QueryAllGroupStats { start_after, limit } => {
+            query_all_group_stats(deps, start_after, limit)
+        }
+        QueryMsg::QueryApprovalEvents {
+            account,
+            group_id,
+            min_height,
+            max_height,
+            min_time,
+            max_time,
+            start_after,
+            limit,
+        } => query_approval_events(
+            deps,
+            ApprovalEventFilter {
+                account,
+                group_id,
+                min_height,
+                max_height,
+                min_time,
+                max_time,
+            },
+            start_after,
+            limit,
+        ),
+        QueryMsg::QueryBlockedAccounts { start_after, limit } => {
+            query_blocked_accounts(deps, start_after, limit)
+        }
+        QueryMsg::QueryGroupCapacity { group_id } => query_group_capacity(deps, group_id),
+        QueryMsg::QueryGroupConsentStatus {
+            group_id,
+            page_key,
+            limit,
+        } => query_group_consent_status(deps, group_id, page_key, limit),
+        QueryMsg::QueryGroupTerms { group_id } => query_group_terms(deps, group_id),
+        QueryMsg::QueryTermsAcceptance { account, group_id } => {
+            query_terms_acceptance(deps, account, group_id)
+        }
+        QueryMsg::QueryAttributeDiagnostics { account } => {
+            query_attribute_diagnostics(deps, account)
+        }
+        QueryMsg::QueryHooks {} => query_hooks(deps),
+        QueryMsg::QueryIbcChannels {} => query_ibc_channels(deps),
     }
 }
 
@@ -82,15 +224,142 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successfully defined migration entrypoint.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
 /// * msg` A custom migrate message enum defined by this contract to allow multiple different
-/// results of invoking the migrate endpoint.
-#[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+///   results of invoking the migrate endpoint.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     match msg {
-        MigrateMsg::ContractUpgrade {} => contract_upgrade(deps),
+        MigrateMsg::ContractUpgrade { options } => {
+            contract_upgrade(deps, env, options.unwrap_or_default())
+        }
     }
 }
+
+/// The entry point used when a submessage dispatched by this contract replies.  Only failed hook
+/// notifications are dispatched with a reply.
+///
+/// # Parameters
+///
+/// * `_deps` A dependencies object provided by the cosmwasm framework.  Unused by this function.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function.
+/// * `msg` The reply, identifying the submessage by its id and payload.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        HOOK_REPLY_ID => handle_hook_failure(msg),
+        id => ContractError::ExecuteError {
+            route: "reply".to_string(),
+            message: format!("unknown reply id [{id}]"),
+        }
+        .to_err(),
+    }
+}
+
+/// The entry point used by the IBC module during the first step of a channel handshake with a
+/// counterparty that will receive relayed approvals.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function,
+///   but required by cosmwasm for a successfully defined ibc entrypoint.
+/// * `msg` The channel open message, describing the proposed channel.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    open_channel(deps, msg)
+}
+
+/// The entry point used by the IBC module when a channel handshake completes.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `msg` The channel connect message, describing the connected channel.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    connect_channel(deps, env, msg)
+}
+
+/// The entry point used by the IBC module when a channel is closed.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function,
+///   but required by cosmwasm for a successfully defined ibc entrypoint.
+/// * `msg` The channel close message, describing the closed channel.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    close_channel(deps, msg)
+}
+
+/// The entry point used by the IBC module when a counterparty sends a packet to this contract.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function,
+///   but required by cosmwasm for a successfully defined ibc entrypoint.
+/// * `msg` The received packet.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    receive_packet(deps, msg)
+}
+
+/// The entry point used by the IBC module when a counterparty acknowledges a packet sent by this
+/// contract.
+///
+/// # Parameters
+///
+/// * `_deps` A dependencies object provided by the cosmwasm framework.  Unused by this function.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function.
+/// * `msg` The acknowledgement, along with the original packet.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    acknowledge_packet(msg)
+}
+
+/// The entry point used by the IBC module when a packet sent by this contract times out.
+///
+/// # Parameters
+///
+/// * `_deps` A dependencies object provided by the cosmwasm framework.  Unused by this function.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused by this function.
+/// * `msg` The timed-out packet.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    timeout_packet(msg)
+}
@@ -0,0 +1,349 @@
+use crate::store::approval_events::{record_approval_event, ApprovalAction};
+use crate::store::blocklist::check_account_not_blocked;
+use crate::store::contract_state::get_contract_state;
+use crate::store::group_terms::{
+    check_terms_acknowledged, get_accepted_terms_version, record_accepted_terms,
+};
+use crate::store::history::record_terms_acknowledgement;
+use crate::store::hooks::prepare_hook_messages;
+use crate::store::ibc_channels::prepare_relay_messages;
+use crate::types::core::error::ContractError;
+use crate::types::core::hook::ApprovalHookMsg;
+use crate::util::prov_helpers::{account_has_matching_attribute, parse_group_id_attribute};
+use crate::util::route_helpers::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, SubMsg, Uint64};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Allows a member with an existing approval
+/// of a group to accept the group's most recently published
+/// [terms](crate::store::group_terms::GroupTerms) by their content hash, which brings the approval
+/// up to date without withdrawing it.  This is not a new approval: no attribute is written, and the
+/// group's statistics, the member cap, and the
+/// [ApprovalLimits](crate::store::contract_state::ApprovalLimits) are left untouched, so the
+/// re-approval cooldown does not apply.  Blocked accounts cannot acknowledge terms.  The
+/// acknowledgement is recorded in the
+/// [approval audit trail](crate::store::approval_events::ApprovalEvent) and the contract's
+/// [history log](crate::store::history::HistoryEntry), and each
+/// [registered hook](crate::store::hooks::RegisteredHook) and
+/// [connected IBC channel](crate::store::ibc_channels::ConnectedChannel) is notified with an
+/// [ApprovalHookMsg].
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `group_id` The unique identifier of the approved group whose terms are accepted.
+/// * `terms_hash` The content hash of the group's currently published terms.
+pub fn acknowledge_group_terms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    group_id: Uint64,
+    terms_hash: String,
+) -> Result<Response, ContractError> {
+    check_account_not_blocked(deps.storage, &info.sender)?;
+    check_funds_are_empty(&info)?;
+    let Some(version) = check_terms_acknowledged(deps.storage, group_id, Some(&terms_hash))? else {
+        return ContractError::TermsAcknowledgementError {
+            group_id: group_id.u64(),
+            message: "no terms are published".to_string(),
+        }
+        .to_err();
+    };
+    let previous_version = get_accepted_terms_version(deps.storage, &info.sender, group_id)?;
+    if previous_version == Some(version) {
+        return ContractError::ExecuteError {
+            route: "acknowledge_group_terms".to_string(),
+            message: format!(
+                "version [{version}] of the terms for group with id [{}] has already been accepted by member [{}]",
+                group_id.u64(),
+                info.sender.as_str(),
+            ),
+        }
+        .to_err();
+    }
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = &contract_state.attribute_name;
+    if !account_has_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        attribute_name,
+        contract_state.attribute_query_mode,
+        |attribute| parse_group_id_attribute(attribute, attribute_name) == Some(group_id),
+    )? {
+        return ContractError::ExecuteError {
+            route: "acknowledge_group_terms".to_string(),
+            message: format!(
+                "group with id [{}] has not been approved by member [{}]",
+                group_id.u64(),
+                info.sender.as_str(),
+            ),
+        }
+        .to_err();
+    }
+    record_accepted_terms(deps.storage, &info.sender, group_id, Some(version))?;
+    record_approval_event(
+        deps.storage,
+        &env,
+        &info.sender,
+        group_id,
+        None,
+        ApprovalAction::AcknowledgeTerms,
+        None,
+    )?;
+    record_terms_acknowledgement(
+        deps.storage,
+        &env,
+        &info.sender,
+        group_id,
+        previous_version,
+        version,
+    )?;
+    let notification = ApprovalHookMsg {
+        account: info.sender.to_owned(),
+        group_id,
+        policy_address: None,
+        action: ApprovalAction::AcknowledgeTerms,
+        memo: None,
+        block_height: Uint64::new(env.block.height),
+    };
+    let mut messages = prepare_hook_messages(deps.storage, &notification)?;
+    messages.extend(
+        prepare_relay_messages(deps.storage, &env, &notification)?
+            .into_iter()
+            .map(SubMsg::new),
+    );
+    Response::new()
+        .add_submessages(messages)
+        .add_attribute("action", "acknowledge_group_terms")
+        .add_attribute("account_address", info.sender.as_str())
+        .add_attribute("group_id", group_id.to_string())
+        .add_attribute("terms_version", version.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::contract_state::{get_contract_state, set_contract_state, ApprovalLimits};
+    use crate::store::group_stats::get_group_stats;
+    use crate::store::group_terms::get_terms_acceptance;
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::store::hooks::{add_hook, RegisteredHook};
+    use crate::test::chain_simulator::{ProvenanceSimulator, SimulationError, SimulationResult};
+    use crate::test::test_constants::DEFAULT_CONTRACT_ADMIN;
+    use crate::types::core::error::ContractError;
+    use crate::types::core::hook::ApprovalHookMsg;
+    use crate::types::core::msg::ExecuteMsg;
+    use cosmwasm_std::{Response, Uint64};
+
+    fn publish(content_hash: &str) -> ExecuteMsg {
+        ExecuteMsg::PublishGroupTerms {
+            group_id: Uint64::new(1),
+            uri: "ipfs://terms".to_string(),
+            content_hash: content_hash.to_string(),
+        }
+    }
+
+    fn acknowledge(terms_hash: &str) -> ExecuteMsg {
+        ExecuteMsg::AcknowledgeGroupTerms {
+            group_id: Uint64::new(1),
+            terms_hash: terms_hash.to_string(),
+        }
+    }
+
+    fn expect_contract_error(result: SimulationResult<Response>) -> ContractError {
+        match result.expect_err("the acknowledgement should be rejected") {
+            SimulationError::Contract(e) => e,
+            e => panic!("unexpected error emitted: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_acknowledging_new_terms_does_not_count_as_approval() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member");
+        let mut contract_state =
+            get_contract_state(&simulator.deps.storage).expect("state should load");
+        contract_state.approval_limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(1)),
+            window_blocks: Uint64::new(1000),
+            reapproval_cooldown_blocks: Uint64::new(1000),
+        };
+        set_contract_state(&mut simulator.deps.storage, &contract_state)
+            .expect("state should save");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("abcd"))
+            .expect("the first terms should publish");
+        simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: None,
+                    terms_hash: Some("abcd".to_string()),
+                },
+            )
+            .expect("the member should approve the first terms");
+        assert!(
+            matches!(
+                expect_contract_error(simulator.execute(member.as_str(), acknowledge("abcd"))),
+                ContractError::ExecuteError { .. },
+            ),
+            "accepting the already-accepted version should be rejected",
+        );
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("ef01"))
+            .expect("the second terms should publish");
+        assert!(
+            matches!(
+                expect_contract_error(simulator.execute(member.as_str(), acknowledge("abcd"))),
+                ContractError::TermsAcknowledgementError { group_id: 1, .. },
+            ),
+            "acknowledging superseded terms should be rejected",
+        );
+        simulator
+            .execute(member.as_str(), acknowledge("EF01"))
+            .expect("the new terms should be acknowledged despite the approval limits");
+        let acceptance =
+            get_terms_acceptance(&simulator.deps.storage, &member, Uint64::new(1), true)
+                .expect("the acceptance should load");
+        assert_eq!(
+            (Some(Uint64::new(2)), false),
+            (acceptance.accepted_version, acceptance.outdated),
+            "the approval should be brought up to date with the new terms",
+        );
+        let stats =
+            get_group_stats(&simulator.deps.storage, Uint64::new(1)).expect("stats should load");
+        assert_eq!(
+            (Uint64::new(1), Uint64::new(1)),
+            (stats.current_approvals, stats.total_approvals),
+            "the acknowledgement should not be counted as an approval",
+        );
+        assert_eq!(
+            vec![ApprovalAction::Approve, ApprovalAction::AcknowledgeTerms],
+            get_approval_events(
+                &simulator.deps.storage,
+                &ApprovalEventFilter::default(),
+                None,
+                None
+            )
+            .expect("approval events should load")
+            .into_iter()
+            .map(|event| event.action)
+            .collect::<Vec<ApprovalAction>>(),
+            "the acknowledgement should be recorded as its own event rather than an approval",
+        );
+    }
+
+    #[test]
+    fn test_acknowledgement_is_recorded_in_history_and_notifies_hooks() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member");
+        let hook = simulator.addr("hook");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("abcd"))
+            .expect("the first terms should publish");
+        simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: None,
+                    terms_hash: Some("abcd".to_string()),
+                },
+            )
+            .expect("the member should approve the first terms");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("ef01"))
+            .expect("the second terms should publish");
+        add_hook(
+            &mut simulator.deps.storage,
+            &RegisteredHook {
+                contract: hook.to_owned(),
+                added_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the hook should register");
+        let response = simulator
+            .execute(member.as_str(), acknowledge("ef01"))
+            .expect("the new terms should be acknowledged");
+        assert_eq!(
+            vec![ApprovalHookMsg {
+                account: member.to_owned(),
+                group_id: Uint64::new(1),
+                policy_address: None,
+                action: ApprovalAction::AcknowledgeTerms,
+                memo: None,
+                block_height: Uint64::new(simulator.env.block.height),
+            }
+            .into_cosmos_msg(hook.as_str())
+            .expect("the hook message should build")],
+            response
+                .messages
+                .into_iter()
+                .map(|message| message.msg)
+                .collect::<Vec<_>>(),
+            "the registered hook should be notified of the acknowledgement",
+        );
+        assert_eq!(
+            Some(HistoryEvent::TermsAcknowledgement {
+                account: member,
+                group_id: Uint64::new(1),
+                previous_version: Some(Uint64::new(1)),
+                terms_version: Uint64::new(2),
+            }),
+            get_history_entries(&simulator.deps.storage, None, None)
+                .expect("history should load")
+                .pop()
+                .map(|entry| entry.event),
+            "the acknowledgement should be recorded in the history log",
+        );
+    }
+
+    #[test]
+    fn test_blocked_account_cannot_acknowledge_terms() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("abcd"))
+            .expect("the terms should publish");
+        simulator
+            .execute(
+                DEFAULT_CONTRACT_ADMIN,
+                ExecuteMsg::BlockAccount {
+                    account: member.to_string(),
+                    reason: "offboarded".to_string(),
+                },
+            )
+            .expect("the admin should block the member");
+        assert!(
+            matches!(
+                expect_contract_error(simulator.execute(member.as_str(), acknowledge("abcd"))),
+                ContractError::BlockedAccountError { .. },
+            ),
+            "a blocked account should not acknowledge terms",
+        );
+    }
+
+    #[test]
+    fn test_rejection_without_approval() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("abcd"))
+            .expect("the terms should publish");
+        assert!(
+            matches!(
+                expect_contract_error(simulator.execute(member.as_str(), acknowledge("abcd"))),
+                ContractError::ExecuteError { .. },
+            ),
+            "terms should not be acknowledged for a group the member has not approved",
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::store::history::record_admin_action;
+use crate::store::hooks::{add_hook as store_hook, RegisteredHook};
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Registers a contract to be notified with
+/// an [ApprovalHookMsg](crate::types::core::hook::ApprovalHookMsg) each time an account approves
+/// or withdraws its approval of a group.  The address must belong to an instantiated contract, as
+/// verified by a contract info query.  A contract can only be registered once, and no more than
+/// [MAX_HOOKS](crate::store::hooks::MAX_HOOKS) hooks may be registered.  Only the contract admin may
+/// invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `contract` The bech32 address of the contract to notify.
+pub fn add_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    deps.querier
+        .query_wasm_contract_info(contract.as_str())
+        .map_err(|e| ContractError::ExecuteError {
+            route: "add_hook".to_string(),
+            message: format!("[{contract}] is not a contract: {e}"),
+        })?;
+    store_hook(
+        deps.storage,
+        &RegisteredHook {
+            contract: contract.to_owned(),
+            added_at_height: Uint64::new(env.block.height),
+        },
+    )?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "add_hook",
+        None,
+        Some(contract.to_string()),
+    )?;
+    Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook_address", contract.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::add_hook::add_hook;
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::store::hooks::{get_hooks, RegisteredHook, MAX_HOOKS};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{
+        coins, to_json_binary, Addr, ContractInfoResponse, ContractResult, SystemError,
+        SystemResult, Uint64, WasmQuery,
+    };
+    use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+
+    fn mock_contract_info(querier: &mut MockProvenanceQuerier) {
+        querier.mock_querier.update_wasm(|query| match query {
+            WasmQuery::ContractInfo { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&ContractInfoResponse::new(
+                    1,
+                    Addr::unchecked("creator"),
+                    None,
+                    false,
+                    None,
+                ))
+                .expect("the contract info should serialize"),
+            )),
+            query => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: format!("{query:?}"),
+            }),
+        });
+    }
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("hook").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &coins(1, "nhash"));
+        let err = add_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("hook").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = add_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when a non-admin adds a hook");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin adds a hook",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_non_contract_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("wallet").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = add_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when the address is not a contract");
+        assert!(
+            matches!(&err, ContractError::ExecuteError { route, .. } if route == "add_hook"),
+            "an execute error should be emitted when the address is not a contract, but got: {err:?}",
+        );
+        assert!(
+            get_hooks(&deps.storage)
+                .expect("hooks should load")
+                .is_empty(),
+            "no hook should be stored",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_duplicate_hook() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_contract_info(&mut deps.querier);
+        let contract = deps.api.addr_make("hook").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        add_hook(
+            deps.as_mut(),
+            mock_env(),
+            info.to_owned(),
+            contract.to_owned(),
+        )
+        .expect("the first registration should succeed");
+        let err = add_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when a hook is registered twice");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when a hook is registered twice",
+        );
+    }
+
+    #[test]
+    fn test_rejection_beyond_hook_cap() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_contract_info(&mut deps.querier);
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        for index in 0..MAX_HOOKS {
+            let contract = deps.api.addr_make(&format!("hook{index}")).into_string();
+            add_hook(deps.as_mut(), mock_env(), info.to_owned(), contract)
+                .expect("hooks up to the cap should be registered");
+        }
+        let contract = deps.api.addr_make("one_too_many").into_string();
+        let err = add_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when the hook cap is reached");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the hook cap is reached",
+        );
+    }
+
+    #[test]
+    fn test_successful_add_hook() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_contract_info(&mut deps.querier);
+        let contract = deps.api.addr_make("hook");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response = add_hook(deps.as_mut(), mock_env(), info, contract.to_string())
+            .expect("the admin should be able to add a hook");
+        assert_eq!(
+            contract.as_str(),
+            single_attribute_for_key(&response, "hook_address"),
+            "the hook_address attribute should have the registered contract",
+        );
+        assert_eq!(
+            vec![RegisteredHook {
+                contract: contract.to_owned(),
+                added_at_height: Uint64::new(mock_env().block.height),
+            }],
+            get_hooks(&deps.storage).expect("hooks should load"),
+            "the hook should be stored",
+        );
+        let entries =
+            get_history_entries(&deps.storage, None, None).expect("history should be fetched");
+        assert!(
+            matches!(
+                &entries.last().expect("a history entry should be recorded").event,
+                HistoryEvent::AdminAction { action, new_value, .. }
+                    if action == "add_hook" && new_value.as_deref() == Some(contract.as_str()),
+            ),
+            "the registration should be recorded as an admin action",
+        );
+    }
+}
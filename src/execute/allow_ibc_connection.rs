@@ -0,0 +1,110 @@
+use crate::store::history::record_admin_action;
+use crate::store::ibc_channels::{allow_connection, AllowedConnection};
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Adds an IBC connection to the allowlist
+/// checked during the [channel handshake](crate::ibc::channel::open_channel), permitting
+/// counterparties to open channels over it.  A connection can only be allowlisted once, and no
+/// more than [MAX_ALLOWED_IBC_CONNECTIONS](crate::store::ibc_channels::MAX_ALLOWED_IBC_CONNECTIONS)
+/// connections may be allowlisted.  Only the contract admin may invoke this route, and each
+/// invocation is recorded in the contract's [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `connection_id` The identifier of the IBC connection on this chain, such as `connection-0`.
+pub fn allow_ibc_connection(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    connection_id: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let connection_id = connection_id.trim().to_string();
+    if connection_id.is_empty() {
+        return ContractError::ExecuteError {
+            route: "allow_ibc_connection".to_string(),
+            message: "a connection id must be provided".to_string(),
+        }
+        .to_err();
+    }
+    allow_connection(
+        deps.storage,
+        &AllowedConnection {
+            connection_id: connection_id.to_owned(),
+            allowed_at_height: Uint64::new(env.block.height),
+        },
+    )?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "allow_ibc_connection",
+        None,
+        Some(connection_id.to_owned()),
+    )?;
+    Response::new()
+        .add_attribute("action", "allow_ibc_connection")
+        .add_attribute("connection_id", connection_id)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::allow_ibc_connection::allow_ibc_connection;
+    use crate::store::ibc_channels::is_connection_allowed;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = allow_ibc_connection(deps.as_mut(), mock_env(), info, "connection-0".to_string())
+            .expect_err("an error should occur when a non-admin allowlists a connection");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin allowlists a connection",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_blank_connection_id() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = allow_ibc_connection(deps.as_mut(), mock_env(), info, " ".to_string())
+            .expect_err("an error should occur when the connection id is blank");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the connection id is blank",
+        );
+    }
+
+    #[test]
+    fn test_successful_allow_ibc_connection() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        allow_ibc_connection(deps.as_mut(), mock_env(), info, "connection-0".to_string())
+            .expect("the admin should be able to allowlist a connection");
+        assert!(
+            is_connection_allowed(&deps.storage, "connection-0"),
+            "the connection should be allowlisted",
+        );
+    }
+}
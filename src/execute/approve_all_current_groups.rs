@@ -0,0 +1,356 @@
+use crate::execute::approve_group_membership::{record_approval, ApprovalSubject};
+use crate::store::blocklist::check_account_not_blocked;
+use crate::store::contract_state::get_contract_state;
+use crate::store::group_terms::get_group_terms;
+use crate::types::core::error::ContractError;
+use crate::util::group_helpers::get_group_ids_by_member;
+use crate::util::prov_helpers::get_approved_group_ids;
+use crate::util::route_helpers::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Approves, in a single transaction, every
+/// group in which the signing account is currently a member and that it has not yet approved.  The
+/// account's groups are fetched from the group module via `GroupsByMember` Stargate queries, and
+/// the account's existing approvals are fetched from its attributes, so that attributes are only
+/// written for the missing group ids.  Each new approval is subject to the same member caps,
+/// approval limits, and recording as [approve_group_membership](crate::execute::approve_group_membership::approve_group_membership).
+/// Groups that have reached their member cap, or that the account may not approve yet due to its
+/// approval limits, are skipped and emitted in the `skipped_group_ids` attribute so that the
+/// remaining groups are still approved.  Groups with published
+/// [terms](crate::store::group_terms::GroupTerms) are not approved by this route, because their
+/// terms must be acknowledged individually through [approve_group_membership](crate::execute::approve_group_membership::approve_group_membership),
+/// and are instead emitted in the `terms_required_group_ids` attribute.  Succeeds without writing
+/// any attributes if every group is already approved.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn approve_all_current_groups(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_account_not_blocked(deps.storage, &info.sender)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = &contract_state.attribute_name;
+    let member_group_ids =
+        get_group_ids_by_member(&GroupQuerier::new(&deps.querier), info.sender.as_str())?;
+    let approved_group_ids = get_approved_group_ids(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        attribute_name,
+        contract_state.attribute_query_mode,
+    )?;
+    let mut response = Response::new();
+    let mut newly_approved = vec![];
+    let mut skipped = vec![];
+    let mut terms_required = vec![];
+    for group_id in member_group_ids {
+        if approved_group_ids.contains(&group_id)
+            || newly_approved.contains(&group_id)
+            || skipped.contains(&group_id)
+            || terms_required.contains(&group_id)
+        {
+            continue;
+        }
+        if get_group_terms(deps.storage, group_id)?.is_some() {
+            terms_required.push(group_id);
+            continue;
+        }
+        // The cap and limit checks run before anything is recorded, so a rejected group leaves no
+        // partial state behind and can safely be skipped
+        match record_approval(
+            deps.storage,
+            &env,
+            &contract_state,
+            &info.sender,
+            group_id,
+            ApprovalSubject::Group { terms_hash: None },
+            None,
+        ) {
+            Ok(messages) => {
+                response = response.add_submessages(messages);
+                newly_approved.push(group_id);
+            }
+            Err(
+                ContractError::GroupCapacityError { .. }
+                | ContractError::ApprovalRateLimitError { .. }
+                | ContractError::ReapprovalCooldownError { .. },
+            ) => skipped.push(group_id),
+            Err(e) => return e.to_err(),
+        }
+    }
+    response
+        .add_attribute("action", "approve_all_current_groups")
+        .add_attribute("account_address", info.sender.as_str())
+        .add_attribute("attribute_name", attribute_name)
+        .add_attribute("group_ids", join_group_ids(&newly_approved))
+        .add_attribute("skipped_group_ids", join_group_ids(&skipped))
+        .add_attribute("terms_required_group_ids", join_group_ids(&terms_required))
+        .to_ok()
+}
+
+fn join_group_ids(group_ids: &[Uint64]) -> String {
+    group_ids
+        .iter()
+        .map(|group_id| group_id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::approve_all_current_groups::approve_all_current_groups;
+    use crate::store::approval_events::{get_approval_events, ApprovalEventFilter};
+    use crate::store::blocklist::{block_account, BlockedAccount};
+    use crate::store::contract_state::{get_contract_state, set_contract_state, ApprovalLimits};
+    use crate::store::group_caps::set_group_member_cap;
+    use crate::store::group_stats::get_group_stats;
+    use crate::store::group_terms::publish_group_terms;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::{
+        mock_groups_by_member, mock_no_attributes, single_attribute_for_key,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{coins, from_json, to_json_vec, Addr, AnyMsg, CosmosMsg, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::cosmos::group::v1::QueryGroupsByMemberRequest;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, MsgAddAttributeRequest, QueryAttributeRequest,
+        QueryAttributeResponse,
+    };
+
+    fn written_group_ids(response: &cosmwasm_std::Response) -> Vec<u64> {
+        response
+            .messages
+            .iter()
+            .map(|sub_msg| match &sub_msg.msg {
+                CosmosMsg::Any(AnyMsg { value, .. }) => from_json::<u64>(
+                    MsgAddAttributeRequest::try_from(value.to_owned())
+                        .expect("the add attribute msg should deserialize")
+                        .value,
+                )
+                .expect("the attribute value should be a group id"),
+                msg => panic!("unexpected message emitted: {msg:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &coins(1, "nhash"));
+        let err = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_blocked_account() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        block_account(
+            &mut deps.storage,
+            &BlockedAccount {
+                account: Addr::unchecked(DEFAULT_GROUP_MEMBER),
+                reason: "sanctioned".to_string(),
+                blocked_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the account should be blocked");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect_err("an error should occur when the account is blocked");
+        assert!(
+            matches!(err, ContractError::BlockedAccountError { .. }),
+            "a blocked account error should be emitted when the account is blocked",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_group_query() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_no_attributes(&mut deps.querier);
+        QueryGroupsByMemberRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect_err("an error should occur when the groups cannot be fetched");
+        assert!(
+            matches!(err, ContractError::GroupQueryError { .. }),
+            "a group query error should be emitted when the groups cannot be fetched",
+        );
+    }
+
+    #[test]
+    fn test_full_and_rate_limited_groups_are_skipped() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_no_attributes(&mut deps.querier);
+        mock_groups_by_member(&mut deps.querier, vec![vec![1, 2, 3, 4]]);
+        set_group_member_cap(&mut deps.storage, Uint64::new(2), Some(0))
+            .expect("the cap should be set");
+        let mut contract_state = get_contract_state(&deps.storage).expect("state should load");
+        contract_state.approval_limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(2)),
+            window_blocks: Uint64::new(10),
+            reapproval_cooldown_blocks: Uint64::zero(),
+        };
+        set_contract_state(&mut deps.storage, &contract_state).expect("state should save");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect("the route should succeed when some groups are rejected");
+        assert_eq!(
+            vec![1, 3],
+            written_group_ids(&response),
+            "attributes should only be written for the groups that could be approved",
+        );
+        assert_eq!(
+            "1,3",
+            single_attribute_for_key(&response, "group_ids"),
+            "the newly approved group ids should be emitted",
+        );
+        assert_eq!(
+            "2,4",
+            single_attribute_for_key(&response, "skipped_group_ids"),
+            "the full group and the group beyond the rate limit should be reported as skipped",
+        );
+        for (group_id, expected_approvals) in [(1u64, 1u64), (2, 0), (3, 1), (4, 0)] {
+            assert_eq!(
+                Uint64::new(expected_approvals),
+                get_group_stats(&deps.storage, Uint64::new(group_id))
+                    .expect("stats should load")
+                    .current_approvals,
+                "unexpected approval count for group [{group_id}]",
+            );
+        }
+    }
+
+    #[test]
+    fn test_groups_with_terms_are_reported_instead_of_approved() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_no_attributes(&mut deps.querier);
+        mock_groups_by_member(&mut deps.querier, vec![vec![1, 2, 3]]);
+        publish_group_terms(&mut deps.storage, Uint64::new(2), "ipfs://terms", "abcd", 1)
+            .expect("the terms should publish");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect("the route should succeed when a group has terms");
+        assert_eq!(
+            vec![1, 3],
+            written_group_ids(&response),
+            "attributes should only be written for the groups without terms",
+        );
+        assert_eq!(
+            "2",
+            single_attribute_for_key(&response, "terms_required_group_ids"),
+            "the group with terms should be reported",
+        );
+        assert_eq!(
+            "",
+            single_attribute_for_key(&response, "skipped_group_ids"),
+            "the group with terms should not be reported as rejected",
+        );
+        assert_eq!(
+            Uint64::zero(),
+            get_group_stats(&deps.storage, Uint64::new(2))
+                .expect("stats should load")
+                .current_approvals,
+            "the group with terms should not be approved",
+        );
+    }
+
+    #[test]
+    fn test_only_missing_groups_are_approved() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_groups_by_member(&mut deps.querier, vec![vec![1, 2], vec![3, 4]]);
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_GROUP_MEMBER.to_string(),
+                attributes: [2u64, 4]
+                    .iter()
+                    .map(|group_id| Attribute {
+                        name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                        value: to_json_vec(group_id).unwrap(),
+                        attribute_type: AttributeType::Int.into(),
+                        address: DEFAULT_GROUP_MEMBER.to_string(),
+                        expiration_date: None,
+                    })
+                    .collect(),
+                pagination: None,
+            },
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect("the missing groups should be approved");
+        assert_eq!(
+            vec![1, 3],
+            written_group_ids(&response),
+            "attributes should only be written for groups without an approval",
+        );
+        assert_eq!(
+            "1,3",
+            single_attribute_for_key(&response, "group_ids"),
+            "the newly approved group ids should be emitted",
+        );
+        for group_id in [1u64, 3] {
+            assert_eq!(
+                Uint64::new(1),
+                get_group_stats(&deps.storage, Uint64::new(group_id))
+                    .expect("stats should load")
+                    .current_approvals,
+                "the approval of group [{group_id}] should be counted",
+            );
+        }
+        assert_eq!(
+            2,
+            get_approval_events(&deps.storage, &ApprovalEventFilter::default(), None, None)
+                .expect("approval events should load")
+                .len(),
+            "an approval event should be recorded for each new approval",
+        );
+    }
+
+    #[test]
+    fn test_success_when_every_group_is_approved() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_no_attributes(&mut deps.querier);
+        mock_groups_by_member(&mut deps.querier, vec![vec![]]);
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_all_current_groups(deps.as_mut(), mock_env(), info)
+            .expect("the route should succeed when nothing is missing");
+        assert!(
+            response.messages.is_empty(),
+            "no attributes should be written when nothing is missing",
+        );
+        assert_eq!(
+            "",
+            single_attribute_for_key(&response, "group_ids"),
+            "no group ids should be emitted when nothing is missing",
+        );
+        assert_eq!(
+            "",
+            single_attribute_for_key(&response, "skipped_group_ids"),
+            "no group ids should be skipped when nothing is missing",
+        );
+    }
+}
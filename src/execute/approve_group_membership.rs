@@ -1,8 +1,26 @@
+use crate::store::approval_activity::{
+    check_approval_limits, record_account_approval, record_approval_memo,
+};
+use crate::store::approval_events::{record_approval_event, ApprovalAction};
+use crate::store::blocklist::check_account_not_blocked;
+use crate::store::group_caps::check_group_has_capacity;
+use crate::store::group_stats::{record_group_approval, record_group_policy_approval};
+use crate::store::group_terms::{
+    check_terms_acknowledged, get_accepted_terms_version, record_accepted_terms,
+};
+use crate::store::hooks::prepare_hook_messages;
+use crate::store::ibc_channels::prepare_relay_messages;
 use crate::types::core::error::ContractError;
-use crate::util::prov_helpers::get_group_id_attribute_values_paginated;
-use crate::util::route_helpers::check_funds_are_empty;
-use crate::{store::contract_state::get_contract_state, util::prov_helpers::get_all_attributes};
-use cosmwasm_std::{to_json_vec, DepsMut, Env, MessageInfo, Response, Uint64};
+use crate::types::core::hook::ApprovalHookMsg;
+use crate::util::prov_helpers::{group_policy_attribute_value, parse_group_id_attribute};
+use crate::util::route_helpers::{check_funds_are_empty, validate_memo};
+use crate::{
+    store::contract_state::{get_contract_state, ContractState},
+    util::prov_helpers::account_has_matching_attribute,
+};
+use cosmwasm_std::{
+    to_json_vec, Addr, DepsMut, Env, MessageInfo, Response, Storage, SubMsg, Uint64,
+};
 use provwasm_std::types::provenance::attribute::v1::{
     AttributeQuerier, AttributeType, MsgAddAttributeRequest,
 };
@@ -10,46 +28,64 @@ use result_extensions::ResultExtensions;
 
 /// Invoked via the contract's execution functionality.  Adds an attribute to the signer that
 /// denotes that they affirm their membership in a [Provenance Blockchain Group](https://docs.cosmos.network/main/modules/group)
-/// by setting an int value on the designated attribute equal to the group identifier.  Note:
-/// Cosmwasm does not expose functionality to query the group module, so this route does not do any
-/// verification that the signer is actually a member of the approved group.  However, consenting to
-/// either being or becoming a member of a group is simply an act of compliance.  False claims made
+/// by setting an int value on the designated attribute equal to the group identifier.  Note: this
+/// route intentionally does not verify that the signer is actually a member of the approved group,
+/// because consenting to either being or becoming a member of a group is simply an act of
+/// compliance.  Members that want approvals limited to their current groups can use
+/// [approve_all_current_groups](crate::execute::approve_all_current_groups::approve_all_current_groups),
+/// which fetches them from the group module via Stargate queries.  False claims made
 /// herein can be queried from the standard chain routes, which allows external consumers of this
 /// attribute to verify this statement after it has been made.  The route does, however, validate
 /// that the account does not already have an attribute value affirming the existing group,
-/// preventing duplicate writes.
+/// preventing duplicate writes.  Each approval is counted in the group's
+/// [statistics](crate::store::group_stats::GroupStats) and recorded in the
+/// [approval audit trail](crate::store::approval_events::ApprovalEvent).  Accounts on the
+/// [blocklist](crate::store::blocklist::BlockedAccount) are rejected, as are approvals for groups
+/// that have reached their admin-configured [member cap](crate::store::group_caps::GroupCapacity)
+/// and approvals that exceed the [ApprovalLimits](crate::store::contract_state::ApprovalLimits)
+/// configured in the contract state.  Each [registered hook](crate::store::hooks::RegisteredHook)
+/// is notified of the approval with an [ApprovalHookMsg], which is also relayed to each
+/// [connected IBC channel](crate::store::ibc_channels::ConnectedChannel).  When the contract admin
+/// has published [terms](crate::store::group_terms::GroupTerms) for the group, the approval must
+/// acknowledge them by their content hash, and the accepted version is stored with the approval.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `group_id` The unique identifier of a given group for which the signing account consents to
-/// membership.
+///   membership.
+/// * `memo` An optional statement describing the capacity in which the signing account consents to
+///   membership.  Stored with the approval in the audit trail and emitted as an event attribute.
+/// * `terms_hash` The content hash of the group's published terms, which is required if and only
+///   if terms have been published for the group.
 pub fn approve_group_membership(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     group_id: Uint64,
+    memo: Option<String>,
+    terms_hash: Option<String>,
 ) -> Result<Response, ContractError> {
     // Verify that no coin was sent to start this execution route.  The only charge incurred should
     // be a new attribute write
     check_funds_are_empty(&info)?;
-    let attribute_name = get_contract_state(deps.storage)?.attribute_name;
-    let existing_group_ids = get_all_attributes(
-        AttributeQuerier::new(&deps.querier),
-        &info.sender.clone().into_string(),
-    )
-    .ok()
-    .map(|attributes| get_group_id_attribute_values_paginated(attributes, &attribute_name))
-    .unwrap_or_default();
+    let memo = validate_memo(memo)?;
+    // Blocked accounts may not record new consents, so the check is made before any chain queries
+    check_account_not_blocked(deps.storage, &info.sender)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = &contract_state.attribute_name;
     // First, verify that this member has not yet approved itself for this group.  Duplicate ids
     // would be a waste of hash and needlessly increase data storage on chain
-    if existing_group_ids
-        .iter()
-        .any(|id| id.u64() == group_id.u64())
-    {
+    if account_has_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        attribute_name,
+        contract_state.attribute_query_mode,
+        |attribute| parse_group_id_attribute(attribute, attribute_name) == Some(group_id),
+    )? {
         return ContractError::ExecuteError {
             route: "approve_group_membership".to_string(),
             message: format!(
@@ -60,35 +96,191 @@ pub fn approve_group_membership(
         }
         .to_err();
     }
-    Response::new()
-        .add_message(MsgAddAttributeRequest {
-            name: attribute_name.clone(),
-            value: to_json_vec(&group_id.u64())?,
-            attribute_type: AttributeType::Int.into(),
-            account: info.sender.clone().into_string(),
-            owner: env.contract.address.into_string(),
-            expiration_date: None,
-        })
+    let messages = record_approval(
+        deps.storage,
+        &env,
+        &contract_state,
+        &info.sender,
+        group_id,
+        ApprovalSubject::Group {
+            terms_hash: terms_hash.as_deref(),
+        },
+        memo.clone(),
+    )?;
+    let mut response = Response::new()
+        .add_submessages(messages)
         .add_attribute("action", "approve_group_membership")
         .add_attribute("account_address", info.sender.as_str())
-        .add_attribute("attribute_name", &attribute_name)
-        .add_attribute("group_id", group_id.to_string())
-        .to_ok()
+        .add_attribute("attribute_name", attribute_name)
+        .add_attribute("group_id", group_id.to_string());
+    if let Some(memo) = memo {
+        response = response.add_attribute("memo", memo);
+    }
+    if let Some(version) = get_accepted_terms_version(deps.storage, &info.sender, group_id)? {
+        response = response.add_attribute("terms_version", version.to_string());
+    }
+    response.to_ok()
+}
+
+/// Describes what an account consents to in an approval recorded by [record_approval].
+pub enum ApprovalSubject<'a> {
+    /// Consent to membership in the entire group.
+    Group {
+        /// The content hash of the group's published terms acknowledged by the account.
+        terms_hash: Option<&'a str>,
+    },
+    /// Consent to participation in a single [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy)
+    /// of the group, identified by its bech32 address.
+    Policy(&'a Addr),
+}
+
+/// Records a new approval of a group, or of one of its policy accounts, by an account once the
+/// calling route has verified that the account has not already approved it.  The account's
+/// [ApprovalLimits](crate::store::contract_state::ApprovalLimits) are enforced for every approval,
+/// with policy approvals sharing the re-approval cooldown of their group.  The group's
+/// [member cap](crate::store::group_caps::GroupCapacity) and the acknowledgement of its published
+/// [terms](crate::store::group_terms::GroupTerms) only apply to approvals of the entire group.  The
+/// approval is counted in the group's [statistics](crate::store::group_stats::GroupStats) and
+/// recorded in the [approval audit trail](crate::store::approval_events::ApprovalEvent).  Returns
+/// the message that writes the approval attribute, followed by the messages that notify each
+/// [registered hook](crate::store::hooks::RegisteredHook) and
+/// [connected IBC channel](crate::store::ibc_channels::ConnectedChannel).
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The contract state, holding the configured approval limits and the attribute
+///   name under which the approval is written.
+/// * `account` The bech32 address of the approving account.
+/// * `group_id` The unique identifier of the approved group, or of the group that owns the
+///   approved policy.
+/// * `subject` Whether the entire group or a single policy account of it is approved.
+/// * `memo` An optional, previously-validated statement provided with the approval.
+pub fn record_approval(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contract_state: &ContractState,
+    account: &Addr,
+    group_id: Uint64,
+    subject: ApprovalSubject,
+    memo: Option<String>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let terms_version = match subject {
+        ApprovalSubject::Group { terms_hash } => {
+            let terms_version = check_terms_acknowledged(storage, group_id, terms_hash)?;
+            check_group_has_capacity(storage, group_id)?;
+            terms_version
+        }
+        ApprovalSubject::Policy(_) => None,
+    };
+    check_approval_limits(
+        storage,
+        &contract_state.approval_limits,
+        account,
+        group_id,
+        env.block.height,
+    )?;
+    record_account_approval(
+        storage,
+        &contract_state.approval_limits,
+        account,
+        env.block.height,
+    )?;
+    let policy_address = match subject {
+        ApprovalSubject::Group { .. } => {
+            record_group_approval(storage, account, group_id, env.block.height)?;
+            record_accepted_terms(storage, account, group_id, terms_version)?;
+            record_approval_memo(storage, account, group_id, memo.as_deref())?;
+            None
+        }
+        ApprovalSubject::Policy(policy_address) => {
+            record_group_policy_approval(
+                storage,
+                account,
+                policy_address,
+                group_id,
+                env.block.height,
+            )?;
+            Some(policy_address)
+        }
+    };
+    record_approval_event(
+        storage,
+        env,
+        account,
+        group_id,
+        policy_address,
+        ApprovalAction::Approve,
+        memo.clone(),
+    )?;
+    let notification = ApprovalHookMsg {
+        account: account.to_owned(),
+        group_id,
+        policy_address: policy_address.cloned(),
+        action: ApprovalAction::Approve,
+        memo,
+        block_height: Uint64::new(env.block.height),
+    };
+    let (value, attribute_type) = match policy_address {
+        None => (to_json_vec(&group_id.u64())?, AttributeType::Int),
+        Some(policy_address) => (
+            group_policy_attribute_value(policy_address.as_str()),
+            AttributeType::String,
+        ),
+    };
+    let mut messages = vec![SubMsg::new(MsgAddAttributeRequest {
+        name: contract_state.attribute_name.to_owned(),
+        value,
+        attribute_type: attribute_type.into(),
+        account: account.to_string(),
+        owner: env.contract.address.to_string(),
+        expiration_date: None,
+    })];
+    messages.extend(prepare_hook_messages(storage, &notification)?);
+    messages.extend(
+        prepare_relay_messages(storage, env, &notification)?
+            .into_iter()
+            .map(SubMsg::new),
+    );
+    messages.to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::execute::approve_group_membership::approve_group_membership;
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::blocklist::{block_account, BlockedAccount};
+    use crate::store::contract_state::{
+        get_contract_state, set_contract_state, ApprovalLimits, AttributeQueryMode,
+    };
+    use crate::store::group_caps::set_group_member_cap;
+    use crate::store::group_stats::get_group_stats;
+    use crate::store::group_terms::{get_accepted_terms_version, publish_group_terms};
+    use crate::store::hooks::{add_hook, RegisteredHook};
+    use crate::store::ibc_channels::{save_channel, ConnectedChannel};
+    use crate::test::chain_simulator::{
+        ProvenanceSimulator, SimulationError, ATTRIBUTES_QUERY_PATH, ATTRIBUTE_QUERY_PATH,
+    };
     use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
-    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_helpers::{mock_no_attributes, single_attribute_for_key};
     use crate::test::test_instantiate::test_instantiate;
     use crate::types::core::error::ContractError;
+    use crate::types::core::hook::{ApprovalHookExecuteMsg, ApprovalHookMsg};
+    use crate::types::core::ibc::{ApprovalPacket, ApprovalPacketV1};
+    use crate::types::core::msg::ExecuteMsg;
+    use crate::util::route_helpers::MAX_MEMO_LENGTH;
     use cosmwasm_std::testing::{message_info, mock_env};
-    use cosmwasm_std::{coins, from_json, to_json_vec, Addr, AnyMsg, CosmosMsg, Response, Uint64};
+    use cosmwasm_std::{
+        coins, from_json, to_json_vec, Addr, AnyMsg, CosmosMsg, IbcEndpoint, IbcMsg, Response,
+        Uint64, WasmMsg,
+    };
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::types::provenance::attribute::v1::{
         Attribute, AttributeType, MsgAddAttributeRequest, QueryAttributeRequest,
-        QueryAttributeResponse, QueryAttributesRequest, QueryAttributesResponse,
+        QueryAttributeResponse,
     };
 
     #[test]
@@ -96,21 +288,126 @@ mod tests {
         let mut deps = mock_provenance_dependencies();
         test_instantiate(deps.as_mut());
         let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &coins(15, "nhash"));
-        let err = approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
-            .expect_err("an error should occur when the sender provides funds");
+        let err =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
+                .expect_err("an error should occur when the sender provides funds");
         assert!(
             matches!(err, ContractError::InvalidFundsError { .. }),
             "an invalid funds error should be emitted when the sender provides funds",
         );
     }
 
+    #[test]
+    fn test_rejection_for_invalid_memo() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_group_membership(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(1),
+            Some("a".repeat(MAX_MEMO_LENGTH + 1)),
+            None,
+        )
+        .expect_err("an error should occur when the memo is too long");
+        assert!(
+            matches!(err, ContractError::InvalidFormatError { .. }),
+            "an invalid format error should be emitted when the memo is too long",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_blocked_account() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        block_account(
+            &mut deps.storage,
+            &BlockedAccount {
+                account: Addr::unchecked(DEFAULT_GROUP_MEMBER),
+                reason: "sanctioned".to_string(),
+                blocked_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the member should be blocked");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        match approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
+            .expect_err("an error should occur when a blocked account approves a group")
+        {
+            ContractError::BlockedAccountError { account, reason } => {
+                assert_eq!(
+                    DEFAULT_GROUP_MEMBER, account,
+                    "the blocked account should be reported",
+                );
+                assert_eq!("sanctioned", reason, "the block reason should be reported");
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_rejection_for_full_group() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        set_group_member_cap(&mut deps.storage, Uint64::new(1), Some(0))
+            .expect("the group cap should be set");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
+                .expect_err("an error should occur when the group is full");
+        assert!(
+            matches!(
+                err,
+                ContractError::GroupCapacityError {
+                    group_id: 1,
+                    max_members: 0,
+                },
+            ),
+            "a group capacity error should be emitted when the group is full, but got: {err:?}",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_rate_limited_account() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        let mut contract_state =
+            get_contract_state(&deps.storage).expect("the contract state should load");
+        contract_state.approval_limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(1)),
+            window_blocks: Uint64::new(10),
+            reapproval_cooldown_blocks: Uint64::new(0),
+        };
+        set_contract_state(&mut deps.storage, &contract_state)
+            .expect("the contract state should be saved");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        approve_group_membership(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            Uint64::new(1),
+            None,
+            None,
+        )
+        .expect("the first approval within the window should succeed");
+        let err =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(2), None, None)
+                .expect_err("an error should occur when the account exceeds the rate limit");
+        assert!(
+            matches!(err, ContractError::ApprovalRateLimitError { .. }),
+            "a rate limit error should be emitted when the account exceeds the rate limit",
+        );
+    }
+
     #[test]
     fn test_rejection_for_existing_attribute() {
         let mut deps = mock_provenance_dependencies();
         let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
-        QueryAttributesRequest::mock_response(
+        QueryAttributeRequest::mock_response(
             &mut deps.querier,
-            QueryAttributesResponse {
+            QueryAttributeResponse {
                 account: DEFAULT_GROUP_MEMBER.to_string(),
                 attributes: vec![Attribute {
                     name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
@@ -123,7 +420,7 @@ mod tests {
             },
         );
         test_instantiate(deps.as_mut());
-        let err = approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
+        let err = approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
             .expect_err("an error should occur when the member already has an attribute specifying an approval for the target group");
         match err {
             ContractError::ExecuteError { route, message } => {
@@ -146,12 +443,53 @@ mod tests {
     #[test]
     fn test_successful_call_for_new_attribute() {
         let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
         test_instantiate(deps.as_mut());
         let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
-        let response = approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(15))
-            .expect("an approval of a new group id should be allowed");
+        let response =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(15), None, None)
+                .expect("an approval of a new group id should be allowed");
         assert_correct_response_messages(&response, 15);
-        assert_correct_response_attributes(&response, 15);
+        assert_correct_response_attributes(&response, 15, None);
+        let stats = get_group_stats(deps.as_ref().storage, Uint64::new(15))
+            .expect("group stats should load after an approval");
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "the approval should be counted as a current approval",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.total_approvals,
+            "the approval should be counted as a total approval",
+        );
+        assert_eq!(
+            Some(Uint64::new(mock_env().block.height)),
+            stats.last_approval_height,
+            "the approval height should be recorded",
+        );
+        let events = get_approval_events(
+            deps.as_ref().storage,
+            &ApprovalEventFilter::default(),
+            None,
+            None,
+        )
+        .expect("approval events should load after an approval");
+        assert_eq!(
+            1,
+            events.len(),
+            "a single approval event should be recorded"
+        );
+        assert_eq!(
+            ApprovalAction::Approve,
+            events[0].action,
+            "the event should be recorded as an approval",
+        );
+        assert_eq!(
+            DEFAULT_GROUP_MEMBER,
+            events[0].account.as_str(),
+            "the event should be recorded for the sender",
+        );
     }
 
     #[test]
@@ -182,10 +520,314 @@ mod tests {
                 pagination: None,
             },
         );
-        let response = approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(3))
-            .expect("an approval of a new group id when non-matching existing ids are present should succeed");
+        let response = approve_group_membership(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(3),
+            Some(" signer ".to_string()), None,
+        )
+        .expect("an approval of a new group id when non-matching existing ids are present should succeed");
         assert_correct_response_messages(&response, 3);
-        assert_correct_response_attributes(&response, 3);
+        assert_correct_response_attributes(&response, 3, Some("signer"));
+        let events = get_approval_events(
+            deps.as_ref().storage,
+            &ApprovalEventFilter::default(),
+            None,
+            None,
+        )
+        .expect("approval events should load after an approval");
+        assert_eq!(
+            Some("signer".to_string()),
+            events[0].memo,
+            "the normalized memo should be stored with the approval",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_attribute_query_in_strict_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        QueryAttributeRequest::mock_failed_response(
+            &mut deps.querier,
+            "attribute module unavailable".to_string(),
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        match approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
+            .expect_err("an error should occur when the attribute query fails in strict mode")
+        {
+            ContractError::AttributeQueryError { account, message } => {
+                assert_eq!(
+                    DEFAULT_GROUP_MEMBER, account,
+                    "the queried account should be reported",
+                );
+                assert!(
+                    message.contains("attribute module unavailable"),
+                    "the querier failure should be wrapped, but got: {message}",
+                );
+            }
+            e => panic!("unexpected error: {e:?}"),
+        };
+        assert_eq!(
+            Uint64::new(0),
+            get_group_stats(&deps.storage, Uint64::new(1))
+                .expect("group stats should load")
+                .total_approvals,
+            "no approval should be recorded when the duplicate check cannot be performed",
+        );
+    }
+
+    #[test]
+    fn test_success_for_failed_attribute_query_in_lenient_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let mut contract_state =
+            get_contract_state(&deps.storage).expect("the contract state should load");
+        contract_state.attribute_query_mode = AttributeQueryMode::Lenient;
+        set_contract_state(&mut deps.storage, &contract_state)
+            .expect("the contract state should save");
+        QueryAttributeRequest::mock_failed_response(
+            &mut deps.querier,
+            "attribute module unavailable".to_string(),
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1), None, None)
+                .expect("the approval should proceed without duplicate detection in lenient mode");
+        assert_correct_response_messages(&response, 1);
+    }
+
+    #[test]
+    fn test_hooks_are_notified_of_approval() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        let hook_addresses = [deps.api.addr_make("hook_a"), deps.api.addr_make("hook_b")];
+        for contract in &hook_addresses {
+            add_hook(
+                &mut deps.storage,
+                &RegisteredHook {
+                    contract: contract.to_owned(),
+                    added_at_height: Uint64::new(1),
+                },
+            )
+            .expect("the hook should be registered");
+        }
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_group_membership(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(15),
+            Some("signer".to_string()),
+            None,
+        )
+        .expect("an approval should succeed with registered hooks");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the attribute message and one message per hook should be emitted",
+        );
+        let mut notified = response.messages[1..]
+            .iter()
+            .map(|sub_msg| match &sub_msg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr, msg, ..
+                }) => {
+                    assert_eq!(
+                        ApprovalHookExecuteMsg::ApprovalHook(ApprovalHookMsg {
+                            account: Addr::unchecked(DEFAULT_GROUP_MEMBER),
+                            group_id: Uint64::new(15),
+                            policy_address: None,
+                            action: ApprovalAction::Approve,
+                            memo: Some("signer".to_string()),
+                            block_height: Uint64::new(mock_env().block.height),
+                        }),
+                        from_json(msg).expect("the hook payload should deserialize"),
+                        "the hook payload should describe the approval",
+                    );
+                    contract_addr.to_owned()
+                }
+                msg => panic!("unexpected message emitted: {msg:?}"),
+            })
+            .collect::<Vec<_>>();
+        notified.sort();
+        let mut expected = hook_addresses.map(|addr| addr.into_string()).to_vec();
+        expected.sort();
+        assert_eq!(
+            expected, notified,
+            "each registered hook should be notified"
+        );
+    }
+
+    #[test]
+    fn test_approval_is_relayed_to_connected_channels() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        save_channel(
+            &mut deps.storage,
+            &ConnectedChannel {
+                channel_id: "channel-0".to_string(),
+                counterparty_endpoint: IbcEndpoint {
+                    port_id: "their_port".to_string(),
+                    channel_id: "channel-7".to_string(),
+                },
+                connection_id: "connection-2".to_string(),
+                connected_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the channel should be stored");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response =
+            approve_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(15), None, None)
+                .expect("an approval should succeed with a connected channel");
+        assert_eq!(
+            2,
+            response.messages.len(),
+            "the attribute message and one packet per channel should be emitted",
+        );
+        match &response.messages[1].msg {
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id, data, ..
+            }) => {
+                assert_eq!(
+                    "channel-0", channel_id,
+                    "the connected channel should be targeted"
+                );
+                assert!(
+                    matches!(
+                        from_json(data).expect("the packet should deserialize"),
+                        ApprovalPacket::V1(ApprovalPacketV1::ApprovalChanged(ApprovalHookMsg {
+                            group_id,
+                            action: ApprovalAction::Approve,
+                            ..
+                        })) if group_id.u64() == 15,
+                    ),
+                    "the packet should describe the approval",
+                );
+            }
+            msg => panic!("unexpected message emitted: {msg:?}"),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_check_query_count_ignores_unrelated_attributes() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        for index in 0..500u64 {
+            simulator = simulator.with_attribute(Attribute {
+                name: format!("unrelated{index}.pb"),
+                value: to_json_vec(&index).unwrap(),
+                attribute_type: AttributeType::Int.into(),
+                address: DEFAULT_GROUP_MEMBER.to_string(),
+                expiration_date: None,
+            });
+        }
+        simulator.reset_query_counts();
+        simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: None,
+                    terms_hash: None,
+                },
+            )
+            .expect("the approval should succeed for a heavily-attributed account");
+        assert_eq!(
+            0,
+            simulator.query_count(ATTRIBUTES_QUERY_PATH),
+            "the approval should never scan every attribute on the account",
+        );
+        assert_eq!(
+            1,
+            simulator.query_count(ATTRIBUTE_QUERY_PATH),
+            "the approval should look up the contract's attribute name in a single query",
+        );
+    }
+
+    #[test]
+    fn test_rejection_when_attribute_page_bound_is_exceeded() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        for group_id in 1000..2001u64 {
+            simulator = simulator.with_attribute(Attribute {
+                name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                value: to_json_vec(&group_id).unwrap(),
+                attribute_type: AttributeType::Int.into(),
+                address: DEFAULT_GROUP_MEMBER.to_string(),
+                expiration_date: None,
+            });
+        }
+        let err = simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: None,
+                    terms_hash: None,
+                },
+            )
+            .expect_err("the approval should fail when the duplicate check cannot complete");
+        assert!(
+            matches!(
+                err,
+                SimulationError::Contract(ContractError::AttributePageLimitError { .. })
+            ),
+            "an attribute page limit error should be emitted, but got: {err:?}",
+        );
+    }
+
+    #[test]
+    fn test_terms_hash_must_match_published_terms() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        publish_group_terms(&mut deps.storage, Uint64::new(1), "ipfs://terms", "abcd", 1)
+            .expect("the terms should publish");
+        for terms_hash in [None, Some("ef01".to_string())] {
+            let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+            let err = approve_group_membership(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                Uint64::new(1),
+                None,
+                terms_hash.to_owned(),
+            )
+            .expect_err("an error should occur when the terms are not acknowledged");
+            assert!(
+                matches!(
+                    err,
+                    ContractError::TermsAcknowledgementError { group_id: 1, .. }
+                ),
+                "a terms acknowledgement error should be emitted for hash {terms_hash:?}",
+            );
+        }
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_group_membership(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(1),
+            None,
+            Some("ABCD".to_string()),
+        )
+        .expect("the approval should succeed when the current terms are acknowledged");
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, "terms_version"),
+            "the accepted terms version should be emitted",
+        );
+        assert_eq!(
+            Some(Uint64::new(1)),
+            get_accepted_terms_version(
+                &deps.storage,
+                &Addr::unchecked(DEFAULT_GROUP_MEMBER),
+                Uint64::new(1),
+            )
+            .expect("the accepted version should load"),
+            "the accepted terms version should be stored with the approval",
+        );
     }
 
     fn assert_correct_response_messages(response: &Response, group_id: u64) {
@@ -223,31 +865,38 @@ mod tests {
         };
     }
 
-    fn assert_correct_response_attributes(response: &Response, group_id: u64) {
+    fn assert_correct_response_attributes(response: &Response, group_id: u64, memo: Option<&str>) {
         assert_eq!(
-            4,
+            if memo.is_some() { 5 } else { 4 },
             response.attributes.len(),
             "the correct number of attributes should be emitted in the result",
         );
         assert_eq!(
             "approve_group_membership",
-            single_attribute_for_key(&response, "action"),
+            single_attribute_for_key(response, "action"),
             "the action attribute should have the correct value",
         );
         assert_eq!(
             DEFAULT_GROUP_MEMBER,
-            single_attribute_for_key(&response, "account_address"),
+            single_attribute_for_key(response, "account_address"),
             "the account_address attribute should hold the sender's address",
         );
         assert_eq!(
             DEFAULT_CONTRACT_ATTRIBUTE,
-            single_attribute_for_key(&response, "attribute_name"),
+            single_attribute_for_key(response, "attribute_name"),
             "the attribute_name attribute should have the contract's defined attribute name",
         );
         assert_eq!(
             group_id.to_string(),
-            single_attribute_for_key(&response, "group_id"),
+            single_attribute_for_key(response, "group_id"),
             "the group_id attribute should have the provided group's id",
         );
+        if let Some(memo) = memo {
+            assert_eq!(
+                memo,
+                single_attribute_for_key(response, "memo"),
+                "the memo attribute should have the normalized memo",
+            );
+        }
     }
 }
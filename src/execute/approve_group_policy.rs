@@ -0,0 +1,338 @@
+use crate::execute::approve_group_membership::{record_approval, ApprovalSubject};
+use crate::store::blocklist::check_account_not_blocked;
+use crate::store::contract_state::get_contract_state;
+use crate::types::core::error::ContractError;
+use crate::util::group_helpers::get_group_policy_group_id;
+use crate::util::prov_helpers::{account_has_matching_attribute, parse_group_policy_attribute};
+use crate::util::route_helpers::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Adds an attribute to the signer that
+/// denotes that they affirm their participation in a specific [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy),
+/// rather than in every policy of a group.  The approval is written under the contract's attribute
+/// name as a [String](provwasm_std::types::provenance::attribute::v1::AttributeType::String) value
+/// encoded by [group_policy_attribute_value](crate::util::prov_helpers::group_policy_attribute_value),
+/// which keeps it distinguishable from plain group id approvals.  The policy's group is resolved
+/// via a Stargate query to the group module, which also verifies that the policy exists.  The
+/// approval is then recorded in the same manner as a group approval by
+/// [record_approval](crate::execute::approve_group_membership::record_approval): it is subject to
+/// the account's approval limits, counted in the group's policy statistics, recorded in the audit
+/// trail, and sent to registered hooks and connected IBC channels.  Accounts on the
+/// [blocklist](crate::store::blocklist::BlockedAccount) are rejected.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `policy_address` The bech32 address of the group policy account for which the signing account
+///   consents to participation.
+pub fn approve_group_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    policy_address: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_account_not_blocked(deps.storage, &info.sender)?;
+    let policy_address =
+        deps.api
+            .addr_validate(&policy_address)
+            .map_err(|e| ContractError::InvalidFormatError {
+                message: format!("invalid group policy address [{policy_address}]: {e}"),
+            })?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = &contract_state.attribute_name;
+    if account_has_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        attribute_name,
+        contract_state.attribute_query_mode,
+        |attribute| {
+            parse_group_policy_attribute(attribute, attribute_name).as_deref()
+                == Some(policy_address.as_str())
+        },
+    )? {
+        return ContractError::ExecuteError {
+            route: "approve_group_policy".to_string(),
+            message: format!(
+                "group policy [{}] has already been approved by member [{}]",
+                policy_address.as_str(),
+                info.sender.as_str(),
+            ),
+        }
+        .to_err();
+    }
+    let group_id =
+        get_group_policy_group_id(&GroupQuerier::new(&deps.querier), policy_address.as_str())?;
+    let messages = record_approval(
+        deps.storage,
+        &env,
+        &contract_state,
+        &info.sender,
+        group_id,
+        ApprovalSubject::Policy(&policy_address),
+        None,
+    )?;
+    Response::new()
+        .add_submessages(messages)
+        .add_attribute("action", "approve_group_policy")
+        .add_attribute("account_address", info.sender.as_str())
+        .add_attribute("attribute_name", attribute_name)
+        .add_attribute("policy_address", policy_address.as_str())
+        .add_attribute("group_id", group_id.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::approve_group_policy::approve_group_policy;
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::group_stats::get_group_stats;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::{mock_no_attributes, single_attribute_for_key};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::util::prov_helpers::group_policy_attribute_value;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::cosmos::group::v1::{
+        GroupPolicyInfo, QueryGroupPolicyInfoRequest, QueryGroupPolicyInfoResponse,
+    };
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, MsgAddAttributeRequest, QueryAttributeRequest,
+        QueryAttributeResponse,
+    };
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let policy = deps.api.addr_make("policy").to_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &coins(15, "nhash"));
+        let err = approve_group_policy(deps.as_mut(), mock_env(), info, policy)
+            .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_attribute_query_in_strict_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        QueryAttributeRequest::mock_failed_response(
+            &mut deps.querier,
+            "attribute module unavailable".to_string(),
+        );
+        let policy = deps.api.addr_make("policy").to_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_group_policy(deps.as_mut(), mock_env(), info, policy)
+            .expect_err("an error should occur when the attribute query fails in strict mode");
+        assert!(
+            matches!(err, ContractError::AttributeQueryError { .. }),
+            "an attribute query error should be emitted when the duplicate check cannot be performed",
+        );
+    }
+    #[test]
+    fn test_rejection_for_invalid_policy_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_group_policy(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            "not a bech32 address".to_string(),
+        )
+        .expect_err("an error should occur when the policy address is not valid bech32");
+        assert!(
+            matches!(err, ContractError::InvalidFormatError { .. }),
+            "an invalid format error should be emitted for a malformed policy address",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_existing_policy_approval() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let policy = deps.api.addr_make("policy").to_string();
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_GROUP_MEMBER.to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    value: group_policy_attribute_value(&policy),
+                    attribute_type: AttributeType::String.into(),
+                    address: DEFAULT_GROUP_MEMBER.to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        match approve_group_policy(deps.as_mut(), mock_env(), info, policy.clone())
+            .expect_err("an error should occur when the policy has already been approved")
+        {
+            ContractError::ExecuteError { route, message } => {
+                assert_eq!(
+                    "approve_group_policy", route,
+                    "unexpected route in execute error",
+                );
+                assert_eq!(
+                    format!(
+                        "group policy [{policy}] has already been approved by member [{DEFAULT_GROUP_MEMBER}]",
+                    ),
+                    message,
+                    "unexpected message in execute error",
+                );
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_rejection_for_unresolvable_policy() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        QueryGroupPolicyInfoRequest::mock_response(
+            &mut deps.querier,
+            QueryGroupPolicyInfoResponse { info: None },
+        );
+        let policy = deps.api.addr_make("policy").to_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = approve_group_policy(deps.as_mut(), mock_env(), info, policy)
+            .expect_err("an error should occur when the policy cannot be resolved");
+        assert!(
+            matches!(err, ContractError::GroupQueryError { .. }),
+            "a group query error should be emitted when the policy does not exist",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_policy_query() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        QueryGroupPolicyInfoRequest::mock_failed_response(
+            &mut deps.querier,
+            "group module unavailable".to_string(),
+        );
+        let policy = deps.api.addr_make("policy").to_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        match approve_group_policy(deps.as_mut(), mock_env(), info, policy)
+            .expect_err("an error should occur when the policy query fails")
+        {
+            ContractError::GroupQueryError { message } => assert!(
+                message.contains("group module unavailable"),
+                "the query failure should be propagated rather than reported as a missing policy: {message}",
+            ),
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_successful_approval_with_resolved_group() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        let policy = deps.api.addr_make("policy").to_string();
+        QueryGroupPolicyInfoRequest::mock_response(
+            &mut deps.querier,
+            QueryGroupPolicyInfoResponse {
+                info: Some(GroupPolicyInfo {
+                    address: policy.clone(),
+                    group_id: 9,
+                    admin: "admin".to_string(),
+                    metadata: "".to_string(),
+                    version: 1,
+                    decision_policy: None,
+                    created_at: None,
+                }),
+            },
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = approve_group_policy(deps.as_mut(), mock_env(), info, policy.clone())
+            .expect("an approval of a resolvable policy should succeed");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single message should be emitted in the response",
+        );
+        let stats = get_group_stats(&deps.storage, Uint64::new(9)).expect("stats should load");
+        assert_eq!(
+            (Uint64::zero(), Uint64::one()),
+            (stats.current_approvals, stats.current_policy_approvals),
+            "the approval should be counted as a policy approval of the resolved group",
+        );
+        let events =
+            get_approval_events(&deps.storage, &ApprovalEventFilter::default(), None, None)
+                .expect("approval events should load");
+        assert_eq!(
+            vec![(
+                Uint64::new(9),
+                Some(Addr::unchecked(&policy)),
+                ApprovalAction::Approve
+            )],
+            events
+                .into_iter()
+                .map(|event| (event.group_id, event.policy_address, event.action))
+                .collect::<Vec<_>>(),
+            "the approval should be recorded in the audit trail with its policy",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Any(AnyMsg { type_url: _, value }) => {
+                let add_attribute = MsgAddAttributeRequest::try_from(value.to_owned())
+                    .expect("expected the add attribute msg binary to deserialize correctly");
+                assert_eq!(
+                    DEFAULT_GROUP_MEMBER, &add_attribute.account,
+                    "the member should receive the attribute",
+                );
+                assert_eq!(
+                    DEFAULT_CONTRACT_ATTRIBUTE, &add_attribute.name,
+                    "the name used should be the attribute name stored in the contract",
+                );
+                assert_eq!(
+                    group_policy_attribute_value(&policy),
+                    add_attribute.value,
+                    "the policy address should be written with the group policy encoding",
+                );
+                assert_eq!(
+                    &AttributeType::String,
+                    &add_attribute.attribute_type(),
+                    "the value type should be properly written as String",
+                );
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR, &add_attribute.owner,
+                    "the contract should be the owner of the attribute",
+                );
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        };
+        assert_eq!(
+            "approve_group_policy",
+            single_attribute_for_key(&response, "action"),
+            "the action attribute should have the correct value",
+        );
+        assert_eq!(
+            policy,
+            single_attribute_for_key(&response, "policy_address"),
+            "the policy_address attribute should have the provided policy",
+        );
+        assert_eq!(
+            "9",
+            single_attribute_for_key(&response, "group_id"),
+            "the group_id attribute should have the resolved group's id",
+        );
+    }
+}
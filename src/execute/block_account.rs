@@ -0,0 +1,200 @@
+use crate::store::blocklist::{block_account as store_blocked_account, BlockedAccount};
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin, validate_reason};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Adds an account to the contract's
+/// blocklist, preventing it from recording any new approvals until it is unblocked.  Existing
+/// approvals are left untouched.  Re-blocking an already-blocked account replaces its reason.  Only
+/// the contract admin may invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `account` The bech32 address of the account to block.
+/// * `reason` A non-blank explanation for the block, such as a sanctions or offboarding reference,
+///   validated by [validate_reason].
+pub fn block_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let account = deps.api.addr_validate(&account)?;
+    let reason = validate_reason(reason)?;
+    if reason.is_empty() {
+        return ContractError::ExecuteError {
+            route: "block_account".to_string(),
+            message: "a reason must be provided when blocking an account".to_string(),
+        }
+        .to_err();
+    }
+    let previous = store_blocked_account(
+        deps.storage,
+        &BlockedAccount {
+            account: account.to_owned(),
+            reason: reason.to_owned(),
+            blocked_at_height: Uint64::new(env.block.height),
+        },
+    )?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "block_account",
+        previous.map(|entry| format!("{}: {}", entry.account, entry.reason)),
+        Some(format!("{account}: {reason}")),
+    )?;
+    Response::new()
+        .add_attribute("action", "block_account")
+        .add_attribute("account_address", account.as_str())
+        .add_attribute("reason", reason)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::block_account::block_account;
+    use crate::store::blocklist::get_blocked_account;
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::util::route_helpers::MAX_REASON_LENGTH;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{coins, Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &coins(1, "nhash"));
+        let err = block_account(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            "reason".to_string(),
+        )
+        .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = block_account(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            "reason".to_string(),
+        )
+        .expect_err("an error should occur when a non-admin blocks an account");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin blocks an account",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_blank_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = block_account(deps.as_mut(), mock_env(), info, account, "  ".to_string())
+            .expect_err("an error should occur when no reason is provided");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when no reason is provided",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_invalid_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        for reason in [
+            "a".repeat(MAX_REASON_LENGTH + 1),
+            "sanctions\ncase".to_string(),
+        ] {
+            let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+            let err = block_account(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                account.to_owned(),
+                reason.to_owned(),
+            )
+            .expect_err("an error should occur when the reason is invalid");
+            assert!(
+                matches!(err, ContractError::InvalidFormatError { .. }),
+                "an invalid format error should be emitted for reason [{reason}], but got: {err:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_successful_block() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response = block_account(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account.to_string(),
+            "sanctioned".to_string(),
+        )
+        .expect("the admin should be able to block an account");
+        assert_eq!(
+            account.as_str(),
+            single_attribute_for_key(&response, "account_address"),
+            "the account_address attribute should have the blocked account",
+        );
+        let blocked_account = get_blocked_account(&deps.storage, &account)
+            .expect("the blocklist should load")
+            .expect("the account should be on the blocklist");
+        assert_eq!(
+            "sanctioned", blocked_account.reason,
+            "the reason should be stored",
+        );
+        assert_eq!(
+            Uint64::new(mock_env().block.height),
+            blocked_account.blocked_at_height,
+            "the block height should be stored",
+        );
+        let entries =
+            get_history_entries(&deps.storage, None, None).expect("history should be fetched");
+        assert!(
+            matches!(
+                &entries.last().expect("a history entry should be recorded").event,
+                HistoryEvent::AdminAction { action, .. } if action == "block_account",
+            ),
+            "the block should be recorded as an admin action",
+        );
+    }
+}
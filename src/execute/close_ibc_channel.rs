@@ -0,0 +1,137 @@
+use crate::store::history::record_admin_action;
+use crate::store::ibc_channels::remove_channel;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, IbcMsg, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Stops relaying approval changes over a
+/// connected IBC channel and asks the IBC module to close it.  The channel is removed immediately,
+/// so no further packets are sent over it while the close handshake completes.  Only the contract
+/// admin may invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `channel_id` The identifier of the connected channel on this chain.
+pub fn close_ibc_channel(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel_id: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let Some(channel) = remove_channel(deps.storage, &channel_id)? else {
+        return ContractError::ExecuteError {
+            route: "close_ibc_channel".to_string(),
+            message: format!("channel [{channel_id}] is not connected"),
+        }
+        .to_err();
+    };
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "close_ibc_channel",
+        Some(channel.channel_id.to_owned()),
+        None,
+    )?;
+    Response::new()
+        .add_message(IbcMsg::CloseChannel {
+            channel_id: channel.channel_id.to_owned(),
+        })
+        .add_attribute("action", "close_ibc_channel")
+        .add_attribute("channel_id", channel.channel_id)
+        .add_attribute("connection_id", channel.connection_id)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::close_ibc_channel::close_ibc_channel;
+    use crate::store::ibc_channels::{get_channels, save_channel, ConnectedChannel};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, CosmosMsg, IbcEndpoint, IbcMsg, Storage, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn connect_channel(storage: &mut dyn Storage) {
+        save_channel(
+            storage,
+            &ConnectedChannel {
+                channel_id: "channel-0".to_string(),
+                counterparty_endpoint: IbcEndpoint {
+                    port_id: "their_port".to_string(),
+                    channel_id: "channel-7".to_string(),
+                },
+                connection_id: "connection-2".to_string(),
+                connected_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the channel should save");
+    }
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        connect_channel(&mut deps.storage);
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = close_ibc_channel(deps.as_mut(), mock_env(), info, "channel-0".to_string())
+            .expect_err("an error should occur when a non-admin closes a channel");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin closes a channel",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_unknown_channel() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = close_ibc_channel(deps.as_mut(), mock_env(), info, "channel-0".to_string())
+            .expect_err("an error should occur when the channel is not connected");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the channel is not connected",
+        );
+    }
+
+    #[test]
+    fn test_successful_close_ibc_channel() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        connect_channel(&mut deps.storage);
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response = close_ibc_channel(deps.as_mut(), mock_env(), info, "channel-0".to_string())
+            .expect("the admin should be able to close a channel");
+        assert!(
+            get_channels(&deps.storage)
+                .expect("channels should load")
+                .is_empty(),
+            "the channel should no longer receive relayed approvals",
+        );
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single close message should be emitted",
+        );
+        assert!(
+            matches!(
+                &response.messages[0].msg,
+                CosmosMsg::Ibc(IbcMsg::CloseChannel { channel_id }) if channel_id == "channel-0",
+            ),
+            "the IBC module should be asked to close the channel",
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::store::history::record_admin_action;
+use crate::store::ibc_channels::disallow_connection;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Removes an IBC connection from the
+/// allowlist, so that no new channels may be opened over it.  Channels that are already connected
+/// over the connection are left open, and can be closed with
+/// [close_ibc_channel](crate::execute::close_ibc_channel::close_ibc_channel).  Only the contract
+/// admin may invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `connection_id` The identifier of the IBC connection on this chain to remove.
+pub fn disallow_ibc_connection(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    connection_id: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    if disallow_connection(deps.storage, &connection_id)?.is_none() {
+        return ContractError::ExecuteError {
+            route: "disallow_ibc_connection".to_string(),
+            message: format!("connection [{connection_id}] is not allowlisted"),
+        }
+        .to_err();
+    }
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "disallow_ibc_connection",
+        Some(connection_id.to_owned()),
+        None,
+    )?;
+    Response::new()
+        .add_attribute("action", "disallow_ibc_connection")
+        .add_attribute("connection_id", connection_id)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::disallow_ibc_connection::disallow_ibc_connection;
+    use crate::store::ibc_channels::{allow_connection, is_connection_allowed, AllowedConnection};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn allowed_connection() -> AllowedConnection {
+        AllowedConnection {
+            connection_id: "connection-0".to_string(),
+            allowed_at_height: Uint64::new(1),
+        }
+    }
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        allow_connection(&mut deps.storage, &allowed_connection())
+            .expect("the connection should be allowlisted");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err =
+            disallow_ibc_connection(deps.as_mut(), mock_env(), info, "connection-0".to_string())
+                .expect_err("an error should occur when a non-admin removes a connection");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin removes a connection",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_connection_not_allowlisted() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err =
+            disallow_ibc_connection(deps.as_mut(), mock_env(), info, "connection-0".to_string())
+                .expect_err("an error should occur when the connection is not allowlisted");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the connection is not allowlisted",
+        );
+    }
+
+    #[test]
+    fn test_successful_disallow_ibc_connection() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        allow_connection(&mut deps.storage, &allowed_connection())
+            .expect("the connection should be allowlisted");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        disallow_ibc_connection(deps.as_mut(), mock_env(), info, "connection-0".to_string())
+            .expect("the admin should be able to remove a connection");
+        assert!(
+            !is_connection_allowed(&deps.storage, "connection-0"),
+            "the connection should be removed from the allowlist",
+        );
+    }
+}
@@ -0,0 +1,545 @@
+use crate::execute::revoke_group_membership::record_revocation;
+use crate::store::approval_events::ApprovalAction;
+use crate::store::contract_state::get_contract_state;
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::group_helpers::get_group_policy_group_id;
+use crate::util::prov_helpers::{
+    msg_delete_matching_attribute, parse_group_id_attribute, parse_group_policy_attribute,
+};
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin, validate_reason};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint64};
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Removes an account's approval of a group
+/// on its behalf, such as when the account is compromised or offboarded.  Only the contract can
+/// remove the value, because it is the owner of the attribute name.  The revocation is recorded in
+/// the [approval audit trail](crate::store::approval_events::ApprovalEvent) as a
+/// [ForceRevoke](ApprovalAction::ForceRevoke) with the reason as its memo, is counted separately
+/// in the group's [statistics](crate::store::group_stats::GroupStats), and is recorded in the
+/// contract's [history log](crate::store::history::HistoryEntry).  Unlike a withdrawal, it does
+/// not start the account's re-approval cooldown; use the blocklist to keep the account from
+/// approving again.  Registered hooks and connected IBC channels are notified of the revocation.
+/// Only the contract admin may invoke this route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `account` The bech32 address of the account whose approval is removed.
+/// * `group_id` The unique identifier of the group for which the approval is removed.
+/// * `reason` A non-blank explanation for the revocation, such as a compliance case reference.
+pub fn force_revoke_approval(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    group_id: Uint64,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let (account, reason) =
+        validate_force_revocation(&deps, &info, "force_revoke_approval", account, reason)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let Some(delete_msg) = msg_delete_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        account.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        env.contract.address.as_str(),
+        |attribute| parse_group_id_attribute(attribute, &attribute_name) == Some(group_id),
+    )?
+    else {
+        return ContractError::ExecuteError {
+            route: "force_revoke_approval".to_string(),
+            message: format!(
+                "group with id [{}] has not been approved by member [{}]",
+                group_id.u64(),
+                account.as_str(),
+            ),
+        }
+        .to_err();
+    };
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "force_revoke_approval",
+        Some(format!("{account}: {group_id}")),
+        Some(format!("revoked: {reason}")),
+    )?;
+    let messages = record_revocation(
+        deps.storage,
+        &env,
+        &account,
+        group_id,
+        None,
+        ApprovalAction::ForceRevoke,
+        Some(reason.to_owned()),
+    )?;
+    Response::new()
+        .add_message(delete_msg)
+        .add_submessages(messages)
+        .add_attribute("action", "force_revoke_approval")
+        .add_attribute("account_address", account.as_str())
+        .add_attribute("attribute_name", &attribute_name)
+        .add_attribute("group_id", group_id.to_string())
+        .add_attribute("reason", reason)
+        .to_ok()
+}
+
+/// Invoked via the contract's execution functionality.  Removes an account's approval of a
+/// specific [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy)
+/// on its behalf, in the same manner as [force_revoke_approval].  The policy's group is resolved
+/// via a Stargate query to the group module so that the revocation is counted in the group's
+/// statistics.  Only the contract admin may invoke this route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `account` The bech32 address of the account whose approval is removed.
+/// * `policy_address` The bech32 address of the group policy account for which the approval is
+///   removed.
+/// * `reason` A non-blank explanation for the revocation, such as a compliance case reference.
+pub fn force_revoke_policy_approval(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    policy_address: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let (account, reason) = validate_force_revocation(
+        &deps,
+        &info,
+        "force_revoke_policy_approval",
+        account,
+        reason,
+    )?;
+    let policy_address =
+        deps.api
+            .addr_validate(&policy_address)
+            .map_err(|e| ContractError::InvalidFormatError {
+                message: format!("invalid group policy address [{policy_address}]: {e}"),
+            })?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let Some(delete_msg) = msg_delete_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        account.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        env.contract.address.as_str(),
+        |attribute| {
+            parse_group_policy_attribute(attribute, &attribute_name).as_deref()
+                == Some(policy_address.as_str())
+        },
+    )?
+    else {
+        return ContractError::ExecuteError {
+            route: "force_revoke_policy_approval".to_string(),
+            message: format!(
+                "group policy [{}] has not been approved by member [{}]",
+                policy_address.as_str(),
+                account.as_str(),
+            ),
+        }
+        .to_err();
+    };
+    let group_id =
+        get_group_policy_group_id(&GroupQuerier::new(&deps.querier), policy_address.as_str())?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "force_revoke_policy_approval",
+        Some(format!("{account}: {policy_address}")),
+        Some(format!("revoked: {reason}")),
+    )?;
+    let messages = record_revocation(
+        deps.storage,
+        &env,
+        &account,
+        group_id,
+        Some(&policy_address),
+        ApprovalAction::ForceRevoke,
+        Some(reason.to_owned()),
+    )?;
+    Response::new()
+        .add_message(delete_msg)
+        .add_submessages(messages)
+        .add_attribute("action", "force_revoke_policy_approval")
+        .add_attribute("account_address", account.as_str())
+        .add_attribute("attribute_name", &attribute_name)
+        .add_attribute("policy_address", policy_address.as_str())
+        .add_attribute("group_id", group_id.to_string())
+        .add_attribute("reason", reason)
+        .to_ok()
+}
+
+/// Verifies that a forced revocation was sent by the contract admin without funds, and produces
+/// the validated account address and the non-blank reason normalized by [validate_reason].
+fn validate_force_revocation(
+    deps: &DepsMut,
+    info: &MessageInfo,
+    route: &str,
+    account: String,
+    reason: String,
+) -> Result<(Addr, String), ContractError> {
+    check_funds_are_empty(info)?;
+    check_sender_is_admin(deps.storage, info)?;
+    let account = deps.api.addr_validate(&account)?;
+    let reason = validate_reason(reason)?;
+    if reason.is_empty() {
+        return ContractError::ExecuteError {
+            route: route.to_string(),
+            message: "a reason must be provided when revoking an approval".to_string(),
+        }
+        .to_err();
+    }
+    (account, reason).to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::force_revoke_approval::force_revoke_approval;
+    use crate::store::approval_activity::check_approval_limits;
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::contract_state::{get_contract_state, set_contract_state, ApprovalLimits};
+    use crate::store::group_stats::get_group_stats;
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::test::chain_simulator::ProvenanceSimulator;
+    use crate::test::test_constants::{
+        DEFAULT_CONTRACT_ADMIN, DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER,
+    };
+    use crate::test::test_helpers::{mock_no_attributes, single_attribute_for_key};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::types::core::msg::ExecuteMsg;
+    use crate::util::route_helpers::MAX_REASON_LENGTH;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, to_json_vec, Addr, AnyMsg, CosmosMsg, Uint64};
+    use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::group::v1::{
+        GroupPolicyInfo, QueryGroupPolicyInfoRequest, QueryGroupPolicyInfoResponse,
+    };
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, MsgDeleteDistinctAttributeRequest, QueryAttributeRequest,
+        QueryAttributeResponse,
+    };
+
+    fn mock_approval(querier: &mut MockProvenanceQuerier, account: &str, group_id: u64) {
+        QueryAttributeRequest::mock_response(
+            querier,
+            QueryAttributeResponse {
+                account: account.to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    value: to_json_vec(&group_id).unwrap(),
+                    attribute_type: AttributeType::Int.into(),
+                    address: account.to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &coins(1, "nhash"));
+        let err = force_revoke_approval(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            Uint64::new(1),
+            "compromised".to_string(),
+        )
+        .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = force_revoke_approval(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            Uint64::new(1),
+            "compromised".to_string(),
+        )
+        .expect_err("an error should occur when a non-admin forces a revocation");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin forces a revocation",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_blank_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = force_revoke_approval(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            Uint64::new(1),
+            " ".to_string(),
+        )
+        .expect_err("an error should occur when no reason is provided");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when no reason is provided",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_invalid_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member").into_string();
+        for reason in ["a".repeat(MAX_REASON_LENGTH + 1), "case\u{7}12".to_string()] {
+            let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+            let err = force_revoke_approval(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                account.to_owned(),
+                Uint64::new(1),
+                reason.to_owned(),
+            )
+            .expect_err("an error should occur when the reason is invalid");
+            assert!(
+                matches!(err, ContractError::InvalidFormatError { .. }),
+                "an invalid format error should be emitted for reason [{reason}], but got: {err:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_rejection_for_missing_approval() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = force_revoke_approval(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account,
+            Uint64::new(1),
+            "compromised".to_string(),
+        )
+        .expect_err("an error should occur when the account has not approved the group");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the account has not approved the group",
+        );
+    }
+
+    #[test]
+    fn test_successful_force_revocation() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("member");
+        mock_approval(&mut deps.querier, account.as_str(), 4);
+        let mut contract_state =
+            get_contract_state(&deps.storage).expect("contract state should load");
+        contract_state.approval_limits = ApprovalLimits {
+            reapproval_cooldown_blocks: Uint64::new(100),
+            ..contract_state.approval_limits
+        };
+        set_contract_state(&mut deps.storage, &contract_state).expect("contract state should save");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response = force_revoke_approval(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            account.to_string(),
+            Uint64::new(4),
+            " compromised key ".to_string(),
+        )
+        .expect("the admin should be able to force a revocation");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single message should be emitted in the response",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Any(AnyMsg { value, .. }) => {
+                let delete_attribute =
+                    MsgDeleteDistinctAttributeRequest::try_from(value.to_owned())
+                        .expect("the delete attribute msg binary should deserialize");
+                assert_eq!(
+                    account.as_str(),
+                    delete_attribute.account,
+                    "the attribute should be removed from the target account",
+                );
+                assert_eq!(
+                    to_json_vec(&4u64).unwrap(),
+                    delete_attribute.value,
+                    "the approved value should be removed",
+                );
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR, delete_attribute.owner,
+                    "the contract should remove the attribute as its owner",
+                );
+            }
+            msg => panic!("unexpected message emitted: {msg:?}"),
+        }
+        assert_eq!(
+            "compromised key",
+            single_attribute_for_key(&response, "reason"),
+            "the normalized reason should be emitted",
+        );
+        let events =
+            get_approval_events(&deps.storage, &ApprovalEventFilter::default(), None, None)
+                .expect("approval events should load");
+        assert_eq!(
+            vec![(
+                ApprovalAction::ForceRevoke,
+                Some("compromised key".to_string())
+            )],
+            events
+                .into_iter()
+                .map(|event| (event.action, event.memo))
+                .collect::<Vec<_>>(),
+            "the revocation should be recorded as forced with its reason",
+        );
+        let stats = get_group_stats(&deps.storage, Uint64::new(4)).expect("stats should load");
+        assert_eq!(
+            Uint64::new(1),
+            stats.revocations,
+            "the revocation should be counted"
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.forced_revocations,
+            "the revocation should be counted as forced",
+        );
+        let contract_state = get_contract_state(&deps.storage).expect("contract state should load");
+        check_approval_limits(
+            &deps.storage,
+            &contract_state.approval_limits,
+            &account,
+            Uint64::new(4),
+            mock_env().block.height,
+        )
+        .expect("a forced revocation should not start the re-approval cooldown");
+        let entries =
+            get_history_entries(&deps.storage, None, None).expect("history should be fetched");
+        assert!(
+            matches!(
+                &entries.last().expect("a history entry should be recorded").event,
+                HistoryEvent::AdminAction { action, new_value, .. }
+                    if action == "force_revoke_approval"
+                        && new_value.as_deref() == Some("revoked: compromised key"),
+            ),
+            "the revocation should be recorded as an admin action",
+        );
+    }
+
+    #[test]
+    fn test_successful_force_revocation_of_policy_approval() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr(DEFAULT_GROUP_MEMBER);
+        let policy = simulator.addr("policy");
+        QueryGroupPolicyInfoRequest::mock_response(
+            &mut simulator.deps.querier,
+            QueryGroupPolicyInfoResponse {
+                info: Some(GroupPolicyInfo {
+                    address: policy.to_string(),
+                    group_id: 6,
+                    admin: "admin".to_string(),
+                    metadata: "".to_string(),
+                    version: 1,
+                    decision_policy: None,
+                    created_at: None,
+                }),
+            },
+        );
+        simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::ApproveGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect("the policy should be approved");
+        let response = simulator
+            .execute(
+                DEFAULT_CONTRACT_ADMIN,
+                ExecuteMsg::ForceRevokePolicyApproval {
+                    account: member.to_string(),
+                    policy_address: policy.to_string(),
+                    reason: "offboarded".to_string(),
+                },
+            )
+            .expect("the admin should revoke the policy approval");
+        assert_eq!(
+            "6",
+            single_attribute_for_key(&response, "group_id"),
+            "the policy's group should be emitted",
+        );
+        assert!(
+            simulator.attributes(&member).is_empty(),
+            "the policy approval attribute should be deleted",
+        );
+        let stats =
+            get_group_stats(&simulator.deps.storage, Uint64::new(6)).expect("stats should load");
+        assert_eq!(
+            (Uint64::zero(), Uint64::one(), Uint64::one()),
+            (
+                stats.current_policy_approvals,
+                stats.policy_revocations,
+                stats.forced_revocations,
+            ),
+            "the revocation should be counted as a forced policy revocation",
+        );
+        let event = get_approval_events(
+            &simulator.deps.storage,
+            &ApprovalEventFilter::default(),
+            Some(1),
+            None,
+        )
+        .expect("approval events should load")
+        .pop()
+        .expect("the revocation should be recorded");
+        assert_eq!(
+            (
+                ApprovalAction::ForceRevoke,
+                Some(policy),
+                Some("offboarded".to_string())
+            ),
+            (event.action, event.policy_address, event.memo),
+            "the revocation should be recorded with its policy and reason",
+        );
+    }
+}
@@ -1,5 +1,44 @@
 //! Contains all execution routes used by the [contract file](crate::contract).
 
+/// Allows a blockchain account to accept newly published terms for a group it has already
+/// approved.
+pub mod acknowledge_group_terms;
+/// Allows the contract admin to register a contract to be notified of approval changes.
+pub mod add_hook;
+/// Allows the contract admin to permit IBC channels to be opened over a connection.
+pub mod allow_ibc_connection;
+/// Allows a blockchain account to approve every group in which it is currently a member.
+pub mod approve_all_current_groups;
 /// The core functionality of the contract.  Allows a blockchain account to approve its membership
 /// for a given group id.
 pub mod approve_group_membership;
+/// Allows a blockchain account to approve its participation in a specific group policy account.
+pub mod approve_group_policy;
+/// Allows the contract admin to bar an account from recording new approvals.
+pub mod block_account;
+/// Allows the contract admin to close a connected IBC channel.
+pub mod close_ibc_channel;
+/// Allows the contract admin to stop permitting IBC channels to be opened over a connection.
+pub mod disallow_ibc_connection;
+/// Allows the contract admin to remove an account's approval of a group or group policy on its
+/// behalf, such as for a compromised or offboarded account.
+pub mod force_revoke_approval;
+/// Allows the contract admin to publish the terms that members must acknowledge when approving a
+/// group.
+pub mod publish_group_terms;
+/// Allows the contract admin to stop notifying a registered hook contract of approval changes.
+pub mod remove_hook;
+/// Allows a blockchain account to withdraw a previously-recorded approval of its membership for a
+/// given group id.
+pub mod revoke_group_membership;
+/// Allows a blockchain account to withdraw a previously-recorded approval of a specific group
+/// policy account.
+pub mod revoke_group_policy;
+/// Allows the contract admin to choose how failed attribute queries are handled.
+pub mod set_attribute_query_mode;
+/// Allows the contract admin to set or remove the member cap of a group.
+pub mod set_group_member_cap;
+/// Allows the contract admin to remove an account from the blocklist.
+pub mod unblock_account;
+/// Allows the contract admin to configure per-account approval rate limits and cooldowns.
+pub mod update_approval_limits;
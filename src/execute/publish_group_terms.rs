@@ -0,0 +1,180 @@
+use crate::store::group_terms::publish_group_terms as store_group_terms;
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use result_extensions::ResultExtensions;
+
+/// The maximum number of characters allowed in the URI of published terms.
+pub const MAX_TERMS_URI_LENGTH: usize = 512;
+
+/// The maximum number of characters allowed in the hex-encoded content hash of published terms.
+pub const MAX_TERMS_HASH_LENGTH: usize = 128;
+
+/// Invoked via the contract's execution functionality.  Publishes a new version of the terms that
+/// members must acknowledge when approving a group, identified by a URI and the hex-encoded hash of
+/// their content.  The version starts at 1 and increments with each publication for the group.
+/// Existing approvals are not removed, but are reported as outdated until the member accepts the
+/// new terms via
+/// [acknowledge_group_terms](crate::execute::acknowledge_group_terms::acknowledge_group_terms).
+/// Only the contract admin may invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `group_id` The unique identifier of the group to which the terms apply.
+/// * `uri` The location at which the full text of the terms can be found.
+/// * `content_hash` The hex-encoded hash of the terms' content.  Stored in lowercase.
+pub fn publish_group_terms(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    group_id: Uint64,
+    uri: String,
+    content_hash: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let uri = uri.trim();
+    if uri.is_empty() || uri.chars().count() > MAX_TERMS_URI_LENGTH {
+        return ContractError::InvalidFormatError {
+            message: format!(
+                "the terms uri must be between 1 and {MAX_TERMS_URI_LENGTH} characters"
+            ),
+        }
+        .to_err();
+    }
+    if content_hash.is_empty()
+        || content_hash.len() > MAX_TERMS_HASH_LENGTH
+        || !content_hash.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return ContractError::InvalidFormatError {
+            message: format!(
+                "the terms content hash must be between 1 and {MAX_TERMS_HASH_LENGTH} hex characters"
+            ),
+        }
+        .to_err();
+    }
+    let (previous, terms) = store_group_terms(
+        deps.storage,
+        group_id,
+        uri,
+        content_hash.to_ascii_lowercase(),
+        env.block.height,
+    )?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "publish_group_terms",
+        previous.map(|terms| {
+            format!(
+                "group {group_id}: v{} {}",
+                terms.version, terms.content_hash
+            )
+        }),
+        Some(format!(
+            "group {group_id}: v{} {}",
+            terms.version, terms.content_hash
+        )),
+    )?;
+    Response::new()
+        .add_attribute("action", "publish_group_terms")
+        .add_attribute("group_id", group_id.to_string())
+        .add_attribute("terms_version", terms.version.to_string())
+        .add_attribute("terms_uri", terms.uri)
+        .add_attribute("content_hash", terms.content_hash)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::publish_group_terms::publish_group_terms;
+    use crate::store::group_terms::get_group_terms;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = publish_group_terms(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(1),
+            "ipfs://terms".to_string(),
+            "abcd".to_string(),
+        )
+        .expect_err("an error should occur when a non-admin publishes terms");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin publishes terms",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_invalid_content_hash() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        for content_hash in ["", "not hex"] {
+            let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+            let err = publish_group_terms(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                Uint64::new(1),
+                "ipfs://terms".to_string(),
+                content_hash.to_string(),
+            )
+            .expect_err("an error should occur for an invalid content hash");
+            assert!(
+                matches!(err, ContractError::InvalidFormatError { .. }),
+                "an invalid format error should be emitted for content hash [{content_hash}]",
+            );
+        }
+    }
+
+    #[test]
+    fn test_successful_publication_increments_version() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        for (content_hash, expected_version) in [("ABCD", "1"), ("ef01", "2")] {
+            let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+            let response = publish_group_terms(
+                deps.as_mut(),
+                mock_env(),
+                info,
+                Uint64::new(1),
+                "ipfs://terms".to_string(),
+                content_hash.to_string(),
+            )
+            .expect("the admin should publish terms");
+            assert_eq!(
+                expected_version,
+                single_attribute_for_key(&response, "terms_version"),
+                "each publication should increment the terms version",
+            );
+        }
+        let terms = get_group_terms(&deps.storage, Uint64::new(1))
+            .expect("the terms should load")
+            .expect("the terms should exist");
+        assert_eq!(
+            (2, "ef01"),
+            (terms.version.u64(), terms.content_hash.as_str()),
+            "the latest publication should be stored",
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::store::history::record_admin_action;
+use crate::store::hooks::remove_hook as remove_registered_hook;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Stops notifying a previously-registered
+/// hook contract of approval changes.  Only the contract admin may invoke this route, and each
+/// invocation is recorded in the contract's [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `contract` The bech32 address of the hook contract to remove.
+pub fn remove_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    if remove_registered_hook(deps.storage, &contract)?.is_none() {
+        return ContractError::ExecuteError {
+            route: "remove_hook".to_string(),
+            message: format!(
+                "contract [{}] is not registered as a hook",
+                contract.as_str()
+            ),
+        }
+        .to_err();
+    }
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "remove_hook",
+        Some(contract.to_string()),
+        None,
+    )?;
+    Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook_address", contract.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::remove_hook::remove_hook;
+    use crate::store::hooks::{add_hook, get_hooks, RegisteredHook};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("hook");
+        add_hook(
+            &mut deps.storage,
+            &RegisteredHook {
+                contract: contract.to_owned(),
+                added_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the hook should be registered");
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = remove_hook(deps.as_mut(), mock_env(), info, contract.into_string())
+            .expect_err("an error should occur when a non-admin removes a hook");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin removes a hook",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_unregistered_hook() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("hook").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = remove_hook(deps.as_mut(), mock_env(), info, contract)
+            .expect_err("an error should occur when the hook is not registered");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the hook is not registered",
+        );
+    }
+
+    #[test]
+    fn test_successful_remove_hook() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let contract = deps.api.addr_make("hook");
+        add_hook(
+            &mut deps.storage,
+            &RegisteredHook {
+                contract: contract.to_owned(),
+                added_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the hook should be registered");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        remove_hook(deps.as_mut(), mock_env(), info, contract.into_string())
+            .expect("the admin should be able to remove a hook");
+        assert!(
+            get_hooks(&deps.storage)
+                .expect("hooks should load")
+                .is_empty(),
+            "the hook should be removed",
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use crate::store::approval_activity::{clear_approval_memo, record_account_withdrawal};
+use crate::store::approval_events::{record_approval_event, ApprovalAction};
+use crate::store::contract_state::get_contract_state;
+use crate::store::group_stats::{
+    record_forced_group_policy_revocation, record_forced_group_revocation,
+    record_group_policy_revocation, record_group_revocation,
+};
+use crate::store::group_terms::clear_accepted_terms;
+use crate::store::hooks::prepare_hook_messages;
+use crate::store::ibc_channels::prepare_relay_messages;
+use crate::types::core::error::ContractError;
+use crate::types::core::hook::ApprovalHookMsg;
+use crate::util::prov_helpers::{msg_delete_matching_attribute, parse_group_id_attribute};
+use crate::util::route_helpers::check_funds_are_empty;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Storage, SubMsg, Uint64};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Removes the attribute value from the
+/// signer that denotes that they affirm their membership in a [Provenance Blockchain Group](https://docs.cosmos.network/main/modules/group).
+/// Only the contract can remove the value, because it is the owner of the attribute name.  The
+/// route validates that the account has an existing attribute value for the group before emitting
+/// the removal.  Each withdrawal is recorded by [record_revocation] in the
+/// [approval audit trail](crate::store::approval_events::ApprovalEvent) and starts the group's
+/// re-approval cooldown for the signer.  Each [registered hook](crate::store::hooks::RegisteredHook)
+/// is notified of the withdrawal with an [ApprovalHookMsg], which is also relayed to each
+/// [connected IBC channel](crate::store::ibc_channels::ConnectedChannel).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `group_id` The unique identifier of a given group for which the signing account withdraws its
+///   consent to membership.
+pub fn revoke_group_membership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    group_id: Uint64,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let Some(delete_msg) = msg_delete_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        env.contract.address.as_str(),
+        |attribute| parse_group_id_attribute(attribute, &attribute_name) == Some(group_id),
+    )?
+    else {
+        return ContractError::ExecuteError {
+            route: "revoke_group_membership".to_string(),
+            message: format!(
+                "group with id [{}] has not been approved by member [{}]",
+                group_id.u64(),
+                info.sender.as_str(),
+            ),
+        }
+        .to_err();
+    };
+    let messages = record_revocation(
+        deps.storage,
+        &env,
+        &info.sender,
+        group_id,
+        None,
+        ApprovalAction::Withdraw,
+        None,
+    )?;
+    Response::new()
+        .add_message(delete_msg)
+        .add_submessages(messages)
+        .add_attribute("action", "revoke_group_membership")
+        .add_attribute("account_address", info.sender.as_str())
+        .add_attribute("attribute_name", &attribute_name)
+        .add_attribute("group_id", group_id.to_string())
+        .to_ok()
+}
+
+/// Records the removal of an account's approval of a group, or of one of its policy accounts, once
+/// the calling route has located the approval attribute to delete.  The removal is counted in the
+/// group's [statistics](crate::store::group_stats::GroupStats) and recorded in the
+/// [approval audit trail](crate::store::approval_events::ApprovalEvent).  A
+/// [Withdraw](ApprovalAction::Withdraw) starts the group's re-approval cooldown for the account,
+/// while a [ForceRevoke](ApprovalAction::ForceRevoke) is counted as forced and does not.  Returns
+/// the messages that notify each [registered hook](crate::store::hooks::RegisteredHook) and
+/// [connected IBC channel](crate::store::ibc_channels::ConnectedChannel).
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `account` The bech32 address of the account whose approval is removed.
+/// * `group_id` The unique identifier of the group, or of the group that owns the policy account.
+/// * `policy_address` The group policy account whose approval is removed, if the approval applied
+///   to a single policy rather than the entire group.
+/// * `action` Whether the account withdrew the approval or the admin revoked it.
+/// * `memo` An optional statement recorded with the removal, such as the admin's reason.
+pub fn record_revocation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    account: &Addr,
+    group_id: Uint64,
+    policy_address: Option<&Addr>,
+    action: ApprovalAction,
+    memo: Option<String>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let forced = action == ApprovalAction::ForceRevoke;
+    match (policy_address, forced) {
+        (None, false) => record_group_revocation(storage, account, group_id)?,
+        (None, true) => record_forced_group_revocation(storage, account, group_id)?,
+        (Some(policy), false) => {
+            record_group_policy_revocation(storage, account, policy, group_id)?
+        }
+        (Some(policy), true) => {
+            record_forced_group_policy_revocation(storage, account, policy, group_id)?
+        }
+    };
+    if policy_address.is_none() {
+        clear_accepted_terms(storage, account, group_id);
+        clear_approval_memo(storage, account, group_id);
+    }
+    if !forced {
+        record_account_withdrawal(storage, account, group_id, env.block.height)?;
+    }
+    record_approval_event(
+        storage,
+        env,
+        account,
+        group_id,
+        policy_address,
+        action.clone(),
+        memo.clone(),
+    )?;
+    let notification = ApprovalHookMsg {
+        account: account.to_owned(),
+        group_id,
+        policy_address: policy_address.cloned(),
+        action,
+        memo,
+        block_height: Uint64::new(env.block.height),
+    };
+    let mut messages = prepare_hook_messages(storage, &notification)?;
+    messages.extend(
+        prepare_relay_messages(storage, env, &notification)?
+            .into_iter()
+            .map(SubMsg::new),
+    );
+    messages.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::revoke_group_membership::revoke_group_membership;
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::contract_state::{
+        get_contract_state, set_contract_state, AttributeQueryMode,
+    };
+    use crate::store::group_stats::get_group_stats;
+    use crate::test::chain_simulator::{ProvenanceSimulator, SimulationError};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::{mock_no_attributes, single_attribute_for_key};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::types::core::msg::ExecuteMsg;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, from_json, to_json_vec, Addr, AnyMsg, CosmosMsg, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, MsgDeleteDistinctAttributeRequest, QueryAttributeRequest,
+        QueryAttributeResponse,
+    };
+
+    #[test]
+    fn test_rejection_for_provided_funds() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &coins(15, "nhash"));
+        let err = revoke_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
+            .expect_err("an error should occur when the sender provides funds");
+        assert!(
+            matches!(err, ContractError::InvalidFundsError { .. }),
+            "an invalid funds error should be emitted when the sender provides funds",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_attribute_query_in_strict_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        QueryAttributeRequest::mock_failed_response(
+            &mut deps.querier,
+            "attribute module unavailable".to_string(),
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = revoke_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
+            .expect_err("an error should occur when the attribute query fails in strict mode");
+        assert!(
+            matches!(err, ContractError::AttributeQueryError { .. }),
+            "an attribute query error should be emitted rather than a missing approval error",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_failed_attribute_query_in_lenient_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let mut contract_state =
+            get_contract_state(&deps.storage).expect("the contract state should load");
+        contract_state.attribute_query_mode = AttributeQueryMode::Lenient;
+        set_contract_state(&mut deps.storage, &contract_state)
+            .expect("the contract state should save");
+        QueryAttributeRequest::mock_failed_response(
+            &mut deps.querier,
+            "attribute module unavailable".to_string(),
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = revoke_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
+            .expect_err("an error should occur when no approval can be found in lenient mode");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "the failed query should be treated as a missing approval in lenient mode",
+        );
+    }
+    #[test]
+    fn test_rejection_for_missing_attribute() {
+        let mut deps = mock_provenance_dependencies();
+        mock_no_attributes(&mut deps.querier);
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = revoke_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(1))
+            .expect_err("an error should occur when the member has not approved the group");
+        match err {
+            ContractError::ExecuteError { route, message } => {
+                assert_eq!(
+                    "revoke_group_membership", route,
+                    "unexpected route in execute error",
+                );
+                assert_eq!(
+                    format!(
+                        "group with id [1] has not been approved by member [{DEFAULT_GROUP_MEMBER}]",
+                    ),
+                    message,
+                    "unexpected message in execute error",
+                );
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_successful_revocation() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_GROUP_MEMBER.to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    value: to_json_vec(&4u64).unwrap(),
+                    attribute_type: AttributeType::Int.into(),
+                    address: DEFAULT_GROUP_MEMBER.to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let response = revoke_group_membership(deps.as_mut(), mock_env(), info, Uint64::new(4))
+            .expect("a revocation of an approved group should succeed");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single message should be emitted in the response",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Any(AnyMsg { type_url: _, value }) => {
+                let delete_attribute = MsgDeleteDistinctAttributeRequest::try_from(
+                    value.to_owned(),
+                )
+                .expect("expected the delete attribute msg binary to deserialize correctly");
+                assert_eq!(
+                    DEFAULT_GROUP_MEMBER, &delete_attribute.account,
+                    "the attribute should be removed from the member",
+                );
+                assert_eq!(
+                    DEFAULT_CONTRACT_ATTRIBUTE, &delete_attribute.name,
+                    "the name used should be the attribute name stored in the contract",
+                );
+                assert_eq!(
+                    4,
+                    from_json::<u64>(&delete_attribute.value)
+                        .expect("the binary value should deserialize to a u64 correctly"),
+                    "the group id should be the removed value",
+                );
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR, &delete_attribute.owner,
+                    "the contract should be the owner of the removed attribute",
+                );
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        };
+        assert_eq!(
+            "revoke_group_membership",
+            single_attribute_for_key(&response, "action"),
+            "the action attribute should have the correct value",
+        );
+        assert_eq!(
+            "4",
+            single_attribute_for_key(&response, "group_id"),
+            "the group_id attribute should have the provided group's id",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            get_group_stats(deps.as_ref().storage, Uint64::new(4))
+                .expect("group stats should load")
+                .revocations,
+            "the revocation should be counted in the group stats",
+        );
+        let events = get_approval_events(
+            deps.as_ref().storage,
+            &ApprovalEventFilter::default(),
+            None,
+            None,
+        )
+        .expect("approval events should load after a revocation");
+        assert_eq!(
+            vec![ApprovalAction::Withdraw],
+            events
+                .into_iter()
+                .map(|event| event.action)
+                .collect::<Vec<_>>(),
+            "a single withdrawal event should be recorded",
+        );
+    }
+
+    #[test]
+    fn test_revocation_of_legacy_encoded_approval() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        simulator = simulator.with_attribute(Attribute {
+            name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+            value: b"\"5\"".to_vec(),
+            attribute_type: AttributeType::Int.into(),
+            address: DEFAULT_GROUP_MEMBER.to_string(),
+            expiration_date: None,
+        });
+        let err = simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(5),
+                    memo: None,
+                    terms_hash: None,
+                },
+            )
+            .expect_err("a legacy-encoded approval should be detected as a duplicate");
+        assert!(
+            matches!(
+                err,
+                SimulationError::Contract(ContractError::ExecuteError { .. })
+            ),
+            "an execute error should be emitted for the duplicate approval, but got: {err:?}",
+        );
+        simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::RevokeGroupMembership {
+                    group_id: Uint64::new(5),
+                },
+            )
+            .expect("the legacy-encoded approval should be revoked using its stored value");
+        assert!(
+            simulator.attributes(DEFAULT_GROUP_MEMBER).is_empty(),
+            "the legacy-encoded attribute should be deleted",
+        );
+    }
+}
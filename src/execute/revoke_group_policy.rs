@@ -0,0 +1,184 @@
+use crate::execute::revoke_group_membership::record_revocation;
+use crate::store::approval_events::ApprovalAction;
+use crate::store::contract_state::get_contract_state;
+use crate::types::core::error::ContractError;
+use crate::util::group_helpers::get_group_policy_group_id;
+use crate::util::prov_helpers::{msg_delete_matching_attribute, parse_group_policy_attribute};
+use crate::util::route_helpers::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Removes the attribute value from the
+/// signer that denotes that they affirm their participation in a specific [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy).
+/// The route validates that the account has an existing approval of the policy before emitting the
+/// removal, and resolves the policy's group via a Stargate query to the group module.  The
+/// withdrawal is recorded in the same manner as a group withdrawal by
+/// [record_revocation](crate::execute::revoke_group_membership::record_revocation).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `policy_address` The bech32 address of the group policy account for which the signing account
+///   withdraws its consent.
+pub fn revoke_group_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    policy_address: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let policy_address =
+        deps.api
+            .addr_validate(&policy_address)
+            .map_err(|e| ContractError::InvalidFormatError {
+                message: format!("invalid group policy address [{policy_address}]: {e}"),
+            })?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let Some(delete_msg) = msg_delete_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        info.sender.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        env.contract.address.as_str(),
+        |attribute| {
+            parse_group_policy_attribute(attribute, &attribute_name).as_deref()
+                == Some(policy_address.as_str())
+        },
+    )?
+    else {
+        return ContractError::ExecuteError {
+            route: "revoke_group_policy".to_string(),
+            message: format!(
+                "group policy [{}] has not been approved by member [{}]",
+                policy_address.as_str(),
+                info.sender.as_str(),
+            ),
+        }
+        .to_err();
+    };
+    let group_id =
+        get_group_policy_group_id(&GroupQuerier::new(&deps.querier), policy_address.as_str())?;
+    let messages = record_revocation(
+        deps.storage,
+        &env,
+        &info.sender,
+        group_id,
+        Some(&policy_address),
+        ApprovalAction::Withdraw,
+        None,
+    )?;
+    Response::new()
+        .add_message(delete_msg)
+        .add_submessages(messages)
+        .add_attribute("action", "revoke_group_policy")
+        .add_attribute("account_address", info.sender.as_str())
+        .add_attribute("attribute_name", &attribute_name)
+        .add_attribute("policy_address", policy_address.as_str())
+        .add_attribute("group_id", group_id.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::{get_approval_events, ApprovalAction, ApprovalEventFilter};
+    use crate::store::group_stats::get_group_stats;
+    use crate::test::chain_simulator::{ProvenanceSimulator, SimulationError};
+    use crate::types::core::error::ContractError;
+    use crate::types::core::msg::ExecuteMsg;
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_std::types::cosmos::group::v1::{
+        GroupPolicyInfo, QueryGroupPolicyInfoRequest, QueryGroupPolicyInfoResponse,
+    };
+
+    fn mock_policy(simulator: &mut ProvenanceSimulator, policy: &Addr) {
+        QueryGroupPolicyInfoRequest::mock_response(
+            &mut simulator.deps.querier,
+            QueryGroupPolicyInfoResponse {
+                info: Some(GroupPolicyInfo {
+                    address: policy.to_string(),
+                    group_id: 4,
+                    admin: "admin".to_string(),
+                    metadata: "".to_string(),
+                    version: 1,
+                    decision_policy: None,
+                    created_at: None,
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn test_policy_approval_can_be_withdrawn() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member");
+        let policy = simulator.addr("policy");
+        mock_policy(&mut simulator, &policy);
+        simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::ApproveGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect("the policy should be approved");
+        simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::RevokeGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect("the policy approval should be withdrawn");
+        match simulator
+            .execute(
+                member.as_str(),
+                ExecuteMsg::RevokeGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect_err("a second withdrawal should be rejected")
+        {
+            SimulationError::Contract(ContractError::ExecuteError { route, .. }) => assert_eq!(
+                "revoke_group_policy", route,
+                "the withdrawal should fail because the attribute was deleted",
+            ),
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        let stats =
+            get_group_stats(&simulator.deps.storage, Uint64::new(4)).expect("stats should load");
+        assert_eq!(
+            (Uint64::zero(), Uint64::one(), Uint64::one()),
+            (
+                stats.current_policy_approvals,
+                stats.total_policy_approvals,
+                stats.policy_revocations,
+            ),
+            "the withdrawal should be counted against the policy's group",
+        );
+        assert_eq!(
+            vec![
+                (ApprovalAction::Approve, Some(policy.to_owned())),
+                (ApprovalAction::Withdraw, Some(policy.to_owned())),
+            ],
+            get_approval_events(
+                &simulator.deps.storage,
+                &ApprovalEventFilter::default(),
+                None,
+                None,
+            )
+            .expect("approval events should load")
+            .into_iter()
+            .map(|event| (event.action, event.policy_address))
+            .collect::<Vec<_>>(),
+            "both the approval and withdrawal should be recorded in the audit trail",
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use crate::store::contract_state::{get_contract_state, set_contract_state, AttributeQueryMode};
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{to_json_string, DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Replaces the [AttributeQueryMode] stored in
+/// the contract state, which determines whether a failed chain query for an account's existing
+/// attributes aborts an approval or revocation.  Only the contract admin may invoke this route, and
+/// each invocation is recorded in the contract's [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `mode` The new mode to store.
+pub fn set_attribute_query_mode(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mode: AttributeQueryMode,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let mut contract_state = get_contract_state(deps.storage)?;
+    let previous_mode = contract_state.attribute_query_mode;
+    contract_state.attribute_query_mode = mode;
+    set_contract_state(deps.storage, &contract_state)?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_attribute_query_mode",
+        Some(to_json_string(&previous_mode)?),
+        Some(to_json_string(&mode)?),
+    )?;
+    Response::new()
+        .add_attribute("action", "set_attribute_query_mode")
+        .add_attribute(
+            "attribute_query_mode",
+            to_json_string(&mode)?.trim_matches('"'),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::set_attribute_query_mode::set_attribute_query_mode;
+    use crate::store::contract_state::{get_contract_state, AttributeQueryMode};
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err =
+            set_attribute_query_mode(deps.as_mut(), mock_env(), info, AttributeQueryMode::Lenient)
+                .expect_err("an error should occur when a non-admin sets the attribute query mode");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin sets the mode",
+        );
+    }
+
+    #[test]
+    fn test_successful_update() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        assert_eq!(
+            AttributeQueryMode::Strict,
+            get_contract_state(&deps.storage)
+                .expect("the contract state should load")
+                .attribute_query_mode,
+            "new instances should default to strict mode",
+        );
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response =
+            set_attribute_query_mode(deps.as_mut(), mock_env(), info, AttributeQueryMode::Lenient)
+                .expect("the admin should be able to set the attribute query mode");
+        assert_eq!(
+            "lenient",
+            single_attribute_for_key(&response, "attribute_query_mode"),
+            "the new mode should be emitted",
+        );
+        assert_eq!(
+            AttributeQueryMode::Lenient,
+            get_contract_state(&deps.storage)
+                .expect("the contract state should load")
+                .attribute_query_mode,
+            "the mode should be stored in the contract state",
+        );
+        let entries =
+            get_history_entries(&deps.storage, None, None).expect("history should be fetched");
+        assert!(
+            matches!(
+                &entries.last().expect("a history entry should be recorded").event,
+                HistoryEvent::AdminAction { action, old_value, .. }
+                    if action == "set_attribute_query_mode"
+                        && old_value.as_deref() == Some("\"strict\""),
+            ),
+            "the change should be recorded as an admin action with the previous mode",
+        );
+    }
+}
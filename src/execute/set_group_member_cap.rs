@@ -0,0 +1,120 @@
+use crate::store::group_caps::set_group_member_cap as store_group_member_cap;
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Sets or removes the maximum number of
+/// accounts that may consent to membership in a group.  The cap is enforced against the contract's
+/// own approval count for the group, so lowering a cap below the current count prevents new
+/// approvals without affecting existing ones.  Only the contract admin may invoke this route, and
+/// each invocation is recorded in the contract's [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `group_id` The unique identifier of the group to cap.
+/// * `max_members` The maximum number of consenting members, or `None` to remove the cap.
+pub fn set_group_member_cap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    group_id: Uint64,
+    max_members: Option<Uint64>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let max_members = max_members.map(|max| max.u64());
+    let previous = store_group_member_cap(deps.storage, group_id, max_members)?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "set_group_member_cap",
+        previous.map(|max| format!("group {group_id}: {max}")),
+        max_members.map(|max| format!("group {group_id}: {max}")),
+    )?;
+    Response::new()
+        .add_attribute("action", "set_group_member_cap")
+        .add_attribute("group_id", group_id.to_string())
+        .add_attribute(
+            "max_members",
+            max_members.map_or("none".to_string(), |max| max.to_string()),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::set_group_member_cap::set_group_member_cap;
+    use crate::store::group_caps::get_group_member_cap;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = set_group_member_cap(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            Uint64::new(1),
+            Some(Uint64::new(5)),
+        )
+        .expect_err("an error should occur when a non-admin sets a cap");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin sets a cap",
+        );
+    }
+
+    #[test]
+    fn test_set_and_remove_cap() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let response = set_group_member_cap(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            Uint64::new(1),
+            Some(Uint64::new(5)),
+        )
+        .expect("the admin should be able to set a cap");
+        assert_eq!(
+            "5",
+            single_attribute_for_key(&response, "max_members"),
+            "the max_members attribute should have the new cap",
+        );
+        assert_eq!(
+            Some(5),
+            get_group_member_cap(&deps.storage, Uint64::new(1)).expect("the cap should load"),
+            "the cap should be stored",
+        );
+        let response = set_group_member_cap(deps.as_mut(), mock_env(), info, Uint64::new(1), None)
+            .expect("the admin should be able to remove a cap");
+        assert_eq!(
+            "none",
+            single_attribute_for_key(&response, "max_members"),
+            "the max_members attribute should denote the removed cap",
+        );
+        assert_eq!(
+            None,
+            get_group_member_cap(&deps.storage, Uint64::new(1)).expect("the cap should load"),
+            "the cap should be removed",
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::store::blocklist::unblock_account as remove_blocked_account;
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Removes an account from the contract's
+/// blocklist, allowing it to record new approvals again.  Only the contract admin may invoke this
+/// route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `account` The bech32 address of the account to unblock.
+pub fn unblock_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    let account = deps.api.addr_validate(&account)?;
+    let Some(removed) = remove_blocked_account(deps.storage, &account)? else {
+        return ContractError::ExecuteError {
+            route: "unblock_account".to_string(),
+            message: format!("account [{}] is not blocked", account.as_str()),
+        }
+        .to_err();
+    };
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "unblock_account",
+        Some(format!("{}: {}", removed.account, removed.reason)),
+        None,
+    )?;
+    Response::new()
+        .add_attribute("action", "unblock_account")
+        .add_attribute("account_address", account.as_str())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::unblock_account::unblock_account;
+    use crate::store::blocklist::{block_account, get_blocked_account, BlockedAccount};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err = unblock_account(deps.as_mut(), mock_env(), info, account)
+            .expect_err("an error should occur when a non-admin unblocks an account");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin unblocks an account",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_account_that_is_not_blocked() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked").into_string();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = unblock_account(deps.as_mut(), mock_env(), info, account)
+            .expect_err("an error should occur when the account is not blocked");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when the account is not blocked",
+        );
+    }
+
+    #[test]
+    fn test_successful_unblock() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let account = deps.api.addr_make("blocked");
+        block_account(
+            &mut deps.storage,
+            &BlockedAccount {
+                account: account.to_owned(),
+                reason: "offboarded".to_string(),
+                blocked_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the account should be blocked");
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        unblock_account(deps.as_mut(), mock_env(), info, account.to_string())
+            .expect("the admin should be able to unblock an account");
+        assert!(
+            get_blocked_account(&deps.storage, &account)
+                .expect("the blocklist should load")
+                .is_none(),
+            "the account should be removed from the blocklist",
+        );
+    }
+}
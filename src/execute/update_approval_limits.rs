@@ -0,0 +1,135 @@
+use crate::store::contract_state::{get_contract_state, set_contract_state, ApprovalLimits};
+use crate::store::history::record_admin_action;
+use crate::types::core::error::ContractError;
+use crate::util::route_helpers::{check_funds_are_empty, check_sender_is_admin};
+use cosmwasm_std::{to_json_string, DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execution functionality.  Replaces the [ApprovalLimits] stored in the
+/// contract state, which govern how frequently a single account may record approvals.  Approvals
+/// recorded while rate limiting was disabled are not counted towards a newly-enabled limit.  Only
+/// the contract admin may invoke this route, and each invocation is recorded in the contract's
+/// [history log](crate::store::history::HistoryEntry).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `approval_limits` The new limits to store.
+pub fn update_approval_limits(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    approval_limits: ApprovalLimits,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    check_sender_is_admin(deps.storage, &info)?;
+    if approval_limits.max_approvals_per_window.is_some() && approval_limits.window_blocks.is_zero()
+    {
+        return ContractError::ExecuteError {
+            route: "update_approval_limits".to_string(),
+            message: "window_blocks must be greater than zero when max_approvals_per_window is set"
+                .to_string(),
+        }
+        .to_err();
+    }
+    let mut contract_state = get_contract_state(deps.storage)?;
+    let previous_limits = contract_state.approval_limits;
+    contract_state.approval_limits = approval_limits.to_owned();
+    set_contract_state(deps.storage, &contract_state)?;
+    record_admin_action(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_approval_limits",
+        Some(to_json_string(&previous_limits)?),
+        Some(to_json_string(&approval_limits)?),
+    )?;
+    Response::new()
+        .add_attribute("action", "update_approval_limits")
+        .add_attribute(
+            "max_approvals_per_window",
+            approval_limits
+                .max_approvals_per_window
+                .map_or("none".to_string(), |max| max.to_string()),
+        )
+        .add_attribute("window_blocks", approval_limits.window_blocks.to_string())
+        .add_attribute(
+            "reapproval_cooldown_blocks",
+            approval_limits.reapproval_cooldown_blocks.to_string(),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::update_approval_limits::update_approval_limits;
+    use crate::store::contract_state::{get_contract_state, ApprovalLimits};
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env};
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_rejection_for_non_admin_sender() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]);
+        let err =
+            update_approval_limits(deps.as_mut(), mock_env(), info, ApprovalLimits::default())
+                .expect_err("an error should occur when a non-admin updates the limits");
+        assert!(
+            matches!(err, ContractError::UnauthorizedError { .. }),
+            "an unauthorized error should be emitted when a non-admin updates the limits",
+        );
+    }
+
+    #[test]
+    fn test_rejection_for_empty_window() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let err = update_approval_limits(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ApprovalLimits {
+                max_approvals_per_window: Some(Uint64::new(1)),
+                window_blocks: Uint64::new(0),
+                reapproval_cooldown_blocks: Uint64::new(0),
+            },
+        )
+        .expect_err("an error should occur when a maximum is set without a window");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted when a maximum is set without a window",
+        );
+    }
+
+    #[test]
+    fn test_successful_update() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(3)),
+            window_blocks: Uint64::new(100),
+            reapproval_cooldown_blocks: Uint64::new(20),
+        };
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        update_approval_limits(deps.as_mut(), mock_env(), info, limits.clone())
+            .expect("the admin should be able to update the limits");
+        assert_eq!(
+            limits,
+            get_contract_state(&deps.storage)
+                .expect("the contract state should load")
+                .approval_limits,
+            "the limits should be stored in the contract state",
+        );
+    }
+}
@@ -0,0 +1,741 @@
+use crate::store::approval_events::ApprovalEventFilter;
+use crate::store::contract_state::{ApprovalLimits, AttributeQueryMode, ContractState};
+use crate::store::group_caps::GroupCapacity;
+use crate::store::group_stats::GroupStats;
+use crate::store::group_terms::TermsAcceptance;
+use crate::types::core::msg::{ExecuteMsg, QueryMsg};
+use crate::types::core::response::{
+    QueryAllGroupStatsResponse, QueryApprovalEventsResponse, QueryAttributeDiagnosticsResponse,
+    QueryBlockedAccountsResponse, QueryGroupConsentStatusResponse, QueryGroupTermsResponse,
+    QueryHistoryResponse, QueryHooksResponse, QueryIbcChannelsResponse,
+};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, CustomQuery, QuerierWrapper, StdResult, Uint64,
+    WasmMsg, WasmQuery,
+};
+use cw2::ContractVersion;
+use serde::de::DeserializeOwned;
+
+/// A wrapper around the address of a deployed instance of this contract, allowing other contracts
+/// to build its execute messages and run its queries without hand-building JSON payloads.  Enable
+/// the `library` feature when depending on this crate to exclude its entry points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupMemberApprovalContract(pub Addr);
+impl GroupMemberApprovalContract {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` The bech32 address of the deployed contract instance.
+    pub fn new(address: Addr) -> Self {
+        Self(address)
+    }
+
+    /// Returns the bech32 address of the wrapped contract instance.
+    pub fn addr(&self) -> Addr {
+        self.0.to_owned()
+    }
+
+    /// Builds a [WasmMsg::Execute] that invokes the given execute route with no funds attached, as
+    /// all routes in this contract reject funds.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` The execute route to invoke.
+    pub fn execute<T>(&self, msg: ExecuteMsg) -> StdResult<CosmosMsg<T>> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into_string(),
+            msg: to_json_binary(&msg)?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    /// Runs a [WasmQuery::Smart] against the given query route and deserializes its response.
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `msg` The query route to invoke.
+    pub fn query<Q: CustomQuery, T: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        msg: QueryMsg,
+    ) -> StdResult<T> {
+        querier.query(
+            &WasmQuery::Smart {
+                contract_addr: self.addr().into_string(),
+                msg: to_json_binary(&msg)?,
+            }
+            .into(),
+        )
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::ApproveGroupMembership].  The calling contract
+    /// becomes the approving account.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the group to approve.
+    /// * `memo` An optional statement describing the capacity of the approval.
+    /// * `terms_hash` The content hash of the group's published terms, if any.
+    pub fn approve_group_membership<T>(
+        &self,
+        group_id: u64,
+        memo: Option<String>,
+        terms_hash: Option<String>,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::ApproveGroupMembership {
+            group_id: Uint64::new(group_id),
+            memo,
+            terms_hash,
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::ApproveGroupPolicy].  The calling contract
+    /// becomes the approving account.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy_address` The bech32 address of the group policy account to approve.
+    pub fn approve_group_policy<S: Into<String>, T>(
+        &self,
+        policy_address: S,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::ApproveGroupPolicy {
+            policy_address: policy_address.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::ApproveAllCurrentGroups].  The calling contract
+    /// becomes the approving account.
+    pub fn approve_all_current_groups<T>(&self) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::ApproveAllCurrentGroups {})
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::AcknowledgeGroupTerms].  The calling contract
+    /// becomes the acknowledging account.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the approved group.
+    /// * `terms_hash` The content hash of the group's currently published terms.
+    pub fn acknowledge_group_terms<S: Into<String>, T>(
+        &self,
+        group_id: u64,
+        terms_hash: S,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::AcknowledgeGroupTerms {
+            group_id: Uint64::new(group_id),
+            terms_hash: terms_hash.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::RevokeGroupMembership].  The calling contract
+    /// becomes the withdrawing account.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the group for which to withdraw approval.
+    pub fn revoke_group_membership<T>(&self, group_id: u64) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::RevokeGroupMembership {
+            group_id: Uint64::new(group_id),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::RevokeGroupPolicy].  The calling contract
+    /// becomes the withdrawing account.
+    ///
+    /// # Parameters
+    ///
+    /// * `policy_address` The bech32 address of the group policy account for which to withdraw
+    ///   approval.
+    pub fn revoke_group_policy<S: Into<String>, T>(
+        &self,
+        policy_address: S,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::RevokeGroupPolicy {
+            policy_address: policy_address.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::BlockAccount].  Only succeeds if the calling
+    /// contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `account` The bech32 address of the account to block.
+    /// * `reason` The reason for the block.
+    pub fn block_account<S1: Into<String>, S2: Into<String>, T>(
+        &self,
+        account: S1,
+        reason: S2,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::BlockAccount {
+            account: account.into(),
+            reason: reason.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::UnblockAccount].  Only succeeds if the calling
+    /// contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `account` The bech32 address of the account to unblock.
+    pub fn unblock_account<S: Into<String>, T>(&self, account: S) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::UnblockAccount {
+            account: account.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::SetGroupMemberCap].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the group to cap.
+    /// * `max_members` The maximum number of consenting members, or `None` to remove the cap.
+    pub fn set_group_member_cap<T>(
+        &self,
+        group_id: u64,
+        max_members: Option<u64>,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::SetGroupMemberCap {
+            group_id: Uint64::new(group_id),
+            max_members: max_members.map(Uint64::new),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::PublishGroupTerms].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the group to which the terms apply.
+    /// * `uri` The location at which the full text of the terms can be found.
+    /// * `content_hash` The hex-encoded hash of the terms' content.
+    pub fn publish_group_terms<S1: Into<String>, S2: Into<String>, T>(
+        &self,
+        group_id: u64,
+        uri: S1,
+        content_hash: S2,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::PublishGroupTerms {
+            group_id: Uint64::new(group_id),
+            uri: uri.into(),
+            content_hash: content_hash.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::UpdateApprovalLimits].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `approval_limits` The new limits to store.
+    pub fn update_approval_limits<T>(
+        &self,
+        approval_limits: ApprovalLimits,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::UpdateApprovalLimits { approval_limits })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::SetAttributeQueryMode].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `mode` The new mode to store.
+    pub fn set_attribute_query_mode<T>(&self, mode: AttributeQueryMode) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::SetAttributeQueryMode { mode })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::ForceRevokeApproval].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `account` The bech32 address of the account whose approval is removed.
+    /// * `group_id` The unique identifier of the group for which the approval is removed.
+    /// * `reason` The reason for the revocation.
+    pub fn force_revoke_approval<S1: Into<String>, S2: Into<String>, T>(
+        &self,
+        account: S1,
+        group_id: u64,
+        reason: S2,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::ForceRevokeApproval {
+            account: account.into(),
+            group_id: Uint64::new(group_id),
+            reason: reason.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::ForceRevokePolicyApproval].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `account` The bech32 address of the account whose approval is removed.
+    /// * `policy_address` The bech32 address of the group policy account for which the approval is
+    ///   removed.
+    /// * `reason` The reason for the revocation.
+    pub fn force_revoke_policy_approval<S1: Into<String>, S2: Into<String>, S3: Into<String>, T>(
+        &self,
+        account: S1,
+        policy_address: S2,
+        reason: S3,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::ForceRevokePolicyApproval {
+            account: account.into(),
+            policy_address: policy_address.into(),
+            reason: reason.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::AddHook].  Only succeeds if the calling contract
+    /// is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `contract` The bech32 address of the contract to notify of approval changes.
+    pub fn add_hook<S: Into<String>, T>(&self, contract: S) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::AddHook {
+            contract: contract.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::RemoveHook].  Only succeeds if the calling
+    /// contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `contract` The bech32 address of the hook contract to remove.
+    pub fn remove_hook<S: Into<String>, T>(&self, contract: S) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::RemoveHook {
+            contract: contract.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::AllowIbcConnection].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `connection_id` The identifier of the IBC connection over which channels may be opened.
+    pub fn allow_ibc_connection<S: Into<String>, T>(
+        &self,
+        connection_id: S,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::AllowIbcConnection {
+            connection_id: connection_id.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::DisallowIbcConnection].  Only succeeds if the
+    /// calling contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `connection_id` The identifier of the IBC connection to remove from the allowlist.
+    pub fn disallow_ibc_connection<S: Into<String>, T>(
+        &self,
+        connection_id: S,
+    ) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::DisallowIbcConnection {
+            connection_id: connection_id.into(),
+        })
+    }
+
+    /// Builds a message that invokes [ExecuteMsg::CloseIbcChannel].  Only succeeds if the calling
+    /// contract is the admin of the wrapped instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel_id` The identifier of the connected channel to close.
+    pub fn close_ibc_channel<S: Into<String>, T>(&self, channel_id: S) -> StdResult<CosmosMsg<T>> {
+        self.execute(ExecuteMsg::CloseIbcChannel {
+            channel_id: channel_id.into(),
+        })
+    }
+
+    /// Fetches the wrapped instance's [ContractState] via [QueryMsg::QueryContractState].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    pub fn contract_state<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+    ) -> StdResult<ContractState> {
+        self.query(querier, QueryMsg::QueryContractState {})
+    }
+
+    /// Fetches the wrapped instance's cw2 version info via [QueryMsg::QueryContractInfo].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    pub fn contract_info<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+    ) -> StdResult<ContractVersion> {
+        self.query(querier, QueryMsg::QueryContractInfo {})
+    }
+
+    /// Fetches a page of the wrapped instance's history log via [QueryMsg::QueryHistory].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `start_after` If provided, only entries with an identifier greater than this value will be
+    ///   returned.
+    /// * `limit` The maximum number of entries to return.
+    pub fn history<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryHistoryResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryHistory {
+                start_after: start_after.map(Uint64::new),
+                limit,
+            },
+        )
+    }
+
+    /// Fetches the approval counters for a group via [QueryMsg::QueryGroupStats].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `group_id` The unique identifier of the group.
+    pub fn group_stats<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        group_id: u64,
+    ) -> StdResult<GroupStats> {
+        self.query(
+            querier,
+            QueryMsg::QueryGroupStats {
+                group_id: Uint64::new(group_id),
+            },
+        )
+    }
+
+    /// Fetches a page of approval counters for all groups via [QueryMsg::QueryAllGroupStats].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `start_after` If provided, only groups with an id greater than this value will be returned.
+    /// * `limit` The maximum number of groups to return.
+    pub fn all_group_stats<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryAllGroupStatsResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryAllGroupStats {
+                start_after: start_after.map(Uint64::new),
+                limit,
+            },
+        )
+    }
+
+    /// Fetches a page of the approval audit trail via [QueryMsg::QueryApprovalEvents].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `filter` The criteria that each returned event must match.
+    /// * `start_after` If provided, only events with a sequence greater than this value will be
+    ///   returned.
+    /// * `limit` The maximum number of events to return.
+    pub fn approval_events<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        filter: ApprovalEventFilter,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryApprovalEventsResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryApprovalEvents {
+                account: filter.account,
+                group_id: filter.group_id,
+                min_height: filter.min_height,
+                max_height: filter.max_height,
+                min_time: filter.min_time,
+                max_time: filter.max_time,
+                start_after: start_after.map(Uint64::new),
+                limit,
+            },
+        )
+    }
+
+    /// Fetches a page of the accounts barred from recording approvals via
+    /// [QueryMsg::QueryBlockedAccounts].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `start_after` If provided, only accounts with an address greater than this value will be
+    ///   returned.
+    /// * `limit` The maximum number of accounts to return.
+    pub fn blocked_accounts<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryBlockedAccountsResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryBlockedAccounts { start_after, limit },
+        )
+    }
+
+    /// Fetches the member cap and remaining capacity of a group via
+    /// [QueryMsg::QueryGroupCapacity].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `group_id` The unique identifier of the group.
+    pub fn group_capacity<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        group_id: u64,
+    ) -> StdResult<GroupCapacity> {
+        self.query(
+            querier,
+            QueryMsg::QueryGroupCapacity {
+                group_id: Uint64::new(group_id),
+            },
+        )
+    }
+
+    /// Fetches a page of a group's members and whether each has recorded an approval via
+    /// [QueryMsg::QueryGroupConsentStatus].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `group_id` The unique identifier of the group.
+    /// * `page_key` The `next_key` returned with the previous page, or `None` for the first page.
+    /// * `limit` The maximum number of members to return.
+    pub fn group_consent_status<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        group_id: u64,
+        page_key: Option<Binary>,
+        limit: Option<u32>,
+    ) -> StdResult<QueryGroupConsentStatusResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryGroupConsentStatus {
+                group_id: Uint64::new(group_id),
+                page_key,
+                limit,
+            },
+        )
+    }
+
+    /// Fetches the terms currently published for a group via [QueryMsg::QueryGroupTerms].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `group_id` The unique identifier of the group.
+    pub fn group_terms<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        group_id: u64,
+    ) -> StdResult<QueryGroupTermsResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryGroupTerms {
+                group_id: Uint64::new(group_id),
+            },
+        )
+    }
+
+    /// Fetches the version of a group's terms accepted with an account's approval via
+    /// [QueryMsg::QueryTermsAcceptance].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `account` The bech32 address of the account to inspect.
+    /// * `group_id` The unique identifier of the group.
+    pub fn terms_acceptance<Q: CustomQuery, S: Into<String>>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        account: S,
+        group_id: u64,
+    ) -> StdResult<TermsAcceptance> {
+        self.query(
+            querier,
+            QueryMsg::QueryTermsAcceptance {
+                account: account.into(),
+                group_id: Uint64::new(group_id),
+            },
+        )
+    }
+
+    /// Lists the undecodable values under the wrapped instance's attribute name on an account via
+    /// [QueryMsg::QueryAttributeDiagnostics].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    /// * `account` The bech32 address of the account to inspect.
+    pub fn attribute_diagnostics<Q: CustomQuery, S: Into<String>>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        account: S,
+    ) -> StdResult<QueryAttributeDiagnosticsResponse> {
+        self.query(
+            querier,
+            QueryMsg::QueryAttributeDiagnostics {
+                account: account.into(),
+            },
+        )
+    }
+
+    /// Fetches the contracts notified of approval changes via [QueryMsg::QueryHooks].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    pub fn hooks<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+    ) -> StdResult<QueryHooksResponse> {
+        self.query(querier, QueryMsg::QueryHooks {})
+    }
+
+    /// Fetches the connected IBC channels and allowlisted connections via
+    /// [QueryMsg::QueryIbcChannels].
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` A querier provided by the calling contract's dependencies.
+    pub fn ibc_channels<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+    ) -> StdResult<QueryIbcChannelsResponse> {
+        self.query(querier, QueryMsg::QueryIbcChannels {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::query;
+    use crate::helpers::GroupMemberApprovalContract;
+    use crate::store::group_stats::record_group_approval;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::msg::{ExecuteMsg, QueryMsg};
+    use cosmwasm_std::testing::{mock_env, MockQuerier};
+    use cosmwasm_std::{
+        from_json, Addr, CosmosMsg, Empty, QuerierWrapper, SystemError, SystemResult, Uint64,
+        WasmMsg, WasmQuery,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const CONTRACT_ADDRESS: &str = "approvalcontract";
+
+    #[test]
+    fn test_execute_messages_target_contract() {
+        let contract = GroupMemberApprovalContract::new(Addr::unchecked(CONTRACT_ADDRESS));
+        let msg: CosmosMsg = contract
+            .approve_group_membership(3, Some("signer".to_string()), None)
+            .expect("the message should be built");
+        match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                assert_eq!(
+                    CONTRACT_ADDRESS, contract_addr,
+                    "the message should target the wrapped contract",
+                );
+                assert!(funds.is_empty(), "no funds should be attached");
+                assert_eq!(
+                    ExecuteMsg::ApproveGroupMembership {
+                        group_id: Uint64::new(3),
+                        memo: Some("signer".to_string()),
+                        terms_hash: None,
+                    },
+                    from_json::<ExecuteMsg>(&msg).expect("the message should deserialize"),
+                    "the execute payload should be correctly built",
+                );
+            }
+            msg => panic!("unexpected message built: {:?}", msg),
+        };
+    }
+
+    #[test]
+    fn test_queries_return_typed_responses() {
+        let mut contract_deps = mock_provenance_dependencies();
+        test_instantiate(contract_deps.as_mut());
+        record_group_approval(
+            &mut contract_deps.storage,
+            &Addr::unchecked("account"),
+            Uint64::new(4),
+            10,
+        )
+        .expect("the approval should be recorded");
+        // Route smart queries from the calling contract's querier into the approval contract
+        let mut querier = MockQuerier::<Empty>::new(&[]);
+        querier.update_wasm(move |request| match request {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == CONTRACT_ADDRESS => {
+                let msg = from_json::<QueryMsg>(msg).expect("the query should deserialize");
+                SystemResult::Ok(
+                    query(contract_deps.as_ref(), mock_env(), msg)
+                        .map_err(|e| e.to_string())
+                        .into(),
+                )
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "unexpected wasm query".to_string(),
+            }),
+        });
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let contract = GroupMemberApprovalContract::new(Addr::unchecked(CONTRACT_ADDRESS));
+        let stats = contract
+            .group_stats(&querier, 4)
+            .expect("the group stats query should succeed");
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "the group stats should be returned from the wrapped contract",
+        );
+        let capacity = contract
+            .group_capacity(&querier, 4)
+            .expect("the group capacity query should succeed");
+        assert_eq!(
+            None, capacity.remaining_capacity,
+            "an uncapped group should be reported",
+        );
+        let state = contract
+            .contract_state(&querier)
+            .expect("the contract state query should succeed");
+        assert_eq!(
+            "groupmember.test.pb", state.attribute_name,
+            "the contract state should be returned from the wrapped contract",
+        );
+        assert!(
+            GroupMemberApprovalContract::new(Addr::unchecked("other"))
+                .contract_state(&querier)
+                .is_err(),
+            "queries against a different address should not be routed to the contract",
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use crate::store::ibc_channels::{
+    channel_limit_reached, is_connection_allowed, remove_channel, save_channel, ConnectedChannel,
+    MAX_IBC_CHANNELS,
+};
+use crate::types::core::error::ContractError;
+use crate::types::core::ibc::IBC_APP_VERSION;
+use cosmwasm_std::{
+    DepsMut, Env, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, Storage, Uint64,
+};
+use result_extensions::ResultExtensions;
+
+/// Invoked during the first step of the IBC channel handshake.  Only unordered channels using the
+/// [IBC_APP_VERSION] are accepted, because a timed-out packet on an ordered channel would close it.
+/// The channel's connection must be on the admin-managed allowlist, and no more than
+/// [MAX_IBC_CHANNELS] channels may be connected at once.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `msg` The channel open message provided by the IBC module.
+pub fn open_channel(
+    deps: DepsMut,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let channel = msg.channel();
+    check_channel_version(channel, msg.counterparty_version())?;
+    if channel.order != IbcOrder::Unordered {
+        return channel_error(channel, "only unordered channels are supported").to_err();
+    }
+    check_channel_capacity(deps.storage, channel)?;
+    Some(Ibc3ChannelOpenResponse {
+        version: IBC_APP_VERSION.to_string(),
+    })
+    .to_ok()
+}
+
+/// Invoked when the IBC channel handshake completes.  Stores the channel so that subsequent
+/// approval changes are relayed over it.  The allowlist and channel limit are checked again, because
+/// the admin may have disallowed the connection, or other channels may have connected, since the
+/// handshake began.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `msg` The channel connect message provided by the IBC module.
+pub fn connect_channel(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    check_channel_version(channel, msg.counterparty_version())?;
+    check_channel_capacity(deps.storage, channel)?;
+    save_channel(
+        deps.storage,
+        &ConnectedChannel {
+            channel_id: channel.endpoint.channel_id.to_owned(),
+            counterparty_endpoint: channel.counterparty_endpoint.to_owned(),
+            connection_id: channel.connection_id.to_owned(),
+            connected_at_height: Uint64::new(env.block.height),
+        },
+    )?;
+    IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id)
+        .to_ok()
+}
+
+/// Invoked when an IBC channel is closed.  Approval changes are no longer relayed over the channel.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `msg` The channel close message provided by the IBC module.
+pub fn close_channel(
+    deps: DepsMut,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = &msg.channel().endpoint.channel_id;
+    remove_channel(deps.storage, channel_id)?;
+    IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", channel_id)
+        .to_ok()
+}
+
+fn check_channel_version(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.version != IBC_APP_VERSION {
+        return channel_error(
+            channel,
+            format!("version [{}] must be [{IBC_APP_VERSION}]", channel.version),
+        )
+        .to_err();
+    }
+    if let Some(version) = counterparty_version.filter(|version| *version != IBC_APP_VERSION) {
+        return channel_error(
+            channel,
+            format!("counterparty version [{version}] must be [{IBC_APP_VERSION}]"),
+        )
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn check_channel_capacity(
+    storage: &dyn Storage,
+    channel: &IbcChannel,
+) -> Result<(), ContractError> {
+    if !is_connection_allowed(storage, &channel.connection_id) {
+        return channel_error(
+            channel,
+            format!("connection [{}] is not allowlisted", channel.connection_id),
+        )
+        .to_err();
+    }
+    if channel_limit_reached(storage) {
+        return channel_error(
+            channel,
+            format!("no more than [{MAX_IBC_CHANNELS}] channels may be connected"),
+        )
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn channel_error<S: Into<String>>(channel: &IbcChannel, message: S) -> ContractError {
+    ContractError::IbcChannelError {
+        channel_id: channel.endpoint.channel_id.to_owned(),
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ibc::channel::{close_channel, connect_channel, open_channel};
+    use crate::store::ibc_channels::{
+        allow_connection, disallow_connection, get_channels, AllowedConnection, MAX_IBC_CHANNELS,
+    };
+    use crate::types::core::error::ContractError;
+    use crate::types::core::ibc::IBC_APP_VERSION;
+    use cosmwasm_std::testing::{
+        mock_env, mock_ibc_channel, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+        mock_ibc_channel_connect_confirm, mock_ibc_channel_open_init, mock_ibc_channel_open_try,
+    };
+    use cosmwasm_std::{IbcChannelConnectMsg, IbcOrder, Storage, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn allow_mock_connection(storage: &mut dyn Storage) {
+        allow_connection(
+            storage,
+            &AllowedConnection {
+                connection_id: mock_ibc_channel("channel-0", IbcOrder::Unordered, IBC_APP_VERSION)
+                    .connection_id,
+                allowed_at_height: Uint64::new(1),
+            },
+        )
+        .expect("the connection should be allowlisted");
+    }
+
+    #[test]
+    fn test_open_accepts_matching_version() {
+        let mut deps = mock_provenance_dependencies();
+        allow_mock_connection(&mut deps.storage);
+        for msg in [
+            mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+            mock_ibc_channel_open_try("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        ] {
+            let response = open_channel(deps.as_mut(), msg)
+                .expect("a channel with the app version should open");
+            assert_eq!(
+                Some(IBC_APP_VERSION),
+                response.as_ref().map(|response| response.version.as_str()),
+                "the app version should be negotiated",
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_rejects_connection_not_allowlisted() {
+        let mut deps = mock_provenance_dependencies();
+        let err = open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_try("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect_err("a channel over a connection that is not allowlisted should be rejected");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted for a connection that is not allowlisted",
+        );
+    }
+
+    #[test]
+    fn test_open_rejects_wrong_version() {
+        let mut deps = mock_provenance_dependencies();
+        let err = open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, "ics20-1"),
+        )
+        .expect_err("a channel with another version should be rejected");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted for a mismatched version",
+        );
+    }
+
+    #[test]
+    fn test_open_rejects_ordered_channel() {
+        let mut deps = mock_provenance_dependencies();
+        let err = open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_init("channel-0", IbcOrder::Ordered, IBC_APP_VERSION),
+        )
+        .expect_err("an ordered channel should be rejected");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted for an ordered channel",
+        );
+    }
+
+    #[test]
+    fn test_open_rejects_channels_beyond_limit() {
+        let mut deps = mock_provenance_dependencies();
+        allow_mock_connection(&mut deps.storage);
+        for index in 0..MAX_IBC_CHANNELS {
+            connect_channel(
+                deps.as_mut(),
+                mock_env(),
+                mock_ibc_channel_connect_confirm(
+                    &format!("channel-{index}"),
+                    IbcOrder::Unordered,
+                    IBC_APP_VERSION,
+                ),
+            )
+            .expect("channels up to the limit should connect");
+        }
+        let err = open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_init("channel-99", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect_err("a channel beyond the limit should be rejected");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted beyond the channel limit",
+        );
+    }
+
+    #[test]
+    fn test_connect_and_close_channel() {
+        let mut deps = mock_provenance_dependencies();
+        allow_mock_connection(&mut deps.storage);
+        let response = connect_channel(
+            deps.as_mut(),
+            mock_env(),
+            mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect("the channel should connect");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "channel_id" && attr.value == "channel-0"),
+            "the channel_id attribute should be emitted",
+        );
+        let channels = get_channels(&deps.storage).expect("channels should load");
+        assert_eq!(1, channels.len(), "the connected channel should be stored");
+        assert_eq!(
+            "channel-7", channels[0].counterparty_endpoint.channel_id,
+            "the counterparty endpoint should be stored",
+        );
+        close_channel(
+            deps.as_mut(),
+            mock_ibc_channel_close_init("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect("the channel should close");
+        assert!(
+            get_channels(&deps.storage)
+                .expect("channels should load")
+                .is_empty(),
+            "the closed channel should be removed",
+        );
+    }
+
+    #[test]
+    fn test_connect_rejects_wrong_counterparty_version() {
+        let mut deps = mock_provenance_dependencies();
+        let msg = IbcChannelConnectMsg::new_ack(
+            mock_ibc_channel("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+            "group-member-approval-2",
+        );
+        let err = connect_channel(deps.as_mut(), mock_env(), msg)
+            .expect_err("a mismatched counterparty version should be rejected");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted for a mismatched counterparty version",
+        );
+        assert!(
+            get_channels(&deps.storage)
+                .expect("channels should load")
+                .is_empty(),
+            "the rejected channel should not be stored",
+        );
+    }
+
+    #[test]
+    fn test_connect_rejects_connection_disallowed_during_handshake() {
+        let mut deps = mock_provenance_dependencies();
+        allow_mock_connection(&mut deps.storage);
+        open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect("the channel should open over an allowlisted connection");
+        let connection_id =
+            mock_ibc_channel("channel-0", IbcOrder::Unordered, IBC_APP_VERSION).connection_id;
+        disallow_connection(&mut deps.storage, &connection_id)
+            .expect("the connection should be removed from the allowlist");
+        let err = connect_channel(
+            deps.as_mut(),
+            mock_env(),
+            mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect_err("a channel over a disallowed connection should not connect");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted for a connection that is no longer allowlisted",
+        );
+        assert!(
+            get_channels(&deps.storage)
+                .expect("channels should load")
+                .is_empty(),
+            "the rejected channel should not be stored",
+        );
+    }
+
+    #[test]
+    fn test_connect_rejects_channels_beyond_limit() {
+        let mut deps = mock_provenance_dependencies();
+        allow_mock_connection(&mut deps.storage);
+        open_channel(
+            deps.as_mut(),
+            mock_ibc_channel_open_init("channel-99", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect("the channel should open while below the limit");
+        for index in 0..MAX_IBC_CHANNELS {
+            connect_channel(
+                deps.as_mut(),
+                mock_env(),
+                mock_ibc_channel_connect_confirm(
+                    &format!("channel-{index}"),
+                    IbcOrder::Unordered,
+                    IBC_APP_VERSION,
+                ),
+            )
+            .expect("channels up to the limit should connect");
+        }
+        let err = connect_channel(
+            deps.as_mut(),
+            mock_env(),
+            mock_ibc_channel_connect_ack("channel-99", IbcOrder::Unordered, IBC_APP_VERSION),
+        )
+        .expect_err("a channel beyond the limit should not connect");
+        assert!(
+            matches!(err, ContractError::IbcChannelError { .. }),
+            "an ibc channel error should be emitted beyond the channel limit",
+        );
+        assert_eq!(
+            MAX_IBC_CHANNELS,
+            get_channels(&deps.storage)
+                .expect("channels should load")
+                .len(),
+            "the rejected channel should not be stored",
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Contains the functionality used in the [contract file](crate::contract) to relay approvals to
+//! counterparties on other chains over IBC.
+
+/// The channel handshake and close handling.
+pub mod channel;
+/// The handling of received packets and of acknowledgements and timeouts for sent packets.
+pub mod packet;
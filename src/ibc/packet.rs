@@ -0,0 +1,293 @@
+use crate::store::contract_state::get_contract_state;
+use crate::types::core::error::ContractError;
+use crate::types::core::ibc::{ApprovalPacket, ApprovalPacketV1, HasApprovedAck};
+use crate::util::prov_helpers::{account_has_matching_attribute, parse_group_id_attribute};
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Deps, DepsMut, IbcBasicResponse, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, StdAck, Uint64,
+};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Invoked when a counterparty sends a packet to this contract.  Answers
+/// [HasApproved](ApprovalPacketV1::HasApproved) packets by checking the account's attributes on
+/// this chain.  Any failure, including an unsupported packet version, is returned to the
+/// counterparty as an error acknowledgement rather than failing the relayer's transaction.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `msg` The packet receive message provided by the IBC module.
+pub fn receive_packet(
+    deps: DepsMut,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let ack = match handle_packet(deps.as_ref(), &msg.packet.data) {
+        Ok(data) => StdAck::success(data),
+        Err(e) => StdAck::error(e.to_string()),
+    };
+    IbcReceiveResponse::new(ack.to_binary())
+        .add_attribute("action", "ibc_packet_receive")
+        .add_attribute("channel_id", &msg.packet.dest.channel_id)
+        .add_attribute("success", ack.is_success().to_string())
+        .to_ok()
+}
+
+/// Invoked when a counterparty acknowledges an approval change packet sent by this contract.
+/// Failed acknowledgements are surfaced as event attributes; the change is not resent.
+///
+/// # Parameters
+///
+/// * `msg` The packet acknowledgement message provided by the IBC module.
+pub fn acknowledge_packet(msg: IbcPacketAckMsg) -> Result<IbcBasicResponse, ContractError> {
+    let response = IbcBasicResponse::new()
+        .add_attribute("action", "ibc_packet_ack")
+        .add_attribute("channel_id", &msg.original_packet.src.channel_id)
+        .add_attribute("sequence", msg.original_packet.sequence.to_string());
+    match from_json::<StdAck>(&msg.acknowledgement.data) {
+        Ok(StdAck::Success(_)) => response.add_attribute("success", "true"),
+        Ok(StdAck::Error(error)) => response
+            .add_attribute("success", "false")
+            .add_attribute("error", error),
+        Err(e) => response
+            .add_attribute("success", "false")
+            .add_attribute("error", format!("undecodable acknowledgement: {e}")),
+    }
+    .to_ok()
+}
+
+/// Invoked when an approval change packet sent by this contract is not relayed before its timeout.
+/// The timeout is surfaced as an event attribute; the change is not resent.
+///
+/// # Parameters
+///
+/// * `msg` The packet timeout message provided by the IBC module.
+pub fn timeout_packet(msg: IbcPacketTimeoutMsg) -> Result<IbcBasicResponse, ContractError> {
+    IbcBasicResponse::new()
+        .add_attribute("action", "ibc_packet_timeout")
+        .add_attribute("channel_id", &msg.packet.src.channel_id)
+        .add_attribute("sequence", msg.packet.sequence.to_string())
+        .to_ok()
+}
+
+fn handle_packet(deps: Deps, data: &Binary) -> Result<Binary, ContractError> {
+    let packet =
+        from_json::<ApprovalPacket>(data).map_err(|e| ContractError::InvalidFormatError {
+            message: format!("unsupported packet: {e}"),
+        })?;
+    match packet {
+        ApprovalPacket::V1(ApprovalPacketV1::HasApproved { account, group_id }) => {
+            to_json_binary(&HasApprovedAck {
+                approved: has_approved(deps, &account, group_id)?,
+                account,
+                group_id,
+            })?
+            .to_ok()
+        }
+        ApprovalPacket::V1(ApprovalPacketV1::ApprovalChanged(_)) => {
+            ContractError::InvalidFormatError {
+                message: "approval changes are only relayed from this chain".to_string(),
+            }
+            .to_err()
+        }
+    }
+}
+
+fn has_approved(deps: Deps, account: &str, group_id: Uint64) -> Result<bool, ContractError> {
+    let account = deps.api.addr_validate(account)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    account_has_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        account.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        |attribute| parse_group_id_attribute(attribute, &attribute_name) == Some(group_id),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ibc::packet::{acknowledge_packet, receive_packet, timeout_packet};
+    use crate::store::approval_events::ApprovalAction;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::hook::ApprovalHookMsg;
+    use crate::types::core::ibc::{ApprovalPacket, ApprovalPacketV1, HasApprovedAck};
+    use cosmwasm_std::testing::{
+        mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout,
+    };
+    use cosmwasm_std::{
+        from_json, to_json_binary, to_json_vec, Addr, IbcAcknowledgement, IbcBasicResponse, StdAck,
+        Uint64,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributeRequest, QueryAttributeResponse,
+    };
+    use std::collections::BTreeMap;
+
+    fn has_approved_packet(account: &str, group_id: u64) -> ApprovalPacket {
+        ApprovalPacket::V1(ApprovalPacketV1::HasApproved {
+            account: account.to_string(),
+            group_id: Uint64::new(group_id),
+        })
+    }
+
+    fn attribute_value(response: &IbcBasicResponse, key: &str) -> String {
+        response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.to_owned())
+            .unwrap_or_else(|| panic!("attribute [{key}] should be emitted"))
+    }
+
+    #[test]
+    fn test_has_approved_query_is_answered() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let member = deps.api.addr_make("member").into_string();
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: member.to_owned(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    value: to_json_vec(&4u64).unwrap(),
+                    attribute_type: AttributeType::Int.into(),
+                    address: member.to_owned(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        for (group_id, approved) in [(4, true), (5, false)] {
+            let response = receive_packet(
+                deps.as_mut(),
+                mock_ibc_packet_recv("channel-0", &has_approved_packet(&member, group_id))
+                    .expect("the packet should serialize"),
+            )
+            .expect("the packet should be received");
+            let ack = from_json::<StdAck>(
+                response
+                    .acknowledgement
+                    .expect("an acknowledgement should be written"),
+            )
+            .expect("the acknowledgement should deserialize");
+            assert_eq!(
+                HasApprovedAck {
+                    account: member.to_owned(),
+                    group_id: Uint64::new(group_id),
+                    approved,
+                },
+                from_json(ack.unwrap()).expect("the ack data should deserialize"),
+                "the approval status of group [{group_id}] should be returned",
+            );
+        }
+    }
+
+    #[test]
+    fn test_failed_attribute_query_is_returned_as_error_ack() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        QueryAttributeRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        let member = deps.api.addr_make("member").into_string();
+        let response = receive_packet(
+            deps.as_mut(),
+            mock_ibc_packet_recv("channel-0", &has_approved_packet(&member, 1))
+                .expect("the packet should serialize"),
+        )
+        .expect("a failed query should not fail the relayer transaction");
+        let ack = from_json::<StdAck>(
+            response
+                .acknowledgement
+                .expect("an acknowledgement should be written"),
+        )
+        .expect("the acknowledgement should deserialize");
+        assert!(
+            !ack.is_success(),
+            "an error acknowledgement should be written"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_packets_are_rejected_with_error_ack() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let relayed_change = to_json_binary(&ApprovalPacket::V1(
+            ApprovalPacketV1::ApprovalChanged(ApprovalHookMsg {
+                account: Addr::unchecked(DEFAULT_GROUP_MEMBER),
+                group_id: Uint64::new(1),
+                policy_address: None,
+                action: ApprovalAction::Approve,
+                memo: None,
+                block_height: Uint64::new(1),
+            }),
+        ))
+        .expect("the packet should serialize");
+        let future_version = to_json_binary(&BTreeMap::from([("v2", "has_approved")]))
+            .expect("the packet should serialize");
+        for data in [relayed_change, future_version] {
+            let mut msg =
+                mock_ibc_packet_recv("channel-0", &has_approved_packet(DEFAULT_GROUP_MEMBER, 1))
+                    .expect("the packet should serialize");
+            msg.packet.data = data;
+            let response = receive_packet(deps.as_mut(), msg)
+                .expect("an unsupported packet should not fail the relayer transaction");
+            let ack = from_json::<StdAck>(
+                response
+                    .acknowledgement
+                    .expect("an acknowledgement should be written"),
+            )
+            .expect("the acknowledgement should deserialize");
+            assert!(
+                !ack.is_success(),
+                "an error acknowledgement should be written for an unsupported packet",
+            );
+        }
+    }
+
+    #[test]
+    fn test_acknowledgements_and_timeouts_are_reported() {
+        let packet = has_approved_packet(DEFAULT_GROUP_MEMBER, 1);
+        let success = acknowledge_packet(
+            mock_ibc_packet_ack(
+                "channel-0",
+                &packet,
+                IbcAcknowledgement::new(StdAck::success(b"{}").to_binary()),
+            )
+            .expect("the ack should serialize"),
+        )
+        .expect("a successful ack should be handled");
+        assert_eq!(
+            "true",
+            attribute_value(&success, "success"),
+            "a successful ack should be reported",
+        );
+        let failure = acknowledge_packet(
+            mock_ibc_packet_ack(
+                "channel-0",
+                &packet,
+                IbcAcknowledgement::new(StdAck::error("rejected").to_binary()),
+            )
+            .expect("the ack should serialize"),
+        )
+        .expect("an error ack should be handled");
+        assert_eq!(
+            "rejected",
+            attribute_value(&failure, "error"),
+            "the counterparty error should be reported",
+        );
+        let timeout = timeout_packet(
+            mock_ibc_packet_timeout("channel-0", &packet).expect("the timeout should serialize"),
+        )
+        .expect("a timeout should be handled");
+        assert_eq!(
+            "ibc_packet_timeout",
+            attribute_value(&timeout, "action"),
+            "the timeout should be reported",
+        );
+    }
+}
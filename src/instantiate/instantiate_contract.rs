@@ -1,26 +1,28 @@
+use crate::store::contract_info::set_contract_info;
 use crate::store::contract_state::{set_contract_state, ContractState};
 use crate::types::core::error::ContractError;
 use crate::types::core::msg::InstantiateMsg;
 use crate::util::prov_helpers::msg_bind_name;
+use crate::util::provenance_name::ProvenanceName;
 use crate::util::route_helpers::check_funds_are_empty;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use result_extensions::ResultExtensions;
 
 /// The main functionality executed when the smart contract is first instantiated.  This creates the
-/// singleton instance of [ContractState] used to verify the attribute name used in the contract,
-/// as well as optionally binding the contract's name if it does not need to be bound after creation
-/// due to namespace restrictions.
+/// singleton instance of [ContractState] used to verify the attribute name used in the contract and
+/// the cw2-compatible contract version info, as well as optionally binding the contract's name if
+/// it does not need to be bound after creation due to namespace restrictions.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom instantiation message defined by this contract for creating the initial
-/// configuration used by the contract.
+///   configuration used by the contract.
 pub fn instantiate_contract(
     deps: DepsMut,
     env: Env,
@@ -34,14 +36,15 @@ pub fn instantiate_contract(
         }
         .to_err();
     }
-    if msg.attribute_name.is_empty() {
+    if let Err(e) = ProvenanceName::parse(&msg.attribute_name) {
         return ContractError::InstantiationError {
-            message: "Provided attribute name must not be empty".to_string(),
+            message: format!("Provided attribute name is invalid: {e}"),
         }
         .to_err();
     }
     let contract_state = ContractState::new(info.sender, &msg.attribute_name, &msg.contract_name);
     set_contract_state(deps.storage, &contract_state)?;
+    set_contract_info(deps.storage)?;
     let mut response = Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute("contract_name", &msg.contract_name)
@@ -59,7 +62,7 @@ pub fn instantiate_contract(
 #[cfg(test)]
 mod tests {
     use crate::instantiate::instantiate_contract::instantiate_contract;
-    use crate::store::contract_state::get_contract_state;
+    use crate::store::contract_state::{get_contract_state, CONTRACT_TYPE, CONTRACT_VERSION};
     use crate::test::test_constants::{
         DEFAULT_CONTRACT_ADMIN, DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_CONTRACT_NAME,
     };
@@ -122,13 +125,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instantiate_with_malformed_attribute_name() {
+        let mut deps = mock_provenance_dependencies();
+        let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
+        let msg = InstantiateMsg {
+            contract_name: DEFAULT_CONTRACT_NAME.to_string(),
+            attribute_name: "some..name".to_string(),
+            bind_attribute_name: false,
+        };
+        match instantiate_contract(deps.as_mut(), mock_env(), info, msg)
+            .expect_err("an error should occur when a malformed attribute name is used")
+        {
+            ContractError::InstantiationError { message } => assert_eq!(
+                "Provided attribute name is invalid: invalid format: invalid provenance name [some..name]: name must not contain empty segments",
+                message,
+                "unexpected error message when a malformed attribute name is used",
+            ),
+            e => panic!("unexpected error emitted: {:?}", e),
+        }
+    }
+
     #[test]
     fn test_valid_instantiate_without_binding_name() {
         let mut deps = mock_provenance_dependencies();
         let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
         let msg = InstantiateMsg {
             contract_name: "some contract name".to_string(),
-            attribute_name: "some attribute name".to_string(),
+            attribute_name: "someattribute.pb".to_string(),
             bind_attribute_name: false,
         };
         let response = instantiate_contract(deps.as_mut(), mock_env(), info, msg)
@@ -153,7 +177,7 @@ mod tests {
             "the contract_name attribute in the response should be set correctly",
         );
         assert_eq!(
-            "some attribute name",
+            "someattribute.pb",
             single_attribute_for_key(&response, "contract_attribute"),
             "the contract_attribute attribute in the response should be set correctly",
         );
@@ -164,9 +188,19 @@ mod tests {
             "the supplied contract name should be used in the contract state",
         );
         assert_eq!(
-            "some attribute name", &contract_state.attribute_name,
+            "someattribute.pb", &contract_state.attribute_name,
             "the supplied attribute name should be used in the contract state",
         );
+        let contract_info = cw2::get_contract_version(deps.as_ref().storage)
+            .expect("cw2 contract info should be available after instantiation");
+        assert_eq!(
+            CONTRACT_TYPE, contract_info.contract,
+            "the cw2 contract info should contain the contract type",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, contract_info.version,
+            "the cw2 contract info should contain the contract version",
+        );
     }
 
     #[test]
@@ -175,7 +209,7 @@ mod tests {
         let info = message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]);
         let msg = InstantiateMsg {
             contract_name: "some contract name".to_string(),
-            attribute_name: "some attribute name".to_string(),
+            attribute_name: "someattribute.pb".to_string(),
             bind_attribute_name: true,
         };
         let response = instantiate_contract(deps.as_mut(), mock_env(), info, msg)
@@ -194,8 +228,8 @@ mod tests {
                     .record
                     .expect("expected the MsgBindNameRequest to contain a record node");
                 assert_eq!(
-                    "some attribute name", record.name,
-                    "the provided attribute name should be used in the name binding msg",
+                    "someattribute", record.name,
+                    "the leaf of the provided attribute name should be used in the name binding msg",
                 );
                 assert_eq!(
                     MOCK_CONTRACT_ADDR, record.address,
@@ -224,7 +258,7 @@ mod tests {
             "the contract_name attribute in the response should be set correctly",
         );
         assert_eq!(
-            "some attribute name",
+            "someattribute.pb",
             single_attribute_for_key(&response, "contract_attribute"),
             "the contract_attribute attribute in the response should be set correctly",
         );
@@ -235,7 +269,7 @@ mod tests {
             "the supplied contract name should be used in the contract state",
         );
         assert_eq!(
-            "some attribute name", &contract_state.attribute_name,
+            "someattribute.pb", &contract_state.attribute_name,
             "the supplied attribute name should be used in the contract state",
         );
     }
@@ -13,12 +13,18 @@
 pub mod contract;
 /// All commands that are available when executing this contract.
 pub mod execute;
+/// Typed wrappers that allow other contracts to execute and query this contract.
+pub mod helpers;
+/// Defines the IBC channel handshake and packet handling used to relay approvals to other chains.
+pub mod ibc;
 /// Defines the contract instantiation process.
 pub mod instantiate;
 /// Defines the contract migration process.
 pub mod migrate;
 /// Defines the contract query process.
 pub mod query;
+/// Defines the handling of replies to submessages dispatched by the contract.
+pub mod reply;
 /// Contains all internal storage communication functionality.
 pub mod store;
 /// Contains all declared structs for internal and external communication.
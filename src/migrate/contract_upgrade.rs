@@ -1,30 +1,58 @@
+use crate::migrate::migration_steps::{run_migration_steps, MIGRATION_STEPS};
+use crate::store::contract_info::{get_contract_info, set_contract_info};
 use crate::store::contract_state::{
-    get_contract_state, set_contract_state, ContractState, CONTRACT_TYPE, CONTRACT_VERSION,
+    get_contract_state, set_contract_state, CONTRACT_TYPE, CONTRACT_VERSION,
 };
+use crate::store::history::record_migration;
 use crate::types::core::error::ContractError;
-use cosmwasm_std::{to_json_binary, DepsMut, Response};
+use crate::types::core::msg::MigrationOptions;
+use cosmwasm_std::{to_json_binary, DepsMut, Env, Response};
+use cw2::ContractVersion;
 use result_extensions::ResultExtensions;
 use semver::Version;
 
 /// The main entrypoint function for running a code migration.  Auxiliary code run when a stored
 /// instance of this contract on chain is migrated over the existing instance.  Verifies that the
-/// new code instance is a newer version than the current version, and then modifies the contract
-/// state to reflect the new version information contained in the stored file.
+/// new code instance is a newer version than the current version (unless overridden by the
+/// provided options), and then modifies the contract
+/// state and the cw2-compatible contract version info to reflect the new version information
+/// contained in the stored file.  Before the version is updated, each registered
+/// [migration step](crate::migrate::migration_steps::MIGRATION_STEPS) that applies to the stored
+/// version is run in order.  Each successful migration is recorded in the contract's history log.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
-pub fn contract_upgrade(deps: DepsMut) -> Result<Response, ContractError> {
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `options` Explicit overrides for the version checks performed before the migration.
+pub fn contract_upgrade(
+    deps: DepsMut,
+    env: Env,
+    options: MigrationOptions,
+) -> Result<Response, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+    check_valid_migration(&contract_info, &options)?;
+    let executed_steps = run_migration_steps(
+        deps.storage,
+        MIGRATION_STEPS,
+        &contract_info.version.parse::<Version>()?,
+    )?;
     let mut contract_state = get_contract_state(deps.storage)?;
-    check_valid_migration(&contract_state)?;
     contract_state.contract_version = CONTRACT_VERSION.to_string();
     set_contract_state(deps.storage, &contract_state)?;
-    Response::new()
+    set_contract_info(deps.storage)?;
+    record_migration(deps.storage, &env, &contract_info.version, CONTRACT_VERSION)?;
+    let mut response = Response::new()
         .add_attribute("action", "migrate_contract")
         .add_attribute("new_version", CONTRACT_VERSION)
-        .set_data(to_json_binary(&contract_state)?)
-        .to_ok()
+        .add_attribute("allow_same_version", options.allow_same_version.to_string())
+        .add_attribute("allow_downgrade", options.allow_downgrade.to_string());
+    if !executed_steps.is_empty() {
+        response = response.add_attribute("migration_steps", executed_steps.join(","));
+    }
+    response.set_data(to_json_binary(&contract_state)?).to_ok()
 }
 
 /// Verifies that the executing migration has a valid contract type and contract version based on
@@ -32,26 +60,41 @@ pub fn contract_upgrade(deps: DepsMut) -> Result<Response, ContractError> {
 ///
 /// # Parameters
 ///
-/// * `contract_state` The current contract state instance from the store.
-fn check_valid_migration(contract_state: &ContractState) -> Result<(), ContractError> {
+/// * `contract_info` The currently-stored contract version info, derived from either the cw2
+///   `contract_info` value or the [ContractState](crate::store::contract_state::ContractState) for
+///   older instances.
+/// * `options` Explicit overrides for the version checks.  The contract type check is always
+///   performed.
+fn check_valid_migration(
+    contract_info: &ContractVersion,
+    options: &MigrationOptions,
+) -> Result<(), ContractError> {
     // Prevent other contracts of different types from migrating over this one
-    if CONTRACT_TYPE != contract_state.contract_type {
+    if CONTRACT_TYPE != contract_info.contract {
         return ContractError::MigrationError {
             message: format!(
                 "target migration contract type [{CONTRACT_TYPE}] does not match stored contract type [{}]",
-                contract_state.contract_type,
+                contract_info.contract,
             ),
         }
         .to_err();
     }
-    let existing_contract_version = contract_state.contract_version.parse::<Version>()?;
+    let existing_contract_version = contract_info.version.parse::<Version>()?;
     let new_contract_version = CONTRACT_VERSION.parse::<Version>()?;
-    // Ensure only new contract versions are allowed
-    if existing_contract_version >= new_contract_version {
+    // Ensure only new contract versions are allowed unless explicitly overridden
+    if existing_contract_version == new_contract_version && !options.allow_same_version {
+        return ContractError::MigrationError {
+            message: format!(
+                "target migration contract version [{CONTRACT_VERSION}] is identical to the stored contract version. allow_same_version must be set to migrate to the same version",
+            ),
+        }
+        .to_err();
+    }
+    if existing_contract_version > new_contract_version && !options.allow_downgrade {
         return ContractError::MigrationError {
             message: format!(
                 "target migration contract version [{CONTRACT_VERSION}] is too low to use. stored contract version is [{}]",
-                &contract_state.contract_version,
+                &contract_info.version,
             )
         }
         .to_err();
@@ -61,25 +104,25 @@ fn check_valid_migration(contract_state: &ContractState) -> Result<(), ContractE
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Storage, Uint64};
     use provwasm_mocks::mock_provenance_dependencies;
 
     use crate::migrate::contract_upgrade::contract_upgrade;
     use crate::store::contract_state::{
         get_contract_state, set_contract_state, CONTRACT_TYPE, CONTRACT_VERSION,
     };
-    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::store::history::{get_history_entries, HistoryEvent};
+    use crate::test::test_helpers::{set_stored_contract_version, single_attribute_for_key};
     use crate::test::test_instantiate::test_instantiate;
     use crate::types::core::error::ContractError;
+    use crate::types::core::msg::MigrationOptions;
 
     #[test]
     fn test_successful_migration() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate(deps.as_mut());
-        let mut contract_state = get_contract_state(deps.as_ref().storage)
-            .expect("contract state should load after instantiation");
-        contract_state.contract_version = "0.0.1".to_string();
-        set_contract_state(deps.as_mut().storage, &contract_state)
-            .expect("contract state should save successfully");
+        set_stored_contract_version(deps.as_mut().storage, CONTRACT_TYPE, "0.0.1");
         assert_eq!(
             "0.0.1",
             get_contract_state(deps.as_ref().storage)
@@ -87,17 +130,22 @@ mod tests {
                 .contract_version,
             "sanity check: contract version should be successfully updated",
         );
-        let response = contract_upgrade(deps.as_mut())
+        let response = contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
             .expect("contract migration should succeed when versions are appropriately set");
         assert!(
             response.messages.is_empty(),
             "migrations should never produce messages",
         );
         assert_eq!(
-            2,
+            5,
             response.attributes.len(),
             "the correct number of attributes should be emitted",
         );
+        assert_eq!(
+            "materialize_contract_state_settings",
+            single_attribute_for_key(&response, "migration_steps"),
+            "the registered migration steps for the stored version should be reported",
+        );
         assert_eq!(
             "migrate_contract",
             single_attribute_for_key(&response, "action"),
@@ -108,6 +156,16 @@ mod tests {
             single_attribute_for_key(&response, "new_version"),
             "the correct new_version attribute value should be produced",
         );
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, "allow_same_version"),
+            "the correct allow_same_version attribute value should be produced",
+        );
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, "allow_downgrade"),
+            "the correct allow_downgrade attribute value should be produced",
+        );
 
         let contract_state = get_contract_state(deps.as_ref().storage)
             .expect("contract state should load after a migration");
@@ -115,18 +173,116 @@ mod tests {
             CONTRACT_VERSION, contract_state.contract_version,
             "the contract state should have its contract version altered by the migration",
         );
+        assert_eq!(
+            CONTRACT_VERSION,
+            cw2::get_contract_version(deps.as_ref().storage)
+                .expect("cw2 contract info should load after a migration")
+                .version,
+            "the cw2 contract info should have its version altered by the migration",
+        );
+        let history = get_history_entries(deps.as_ref().storage, None, None)
+            .expect("the history log should load after a migration");
+        assert_eq!(
+            1,
+            history.len(),
+            "a single history entry should be recorded for the migration",
+        );
+        assert_eq!(
+            HistoryEvent::Migration {
+                from_version: "0.0.1".to_string(),
+                to_version: CONTRACT_VERSION.to_string(),
+            },
+            history[0].event,
+            "the migration should be recorded with its from and to versions",
+        );
+        assert_eq!(
+            Uint64::new(mock_env().block.height),
+            history[0].block_height,
+            "the migration should be recorded with the current block height",
+        );
     }
 
     #[test]
-    fn test_invalid_migration_scenarios() {
+    fn test_successful_migration_from_instance_without_cw2_info() {
         let mut deps = mock_provenance_dependencies();
         test_instantiate(deps.as_mut());
         let mut contract_state = get_contract_state(deps.as_ref().storage)
-            .expect("expected contract state to load after instantiation");
-        contract_state.contract_type = "unexpected contract type".to_string();
+            .expect("contract state should load after instantiation");
+        contract_state.contract_version = "0.0.1".to_string();
         set_contract_state(deps.as_mut().storage, &contract_state)
-            .expect("expected contract state to be stored correctly");
-        let err = contract_upgrade(deps.as_mut())
+            .expect("contract state should save successfully");
+        // Simulate an older instance that was created before cw2 info was written
+        cw2::CONTRACT.remove(deps.as_mut().storage);
+        contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default()).expect(
+            "contract migration should succeed using the version stored in the contract state",
+        );
+        let contract_info = cw2::get_contract_version(deps.as_ref().storage)
+            .expect("cw2 contract info should be written by the migration");
+        assert_eq!(
+            CONTRACT_TYPE, contract_info.contract,
+            "the cw2 contract info should contain the contract type",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, contract_info.version,
+            "the cw2 contract info should contain the new contract version",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_from_legacy_fixture() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage.set(
+            b"contract_state",
+            include_bytes!("../test/fixtures/contract_state_v1_0_2.json"),
+        );
+        let response = contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
+            .expect("contract migration should succeed from a legacy stored contract state");
+        assert_eq!(
+            "materialize_contract_state_settings",
+            single_attribute_for_key(&response, "migration_steps"),
+            "the contract state settings step should run for the legacy fixture",
+        );
+        let contract_state = get_contract_state(deps.as_ref().storage)
+            .expect("contract state should load after a migration");
+        assert_eq!(
+            CONTRACT_VERSION, contract_state.contract_version,
+            "the legacy contract state should be upgraded to the current version",
+        );
+        assert_eq!(
+            "groupmember.test.pb", contract_state.attribute_name,
+            "the legacy attribute name should be retained",
+        );
+    }
+
+    #[test]
+    fn test_successful_migration_from_v1_0_3_fixture_with_default_options() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage.set(
+            b"contract_state",
+            include_bytes!("../test/fixtures/contract_state_v1_0_3.json"),
+        );
+        let response = contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
+            .expect("a 1.0.3 instance should migrate without overriding the version checks");
+        assert_eq!(
+            "materialize_contract_state_settings",
+            single_attribute_for_key(&response, "migration_steps"),
+            "the contract state settings step should run for a 1.0.3 instance",
+        );
+        assert_eq!(
+            CONTRACT_VERSION,
+            get_contract_state(deps.as_ref().storage)
+                .expect("contract state should load after a migration")
+                .contract_version,
+            "the 1.0.3 contract state should be upgraded to the current version",
+        );
+    }
+
+    #[test]
+    fn test_invalid_migration_scenarios() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        set_stored_contract_version(deps.as_mut().storage, "unexpected contract type", "0.0.1");
+        let err = contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
             .expect_err("an error should occur when migrating from a different contract type");
         match err {
             ContractError::MigrationError { message } => {
@@ -138,13 +294,11 @@ mod tests {
             }
             e => panic!("unexpected error emitted: {:?}", e),
         };
-        contract_state.contract_type = CONTRACT_TYPE.to_string();
-        contract_state.contract_version = "999.999.999".to_string();
-        set_contract_state(deps.as_mut().storage, &contract_state)
-            .expect("expected contract state to be stored successfully after a modification");
-        let err = contract_upgrade(deps.as_mut()).expect_err(
-            "an error should be produced if the contract is downgraded to a lower version",
-        );
+        set_stored_contract_version(deps.as_mut().storage, CONTRACT_TYPE, "999.999.999");
+        let err = contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
+            .expect_err(
+                "an error should be produced if the contract is downgraded to a lower version",
+            );
         match err {
             ContractError::MigrationError { message } => {
                 assert_eq!(
@@ -155,5 +309,111 @@ mod tests {
             }
             e => panic!("unexpected error emitted: {:?}", e),
         };
+        assert!(
+            get_history_entries(deps.as_ref().storage, None, None)
+                .expect("the history log should load")
+                .is_empty(),
+            "failed migrations should not be recorded in the history log",
+        );
+    }
+
+    #[test]
+    fn test_migration_policy_combinations() {
+        // (stored version, allow_same_version, allow_downgrade, expected success)
+        let scenarios = [
+            (CONTRACT_VERSION, false, false, false),
+            (CONTRACT_VERSION, true, false, true),
+            (CONTRACT_VERSION, false, true, false),
+            (CONTRACT_VERSION, true, true, true),
+            ("999.999.999", false, false, false),
+            ("999.999.999", true, false, false),
+            ("999.999.999", false, true, true),
+            ("999.999.999", true, true, true),
+            ("0.0.1", false, false, true),
+            ("0.0.1", true, false, true),
+            ("0.0.1", false, true, true),
+            ("0.0.1", true, true, true),
+        ];
+        for (stored_version, allow_same_version, allow_downgrade, expect_success) in scenarios {
+            let mut deps = mock_provenance_dependencies();
+            test_instantiate(deps.as_mut());
+            set_stored_contract_version(deps.as_mut().storage, CONTRACT_TYPE, stored_version);
+            let result = contract_upgrade(
+                deps.as_mut(),
+                mock_env(),
+                MigrationOptions {
+                    allow_same_version,
+                    allow_downgrade,
+                },
+            );
+            let scenario = format!("stored version [{stored_version}], allow_same_version [{allow_same_version}], allow_downgrade [{allow_downgrade}]");
+            if expect_success {
+                let response = result.unwrap_or_else(|e| {
+                    panic!("migration should succeed for {scenario}, but got: {e:?}")
+                });
+                assert_eq!(
+                    allow_same_version.to_string(),
+                    single_attribute_for_key(&response, "allow_same_version"),
+                    "the allow_same_version attribute should be recorded for {scenario}",
+                );
+                assert_eq!(
+                    allow_downgrade.to_string(),
+                    single_attribute_for_key(&response, "allow_downgrade"),
+                    "the allow_downgrade attribute should be recorded for {scenario}",
+                );
+                assert_eq!(
+                    CONTRACT_VERSION,
+                    get_contract_state(deps.as_ref().storage)
+                        .expect("contract state should load after a migration")
+                        .contract_version,
+                    "the contract version should be updated for {scenario}",
+                );
+            } else {
+                assert!(
+                    matches!(result, Err(ContractError::MigrationError { .. })),
+                    "a migration error should be produced for {scenario}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_version_migration_rejection_message() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        match contract_upgrade(deps.as_mut(), mock_env(), MigrationOptions::default())
+            .expect_err("an error should be produced when migrating to the same version")
+        {
+            ContractError::MigrationError { message } => assert_eq!(
+                format!("target migration contract version [{CONTRACT_VERSION}] is identical to the stored contract version. allow_same_version must be set to migrate to the same version"),
+                message,
+                "unexpected error message when migrating to the same version",
+            ),
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+
+    #[test]
+    fn test_contract_type_check_cannot_be_overridden() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        set_stored_contract_version(
+            deps.as_mut().storage,
+            "unexpected contract type",
+            CONTRACT_VERSION,
+        );
+        let err = contract_upgrade(
+            deps.as_mut(),
+            mock_env(),
+            MigrationOptions {
+                allow_same_version: true,
+                allow_downgrade: true,
+            },
+        )
+        .expect_err("an error should occur when migrating from a different contract type");
+        assert!(
+            matches!(err, ContractError::MigrationError { .. }),
+            "a migration error should be produced regardless of the provided options",
+        );
     }
 }
@@ -0,0 +1,316 @@
+use crate::store::contract_state::{get_contract_state, set_contract_state};
+use crate::types::core::error::ContractError;
+use cosmwasm_std::Storage;
+use result_extensions::ResultExtensions;
+use semver::{Version, VersionReq};
+
+/// A single transformation of contract storage that is required when migrating from a range of
+/// older contract versions.  Steps are used to reshape stored values, for instance deserializing
+/// an older [ContractState](crate::store::contract_state::ContractState) layout and re-saving it in
+/// its current form.
+pub struct MigrationStep {
+    /// A unique, human-readable name for the step.  Emitted in the migration response attributes
+    /// when the step is executed.
+    pub name: &'static str,
+    /// A [semver requirement](VersionReq) that the stored contract version must match for this
+    /// step to run. Ex: "<1.1.0" will run the step for every instance stored before version 1.1.0.
+    pub from_versions: &'static str,
+    /// The function that transforms contract storage.
+    pub migrate: fn(&mut dyn Storage) -> Result<(), ContractError>,
+}
+
+/// All registered migration steps, in the order in which they must be executed.  New steps must
+/// always be appended to the end of this list, and must declare a version range that excludes the
+/// version that introduced them.
+pub const MIGRATION_STEPS: &[MigrationStep] = &[MigrationStep {
+    name: "materialize_contract_state_settings",
+    from_versions: "<1.1.0",
+    migrate: materialize_contract_state_settings,
+}];
+
+/// Rewrites a [ContractState](crate::store::contract_state::ContractState) stored before the
+/// [approval_limits](crate::store::contract_state::ContractState::approval_limits) and
+/// [attribute_query_mode](crate::store::contract_state::ContractState::attribute_query_mode)
+/// fields were introduced, so that the stored layout includes both of them with their defaults:
+/// all approval limits disabled and strict attribute queries.  The step is idempotent, so existing
+/// values are retained if it runs against an already-current layout.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+pub fn materialize_contract_state_settings(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let contract_state = get_contract_state(storage)?;
+    set_contract_state(storage, &contract_state)
+}
+
+/// Executes, in order, each provided step whose version range includes the stored contract
+/// version.  Execution halts at the first step that fails.  Returns the names of all executed
+/// steps.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `steps` The ordered steps to consider.  Outside of tests, this should always be
+///   [MIGRATION_STEPS].
+/// * `from_version` The contract version stored before the migration began.
+pub fn run_migration_steps(
+    storage: &mut dyn Storage,
+    steps: &[MigrationStep],
+    from_version: &Version,
+) -> Result<Vec<&'static str>, ContractError> {
+    let mut executed_steps = vec![];
+    for step in steps {
+        if step
+            .from_versions
+            .parse::<VersionReq>()?
+            .matches(from_version)
+        {
+            (step.migrate)(storage).map_err(|e| ContractError::MigrationError {
+                message: format!("migration step [{}] failed: {e}", step.name),
+            })?;
+            executed_steps.push(step.name);
+        }
+    }
+    executed_steps.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::migrate::migration_steps::{run_migration_steps, MigrationStep, MIGRATION_STEPS};
+    use crate::store::contract_state::{
+        get_contract_state, set_contract_state, ApprovalLimits, AttributeQueryMode,
+    };
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::{from_json, Storage, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use semver::{Version, VersionReq};
+    use serde::Deserialize;
+
+    const LEGACY_CONTRACT_STATE_V1_0_2: &str =
+        include_str!("../test/fixtures/contract_state_v1_0_2.json");
+    const LEGACY_CONTRACT_STATE_V1_0_3: &str =
+        include_str!("../test/fixtures/contract_state_v1_0_3.json");
+
+    /// The settings fields of the stored contract state, without serde defaults, so that
+    /// deserialization fails unless the fields are physically present in storage.
+    #[derive(Deserialize)]
+    struct StoredSettings {
+        approval_limits: ApprovalLimits,
+        attribute_query_mode: AttributeQueryMode,
+    }
+
+    fn stored_settings(storage: &dyn Storage) -> Option<StoredSettings> {
+        from_json(
+            storage
+                .get(b"contract_state")
+                .expect("the contract state should be stored"),
+        )
+        .ok()
+    }
+
+    fn append_marker(storage: &mut dyn Storage, marker: &str) {
+        let mut markers = storage.get(b"markers").unwrap_or_default();
+        markers.extend_from_slice(marker.as_bytes());
+        storage.set(b"markers", &markers);
+    }
+
+    const TEST_STEPS: &[MigrationStep] = &[
+        MigrationStep {
+            name: "first",
+            from_versions: "<1.0.0",
+            migrate: |storage| {
+                append_marker(storage, "a");
+                Ok(())
+            },
+        },
+        MigrationStep {
+            name: "second",
+            from_versions: "<2.0.0",
+            migrate: |storage| {
+                append_marker(storage, "b");
+                Ok(())
+            },
+        },
+    ];
+
+    #[test]
+    fn test_registered_steps_have_valid_version_ranges() {
+        for step in MIGRATION_STEPS {
+            assert!(
+                step.from_versions.parse::<VersionReq>().is_ok(),
+                "migration step [{}] has an invalid version range [{}]",
+                step.name,
+                step.from_versions,
+            );
+        }
+    }
+
+    #[test]
+    fn test_registered_steps_accept_legacy_contract_state() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage
+            .set(b"contract_state", LEGACY_CONTRACT_STATE_V1_0_2.as_bytes());
+        run_migration_steps(&mut deps.storage, MIGRATION_STEPS, &Version::new(1, 0, 2))
+            .expect("all registered steps should succeed for a legacy contract state");
+        let contract_state = get_contract_state(&deps.storage)
+            .expect("the legacy contract state should be readable after all steps are run");
+        assert_eq!(
+            "groupmember.test.pb", contract_state.attribute_name,
+            "the legacy attribute name should be retained",
+        );
+        assert_eq!(
+            "1.0.2", contract_state.contract_version,
+            "steps should not modify the contract version",
+        );
+    }
+
+    #[test]
+    fn test_contract_state_settings_are_materialized_for_legacy_fixtures() {
+        for (fixture, version) in [
+            (LEGACY_CONTRACT_STATE_V1_0_2, Version::new(1, 0, 2)),
+            (LEGACY_CONTRACT_STATE_V1_0_3, Version::new(1, 0, 3)),
+        ] {
+            let mut deps = mock_provenance_dependencies();
+            deps.storage.set(b"contract_state", fixture.as_bytes());
+            assert!(
+                stored_settings(&deps.storage).is_none(),
+                "the fixture for version [{version}] should predate the settings fields",
+            );
+            assert_eq!(
+                vec!["materialize_contract_state_settings"],
+                run_migration_steps(&mut deps.storage, MIGRATION_STEPS, &version)
+                    .expect("the registered steps should succeed"),
+                "the settings step should run for version [{version}]",
+            );
+            let settings = stored_settings(&deps.storage)
+                .expect("the settings fields should be present after the migration");
+            assert_eq!(
+                (ApprovalLimits::default(), AttributeQueryMode::Strict),
+                (settings.approval_limits, settings.attribute_query_mode),
+                "the settings should be written with their defaults for version [{version}]",
+            );
+        }
+    }
+
+    #[test]
+    fn test_contract_state_settings_step_retains_existing_values() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage
+            .set(b"contract_state", LEGACY_CONTRACT_STATE_V1_0_3.as_bytes());
+        let mut contract_state =
+            get_contract_state(&deps.storage).expect("the legacy contract state should load");
+        contract_state.approval_limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(3)),
+            window_blocks: Uint64::new(100),
+            reapproval_cooldown_blocks: Uint64::new(10),
+        };
+        contract_state.attribute_query_mode = AttributeQueryMode::Lenient;
+        set_contract_state(&mut deps.storage, &contract_state)
+            .expect("the contract state should save");
+        run_migration_steps(&mut deps.storage, MIGRATION_STEPS, &Version::new(1, 0, 3))
+            .expect("the registered steps should succeed");
+        assert_eq!(
+            contract_state,
+            get_contract_state(&deps.storage).expect("the contract state should load"),
+            "configured settings should survive a re-run of the step",
+        );
+        assert!(
+            run_migration_steps(&mut deps.storage, MIGRATION_STEPS, &Version::new(1, 1, 0))
+                .expect("the registered steps should succeed")
+                .is_empty(),
+            "the settings step should not run for versions that include the settings",
+        );
+    }
+
+    #[test]
+    fn test_steps_run_in_order_for_matching_versions() {
+        let mut deps = mock_provenance_dependencies();
+        let executed = run_migration_steps(&mut deps.storage, TEST_STEPS, &Version::new(0, 9, 0))
+            .expect("steps should execute successfully");
+        assert_eq!(
+            vec!["first", "second"],
+            executed,
+            "all matching steps should be executed in order",
+        );
+        assert_eq!(
+            b"ab".to_vec(),
+            deps.storage.get(b"markers").unwrap(),
+            "each step should modify storage in order",
+        );
+    }
+
+    #[test]
+    fn test_steps_outside_version_range_are_skipped() {
+        let mut deps = mock_provenance_dependencies();
+        let executed = run_migration_steps(&mut deps.storage, TEST_STEPS, &Version::new(1, 5, 0))
+            .expect("steps should execute successfully");
+        assert_eq!(
+            vec!["second"],
+            executed,
+            "only steps with a matching version range should be executed",
+        );
+        let executed = run_migration_steps(&mut deps.storage, TEST_STEPS, &Version::new(2, 0, 0))
+            .expect("steps should execute successfully");
+        assert!(
+            executed.is_empty(),
+            "no steps should be executed when no version ranges match",
+        );
+    }
+
+    #[test]
+    fn test_failed_step_halts_migration() {
+        let mut deps = mock_provenance_dependencies();
+        let steps = &[
+            MigrationStep {
+                name: "failing",
+                from_versions: "*",
+                migrate: |_| {
+                    Err(ContractError::StorageError {
+                        message: "bad data".to_string(),
+                    })
+                },
+            },
+            MigrationStep {
+                name: "never_run",
+                from_versions: "*",
+                migrate: |storage| {
+                    append_marker(storage, "x");
+                    Ok(())
+                },
+            },
+        ];
+        match run_migration_steps(&mut deps.storage, steps, &Version::new(1, 0, 0))
+            .expect_err("a failing step should produce an error")
+        {
+            ContractError::MigrationError { message } => assert_eq!(
+                "migration step [failing] failed: Contract storage error occurred: bad data",
+                message,
+                "unexpected error message for a failing step",
+            ),
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        assert!(
+            deps.storage.get(b"markers").is_none(),
+            "steps after a failing step should not be executed",
+        );
+    }
+
+    #[test]
+    fn test_invalid_version_range() {
+        let mut deps = mock_provenance_dependencies();
+        let steps = &[MigrationStep {
+            name: "invalid",
+            from_versions: "not a range",
+            migrate: |_| Ok(()),
+        }];
+        assert!(
+            matches!(
+                run_migration_steps(&mut deps.storage, steps, &Version::new(1, 0, 0)),
+                Err(ContractError::SemVerError(..))
+            ),
+            "an invalid version range should produce a semver error",
+        );
+    }
+}
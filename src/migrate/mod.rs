@@ -3,3 +3,5 @@
 
 /// The main entrypoint function for running a code migration.
 pub mod contract_upgrade;
+/// The ordered registry of storage transformations run during a code migration.
+pub mod migration_steps;
@@ -1,4 +1,27 @@
 //! Contains the functionality used in the [contract file](crate::contract) to perform a contract query.
 
+/// A query that fetches filtered pages of the approval audit trail.
+pub mod query_approval_events;
+/// A query that lists the values under the contract's attribute name on an account that cannot be
+/// decoded.
+pub mod query_attribute_diagnostics;
+/// A query that fetches pages of the accounts barred from recording approvals.
+pub mod query_blocked_accounts;
+/// A query that fetches the cw2-compatible contract version info.
+pub mod query_contract_info;
 /// A query that fetches the stored values in the [ContractState](crate::store::contract_state::ContractState).
 pub mod query_contract_state;
+/// A query that fetches the member cap and remaining capacity of a group.
+pub mod query_group_capacity;
+/// A query that reports which members of a group have recorded an approval for it.
+pub mod query_group_consent_status;
+/// Queries that fetch the approval counters maintained for each group.
+pub mod query_group_stats;
+/// Queries that fetch the terms published for each group and the versions accepted by approvals.
+pub mod query_group_terms;
+/// A query that fetches pages of the contract's history log.
+pub mod query_history;
+/// A query that lists the contracts registered to be notified of approval changes.
+pub mod query_hooks;
+/// A query that lists the connected IBC channels and the allowlisted IBC connections.
+pub mod query_ibc_channels;
@@ -0,0 +1,84 @@
+use crate::store::approval_events::{get_approval_events, ApprovalEventFilter};
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryApprovalEventsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Fetches a page of the append-only audit trail of approvals and withdrawals, narrowed by the
+/// provided filter.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `filter` The criteria that each returned event must match.
+/// * `start_after` If provided, only events with a sequence greater than this value will be
+///   returned.
+/// * `limit` The maximum number of events to return.
+pub fn query_approval_events(
+    deps: Deps,
+    filter: ApprovalEventFilter,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryApprovalEventsResponse {
+        events: get_approval_events(
+            deps.storage,
+            &filter,
+            start_after.map(|sequence| sequence.u64()),
+            limit,
+        )?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_approval_events::query_approval_events;
+    use crate::store::approval_events::{
+        record_approval_event, ApprovalAction, ApprovalEventFilter,
+    };
+    use crate::types::core::response::QueryApprovalEventsResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_approval_events() {
+        let mut deps = mock_provenance_dependencies();
+        for group_id in 1..=3u64 {
+            record_approval_event(
+                &mut deps.storage,
+                &mock_env(),
+                &Addr::unchecked("account"),
+                Uint64::new(group_id),
+                None,
+                ApprovalAction::Approve,
+                None,
+            )
+            .expect("approval event should record");
+        }
+        let response = from_json::<QueryApprovalEventsResponse>(
+            query_approval_events(
+                deps.as_ref(),
+                ApprovalEventFilter {
+                    group_id: Some(Uint64::new(2)),
+                    ..Default::default()
+                },
+                None,
+                None,
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![2],
+            response
+                .events
+                .iter()
+                .map(|event| event.sequence.u64())
+                .collect::<Vec<u64>>(),
+            "only events for the requested group should be returned",
+        );
+    }
+}
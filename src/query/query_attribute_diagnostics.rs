@@ -0,0 +1,166 @@
+use crate::store::contract_state::get_contract_state;
+use crate::types::core::error::ContractError;
+use crate::types::core::response::{QueryAttributeDiagnosticsResponse, UndecodableAttributeValue};
+use crate::util::prov_helpers::{
+    decode_group_id, parse_group_policy_attribute, AttributePaginator,
+};
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Lists the values written under the contract's attribute name on an account that cannot be
+/// decoded as either a group id or a group policy approval.  Such values are ignored by the
+/// approval routes, so operators can use this route to find and clean them up.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account to inspect.
+pub fn query_attribute_diagnostics(deps: Deps, account: String) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?.to_string();
+    let attribute_name = get_contract_state(deps.storage)?.attribute_name;
+    let mut undecodable_values = vec![];
+    for attribute in AttributePaginator::by_name(
+        AttributeQuerier::new(&deps.querier),
+        &account,
+        &attribute_name,
+    ) {
+        let attribute = attribute?;
+        if parse_group_policy_attribute(&attribute, &attribute_name).is_some() {
+            continue;
+        }
+        if let Err(reason) = decode_group_id(&attribute) {
+            undecodable_values.push(UndecodableAttributeValue {
+                attribute_type: attribute.attribute_type().as_str_name().to_string(),
+                value: Binary::new(attribute.value),
+                reason,
+            });
+        }
+    }
+    to_json_binary(&QueryAttributeDiagnosticsResponse {
+        account,
+        attribute_name,
+        undecodable_values,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_attribute_diagnostics::query_attribute_diagnostics;
+    use crate::test::chain_simulator::ProvenanceSimulator;
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER};
+    use crate::types::core::error::ContractError;
+    use crate::types::core::response::QueryAttributeDiagnosticsResponse;
+    use crate::util::prov_helpers::group_policy_attribute_value;
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{from_json, to_json_vec, Binary, StdError};
+    use provwasm_std::types::provenance::attribute::v1::{Attribute, AttributeType};
+
+    fn member() -> String {
+        MockApi::default()
+            .addr_make(DEFAULT_GROUP_MEMBER)
+            .to_string()
+    }
+
+    fn attribute(name: &str, value: Vec<u8>, attribute_type: AttributeType) -> Attribute {
+        Attribute {
+            name: name.to_string(),
+            value,
+            attribute_type: attribute_type.into(),
+            address: member(),
+            expiration_date: None,
+        }
+    }
+
+    #[test]
+    fn test_query_lists_only_undecodable_values() {
+        let simulator = ProvenanceSimulator::with_default_contract()
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                to_json_vec(&1u64).unwrap(),
+                AttributeType::Int,
+            ))
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                b"\"2\"".to_vec(),
+                AttributeType::Int,
+            ))
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                b"3".to_vec(),
+                AttributeType::String,
+            ))
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                group_policy_attribute_value("policy"),
+                AttributeType::String,
+            ))
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                b"group four".to_vec(),
+                AttributeType::String,
+            ))
+            .with_attribute(attribute(
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                vec![5],
+                AttributeType::Bytes,
+            ))
+            .with_attribute(attribute(
+                "unrelated.pb",
+                b"garbage".to_vec(),
+                AttributeType::String,
+            ));
+        let response = from_json::<QueryAttributeDiagnosticsResponse>(
+            query_attribute_diagnostics(simulator.deps.as_ref(), member())
+                .expect("the diagnostics query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            DEFAULT_CONTRACT_ATTRIBUTE, response.attribute_name,
+            "the inspected attribute name should be reported",
+        );
+        assert_eq!(
+            vec![
+                Binary::new(b"group four".to_vec()),
+                Binary::new(vec![5]),
+            ],
+            response
+                .undecodable_values
+                .iter()
+                .map(|value| value.value.to_owned())
+                .collect::<Vec<_>>(),
+            "only values under the contract's name that decode as neither a group id nor a policy should be listed",
+        );
+        assert_eq!(
+            "ATTRIBUTE_TYPE_BYTES", response.undecodable_values[1].attribute_type,
+            "the attribute type of each value should be reported",
+        );
+    }
+
+    #[test]
+    fn test_query_for_clean_account() {
+        let simulator = ProvenanceSimulator::with_default_contract();
+        let response = from_json::<QueryAttributeDiagnosticsResponse>(
+            query_attribute_diagnostics(simulator.deps.as_ref(), member())
+                .expect("the diagnostics query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(
+            response.undecodable_values.is_empty(),
+            "an account without attributes should have no undecodable values",
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_invalid_account() {
+        let simulator = ProvenanceSimulator::with_default_contract();
+        match query_attribute_diagnostics(simulator.deps.as_ref(), "not an address".to_string())
+            .expect_err("an invalid account should be rejected")
+        {
+            ContractError::Std(StdError::GenericErr { .. }) => {}
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+    }
+}
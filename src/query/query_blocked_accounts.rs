@@ -0,0 +1,54 @@
+use crate::store::blocklist::get_blocked_accounts;
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryBlockedAccountsResponse;
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches a page of the accounts that the contract admin has barred from recording approvals.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, only accounts with an address greater than this value will be
+///   returned.
+/// * `limit` The maximum number of accounts to return.
+pub fn query_blocked_accounts(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryBlockedAccountsResponse {
+        accounts: get_blocked_accounts(deps.storage, start_after.map(Addr::unchecked), limit)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_blocked_accounts::query_blocked_accounts;
+    use crate::store::blocklist::{block_account, BlockedAccount};
+    use crate::types::core::response::QueryBlockedAccountsResponse;
+    use cosmwasm_std::{from_json, Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_blocked_accounts() {
+        let mut deps = mock_provenance_dependencies();
+        let blocked_account = BlockedAccount {
+            account: Addr::unchecked("account"),
+            reason: "sanctioned".to_string(),
+            blocked_at_height: Uint64::new(5),
+        };
+        block_account(&mut deps.storage, &blocked_account).expect("the account should be blocked");
+        let response = from_json::<QueryBlockedAccountsResponse>(
+            query_blocked_accounts(deps.as_ref(), None, None).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![blocked_account],
+            response.accounts,
+            "the blocked account should be returned",
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::store::contract_info::get_contract_info;
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the cw2-compatible contract version info for this contract.  Instances that have not
+/// yet been migrated to a version that writes cw2 info will report the values held in the
+/// [ContractState](crate::store::contract_state::ContractState).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_contract_info(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&get_contract_info(deps.storage)?)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_contract_info::query_contract_info;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::from_json;
+    use cw2::ContractVersion;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_when_missing_contract_info() {
+        let deps = mock_provenance_dependencies();
+        let result = query_contract_info(deps.as_ref());
+        assert!(
+            matches!(result, Err(ContractError::StorageError { .. })),
+            "a storage error should be emitted when no contract info exists",
+        );
+    }
+
+    #[test]
+    fn test_query_when_contract_info_available() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        let result_binary = query_contract_info(deps.as_ref())
+            .expect("contract info should be successfully derived");
+        let contract_info = from_json::<ContractVersion>(&result_binary)
+            .expect("the contract info should successfully deserialize from binary");
+        assert_eq!(
+            ContractVersion {
+                contract: CONTRACT_TYPE.to_string(),
+                version: CONTRACT_VERSION.to_string(),
+            },
+            contract_info,
+            "the resulting contract info should reflect the instantiated contract",
+        );
+    }
+}
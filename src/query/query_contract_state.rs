@@ -8,7 +8,7 @@ use result_extensions::ResultExtensions;
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like contract internal storage and a querier to retrieve blockchain objects.
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
 pub fn query_contract_state(deps: Deps) -> Result<Binary, ContractError> {
     to_json_binary(&get_contract_state(deps.storage)?)?.to_ok()
 }
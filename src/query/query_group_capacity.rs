@@ -0,0 +1,40 @@
+use crate::store::group_caps::get_group_capacity;
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Fetches the admin-configured member cap of a group alongside its contract-side approval count
+/// and remaining capacity.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `group_id` The unique identifier of the group for which to fetch capacity.
+pub fn query_group_capacity(deps: Deps, group_id: Uint64) -> Result<Binary, ContractError> {
+    to_json_binary(&get_group_capacity(deps.storage, group_id)?)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_group_capacity::query_group_capacity;
+    use crate::store::group_caps::{set_group_member_cap, GroupCapacity};
+    use cosmwasm_std::{from_json, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_group_capacity() {
+        let mut deps = mock_provenance_dependencies();
+        set_group_member_cap(&mut deps.storage, Uint64::new(3), Some(4))
+            .expect("the cap should be set");
+        let capacity = from_json::<GroupCapacity>(
+            query_group_capacity(deps.as_ref(), Uint64::new(3)).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            Some(Uint64::new(4)),
+            capacity.remaining_capacity,
+            "a capped group without approvals should have its full cap remaining",
+        );
+    }
+}
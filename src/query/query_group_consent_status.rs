@@ -0,0 +1,255 @@
+use crate::store::approval_activity::get_approval_memo;
+use crate::store::contract_state::get_contract_state;
+use crate::store::group_terms::get_terms_acceptance;
+use crate::types::core::error::ContractError;
+use crate::types::core::response::{
+    ConsentState, MemberConsentStatus, QueryGroupConsentStatusResponse,
+};
+use crate::util::group_helpers::get_group_member_page;
+use crate::util::pagination::query_limit;
+use crate::util::prov_helpers::{check_matching_attribute, parse_group_id_attribute};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Uint64};
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Fetches a page of a group's members from the group module and reports whether each member has
+/// recorded an approval for the group.  Members are paged in the order maintained by the group
+/// module, and the module's `next_key` is returned as the cursor for the next page.  A member whose
+/// attributes cannot be fetched is reported as [unknown](ConsentState::Unknown) in
+/// [lenient](crate::store::contract_state::AttributeQueryMode::Lenient) mode.  The counts in the
+/// response only cover the returned page.  Each consenting member is also reported with the version
+/// of the group's [terms](crate::store::group_terms::GroupTerms) it accepted, and whether newer
+/// terms have since been published.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `group_id` The unique identifier of the group for which to report consent.
+/// * `page_key` The `next_key` returned with the previous page, or `None` for the first page.
+/// * `limit` The maximum number of members to return.  Defaults to 10 and is capped at 100.
+pub fn query_group_consent_status(
+    deps: Deps,
+    group_id: Uint64,
+    page_key: Option<Binary>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let page = get_group_member_page(
+        &GroupQuerier::new(&deps.querier),
+        group_id,
+        page_key.as_ref(),
+        query_limit(limit) as u64,
+    )?;
+    let members = page
+        .members
+        .into_iter()
+        .map(|address| {
+            let consent = match check_matching_attribute(
+                AttributeQuerier::new(&deps.querier),
+                &address,
+                &attribute_name,
+                contract_state.attribute_query_mode,
+                |attribute| parse_group_id_attribute(attribute, &attribute_name) == Some(group_id),
+            )? {
+                Some(true) => ConsentState::Consented,
+                Some(false) => ConsentState::NotConsented,
+                None => ConsentState::Unknown,
+            };
+            let consented = consent == ConsentState::Consented;
+            let account = Addr::unchecked(&address);
+            let acceptance = get_terms_acceptance(deps.storage, &account, group_id, consented)?;
+            let memo = get_approval_memo(deps.storage, &account, group_id)?;
+            MemberConsentStatus {
+                address,
+                consent,
+                accepted_terms_version: acceptance.accepted_version.filter(|_| consented),
+                terms_outdated: acceptance.outdated,
+                memo: memo.filter(|_| consented),
+            }
+            .to_ok()
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+    let count = |state: ConsentState| {
+        Uint64::new(
+            members
+                .iter()
+                .filter(|member| member.consent == state)
+                .count() as u64,
+        )
+    };
+    to_json_binary(&QueryGroupConsentStatusResponse {
+        group_id,
+        page_consented_count: count(ConsentState::Consented),
+        page_not_consented_count: count(ConsentState::NotConsented),
+        page_unknown_count: count(ConsentState::Unknown),
+        total_members: page.total_members.map(Uint64::new),
+        next_key: page.next_key,
+        members,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_group_consent_status::query_group_consent_status;
+    use crate::store::contract_state::{
+        get_contract_state, set_contract_state, AttributeQueryMode,
+    };
+    use crate::test::chain_simulator::ProvenanceSimulator;
+    use crate::test::test_helpers::mock_group_members;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::types::core::msg::ExecuteMsg;
+    use crate::types::core::response::{
+        ConsentState, MemberConsentStatus, QueryGroupConsentStatusResponse,
+    };
+    use cosmwasm_std::{from_json, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::attribute::v1::QueryAttributeRequest;
+
+    fn approve(group_id: u64) -> ExecuteMsg {
+        ExecuteMsg::ApproveGroupMembership {
+            group_id: Uint64::new(group_id),
+            memo: None,
+            terms_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_query_reports_consent_of_each_member() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let first = simulator.addr("first").to_string();
+        let second = simulator.addr("second").to_string();
+        let third = simulator.addr("third").to_string();
+        simulator
+            .execute(
+                &first,
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: Some("signer".to_string()),
+                    terms_hash: None,
+                },
+            )
+            .expect("the first member should approve group one");
+        simulator
+            .execute(&second, approve(2))
+            .expect("the second member should approve a different group");
+        simulator
+            .execute(&third, approve(1))
+            .expect("the third member should approve group one");
+        mock_group_members(
+            &mut simulator.deps.querier,
+            vec![
+                vec![first.to_owned(), second.to_owned()],
+                vec![third.to_owned()],
+            ],
+        );
+        let response = from_json::<QueryGroupConsentStatusResponse>(
+            query_group_consent_status(simulator.deps.as_ref(), Uint64::new(1), None, Some(2))
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            QueryGroupConsentStatusResponse {
+                group_id: Uint64::new(1),
+                members: vec![
+                    MemberConsentStatus {
+                        address: first,
+                        consent: ConsentState::Consented,
+                        accepted_terms_version: None,
+                        terms_outdated: false,
+                        memo: Some("signer".to_string()),
+                    },
+                    MemberConsentStatus {
+                        address: second.to_owned(),
+                        consent: ConsentState::NotConsented,
+                        accepted_terms_version: None,
+                        terms_outdated: false,
+                        memo: None,
+                    },
+                ],
+                page_consented_count: Uint64::new(1),
+                page_not_consented_count: Uint64::new(1),
+                page_unknown_count: Uint64::zero(),
+                total_members: Some(Uint64::new(3)),
+                next_key: response.next_key.to_owned(),
+            },
+            response,
+            "an approval for a different group should not count as consent",
+        );
+        assert!(
+            response.next_key.is_some(),
+            "the first page should have a next key",
+        );
+        let response = from_json::<QueryGroupConsentStatusResponse>(
+            query_group_consent_status(
+                simulator.deps.as_ref(),
+                Uint64::new(1),
+                response.next_key,
+                Some(2),
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![MemberConsentStatus {
+                address: third,
+                consent: ConsentState::Consented,
+                accepted_terms_version: None,
+                terms_outdated: false,
+                memo: None,
+            }],
+            response.members,
+            "the next page should resume after the last member of the first page",
+        );
+        assert_eq!(
+            (None, None),
+            (response.total_members, response.next_key),
+            "the last page should not report a total or a next key",
+        );
+    }
+
+    #[test]
+    fn test_failed_attribute_queries_are_reported_by_mode() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        mock_group_members(&mut deps.querier, vec![vec!["first", "second"]]);
+        QueryAttributeRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        assert!(
+            matches!(
+                query_group_consent_status(deps.as_ref(), Uint64::new(1), None, None),
+                Err(ContractError::AttributeQueryError { .. })
+            ),
+            "a failed attribute query should fail the query in strict mode",
+        );
+        let mut contract_state = get_contract_state(&deps.storage).expect("state should load");
+        contract_state.attribute_query_mode = AttributeQueryMode::Lenient;
+        set_contract_state(&mut deps.storage, &contract_state).expect("state should save");
+        let response = from_json::<QueryGroupConsentStatusResponse>(
+            query_group_consent_status(deps.as_ref(), Uint64::new(1), None, None)
+                .expect("the query should succeed in lenient mode"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![ConsentState::Unknown, ConsentState::Unknown],
+            response
+                .members
+                .iter()
+                .map(|member| member.consent)
+                .collect::<Vec<_>>(),
+            "members whose attributes could not be fetched should be reported as unknown",
+        );
+        assert_eq!(
+            (Uint64::zero(), Uint64::zero(), Uint64::new(2)),
+            (
+                response.page_consented_count,
+                response.page_not_consented_count,
+                response.page_unknown_count,
+            ),
+            "unknown members should not be counted as consenting or not consenting",
+        );
+    }
+}
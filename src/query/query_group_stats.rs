@@ -0,0 +1,104 @@
+use crate::store::group_stats::{get_all_group_stats, get_group_stats};
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryAllGroupStatsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Fetches the [approval counters](crate::store::group_stats::GroupStats) maintained for a single
+/// group.  Groups without any recorded activity produce zeroed counters.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `group_id` The unique identifier of the group for which to fetch counters.
+pub fn query_group_stats(deps: Deps, group_id: Uint64) -> Result<Binary, ContractError> {
+    to_json_binary(&get_group_stats(deps.storage, group_id)?)?.to_ok()
+}
+
+/// Fetches a page of the [approval counters](crate::store::group_stats::GroupStats) maintained for
+/// every group with recorded activity.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, only groups with an id greater than this value will be returned.
+/// * `limit` The maximum number of groups to return.
+pub fn query_all_group_stats(
+    deps: Deps,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryAllGroupStatsResponse {
+        stats: get_all_group_stats(deps.storage, start_after, limit)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_group_stats::{query_all_group_stats, query_group_stats};
+    use crate::store::group_stats::{record_group_approval, GroupStats};
+    use crate::types::core::response::QueryAllGroupStatsResponse;
+    use cosmwasm_std::{from_json, Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_group_stats() {
+        let mut deps = mock_provenance_dependencies();
+        record_group_approval(
+            &mut deps.storage,
+            &Addr::unchecked("account"),
+            Uint64::new(2),
+            5,
+        )
+        .expect("approval should record");
+        let stats = from_json::<GroupStats>(
+            query_group_stats(deps.as_ref(), Uint64::new(2)).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "the recorded approval should be returned",
+        );
+        let stats = from_json::<GroupStats>(
+            query_group_stats(deps.as_ref(), Uint64::new(3)).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            GroupStats::new(Uint64::new(3)),
+            stats,
+            "a group without activity should produce zeroed counters",
+        );
+    }
+
+    #[test]
+    fn test_query_all_group_stats() {
+        let mut deps = mock_provenance_dependencies();
+        for group_id in 1..=3u64 {
+            record_group_approval(
+                &mut deps.storage,
+                &Addr::unchecked("account"),
+                Uint64::new(group_id),
+                5,
+            )
+            .expect("approval should record");
+        }
+        let response = from_json::<QueryAllGroupStatsResponse>(
+            query_all_group_stats(deps.as_ref(), Some(Uint64::new(1)), None)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![2, 3],
+            response
+                .stats
+                .iter()
+                .map(|stats| stats.group_id.u64())
+                .collect::<Vec<u64>>(),
+            "groups after the start_after value should be returned",
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::store::contract_state::get_contract_state;
+use crate::store::group_terms::{get_group_terms, get_terms_acceptance};
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryGroupTermsResponse;
+use crate::util::prov_helpers::{account_has_matching_attribute, parse_group_id_attribute};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint64};
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use result_extensions::ResultExtensions;
+
+/// Fetches the terms currently published for a group, if any.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `group_id` The unique identifier of the group for which to fetch terms.
+pub fn query_group_terms(deps: Deps, group_id: Uint64) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryGroupTermsResponse {
+        group_id,
+        terms: get_group_terms(deps.storage, group_id)?,
+    })?
+    .to_ok()
+}
+
+/// Fetches the version of a group's terms accepted with an account's approval of the group,
+/// flagging the approval as outdated if newer terms have since been published.  Whether the
+/// account holds an approval is determined from its attributes.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account to inspect.
+/// * `group_id` The unique identifier of the group.
+pub fn query_terms_acceptance(
+    deps: Deps,
+    account: String,
+    group_id: Uint64,
+) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?;
+    let contract_state = get_contract_state(deps.storage)?;
+    let attribute_name = contract_state.attribute_name;
+    let approved = account_has_matching_attribute(
+        AttributeQuerier::new(&deps.querier),
+        account.as_str(),
+        &attribute_name,
+        contract_state.attribute_query_mode,
+        |attribute| parse_group_id_attribute(attribute, &attribute_name) == Some(group_id),
+    )?;
+    to_json_binary(&get_terms_acceptance(
+        deps.storage,
+        &account,
+        group_id,
+        approved,
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_group_terms::query_terms_acceptance;
+    use crate::store::group_terms::TermsAcceptance;
+    use crate::test::chain_simulator::ProvenanceSimulator;
+    use crate::test::test_constants::DEFAULT_CONTRACT_ADMIN;
+    use crate::types::core::msg::ExecuteMsg;
+    use cosmwasm_std::{from_json, Uint64};
+
+    fn publish(content_hash: &str) -> ExecuteMsg {
+        ExecuteMsg::PublishGroupTerms {
+            group_id: Uint64::new(1),
+            uri: "ipfs://terms".to_string(),
+            content_hash: content_hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_query_flags_approval_of_superseded_terms() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr("member").to_string();
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("abcd"))
+            .expect("the first terms should publish");
+        simulator
+            .execute(
+                &member,
+                ExecuteMsg::ApproveGroupMembership {
+                    group_id: Uint64::new(1),
+                    memo: None,
+                    terms_hash: Some("abcd".to_string()),
+                },
+            )
+            .expect("the member should approve the first terms");
+        simulator
+            .execute(DEFAULT_CONTRACT_ADMIN, publish("ef01"))
+            .expect("the second terms should publish");
+        let acceptance = from_json::<TermsAcceptance>(
+            query_terms_acceptance(simulator.deps.as_ref(), member, Uint64::new(1))
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            (true, Some(Uint64::new(1)), Some(Uint64::new(2)), true,),
+            (
+                acceptance.approved,
+                acceptance.accepted_version,
+                acceptance.current_version,
+                acceptance.outdated,
+            ),
+            "an approval of superseded terms should be flagged as outdated",
+        );
+    }
+}
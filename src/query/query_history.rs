@@ -0,0 +1,69 @@
+use crate::store::history::get_history_entries;
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryHistoryResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint64};
+use result_extensions::ResultExtensions;
+
+/// Fetches a page of the contract's append-only history log, which records each migration and
+/// each privileged configuration change.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, only entries with an identifier greater than this value will be
+///   returned.
+/// * `limit` The maximum number of entries to return.
+pub fn query_history(
+    deps: Deps,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryHistoryResponse {
+        entries: get_history_entries(deps.storage, start_after.map(|id| id.u64()), limit)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_history::query_history;
+    use crate::store::history::record_migration;
+    use crate::types::core::response::QueryHistoryResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_history() {
+        let deps = mock_provenance_dependencies();
+        let response = from_json::<QueryHistoryResponse>(
+            query_history(deps.as_ref(), None, None).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(
+            response.entries.is_empty(),
+            "no entries should be returned when no history exists",
+        );
+    }
+
+    #[test]
+    fn test_query_paginates_history() {
+        let mut deps = mock_provenance_dependencies();
+        for version in ["1.0.1", "1.0.2", "1.0.3"] {
+            record_migration(&mut deps.storage, &mock_env(), "1.0.0", version)
+                .expect("a migration should be recorded");
+        }
+        let response = from_json::<QueryHistoryResponse>(
+            query_history(deps.as_ref(), Some(Uint64::new(1)), Some(1))
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(1, response.entries.len(), "the limit should be respected",);
+        assert_eq!(
+            Uint64::new(2),
+            response.entries[0].id,
+            "the entry after the start_after value should be returned",
+        );
+    }
+}
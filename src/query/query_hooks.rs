@@ -0,0 +1,47 @@
+use crate::store::hooks::get_hooks;
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryHooksResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches every contract registered to be notified of approval changes.  The number of hooks is
+/// capped by [MAX_HOOKS](crate::store::hooks::MAX_HOOKS), so the results are not paged.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_hooks(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryHooksResponse {
+        hooks: get_hooks(deps.storage)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_hooks::query_hooks;
+    use crate::store::hooks::{add_hook, RegisteredHook};
+    use crate::types::core::response::QueryHooksResponse;
+    use cosmwasm_std::{from_json, Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_hooks() {
+        let mut deps = mock_provenance_dependencies();
+        let hook = RegisteredHook {
+            contract: Addr::unchecked("hook"),
+            added_at_height: Uint64::new(5),
+        };
+        add_hook(&mut deps.storage, &hook).expect("the hook should be registered");
+        let response = from_json::<QueryHooksResponse>(
+            query_hooks(deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![hook],
+            response.hooks,
+            "the registered hook should be returned"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::store::ibc_channels::{get_allowed_connections, get_channels};
+use crate::types::core::error::ContractError;
+use crate::types::core::response::QueryIbcChannelsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches every connected IBC channel and every allowlisted IBC connection.  Both are capped by
+/// [MAX_IBC_CHANNELS](crate::store::ibc_channels::MAX_IBC_CHANNELS) and
+/// [MAX_ALLOWED_IBC_CONNECTIONS](crate::store::ibc_channels::MAX_ALLOWED_IBC_CONNECTIONS), so the
+/// results are not paged.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+///   resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_ibc_channels(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&QueryIbcChannelsResponse {
+        channels: get_channels(deps.storage)?,
+        allowed_connections: get_allowed_connections(deps.storage)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_ibc_channels::query_ibc_channels;
+    use crate::store::ibc_channels::{allow_connection, AllowedConnection};
+    use crate::types::core::response::QueryIbcChannelsResponse;
+    use cosmwasm_std::{from_json, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_ibc_channels() {
+        let mut deps = mock_provenance_dependencies();
+        let connection = AllowedConnection {
+            connection_id: "connection-0".to_string(),
+            allowed_at_height: Uint64::new(5),
+        };
+        allow_connection(&mut deps.storage, &connection)
+            .expect("the connection should be allowlisted");
+        let response = from_json::<QueryIbcChannelsResponse>(
+            query_ibc_channels(deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            QueryIbcChannelsResponse {
+                channels: vec![],
+                allowed_connections: vec![connection],
+            },
+            response,
+            "the allowlisted connection should be returned",
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{from_json, Addr, Reply, Response, SubMsgResult};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's reply functionality when a hook notification dispatched by
+/// [prepare_hook_messages](crate::store::hooks::prepare_hook_messages) fails.  The failure is
+/// logged in the response attributes and otherwise swallowed, so a misbehaving hook cannot block
+/// the approval change that triggered it.  The hook's own state changes are still reverted by the
+/// chain.
+///
+/// # Parameters
+///
+/// * `msg` The reply to the failed submessage, carrying the hook's address as its payload.
+pub fn handle_hook_failure(msg: Reply) -> Result<Response, ContractError> {
+    let SubMsgResult::Err(error) = msg.result else {
+        return ContractError::ExecuteError {
+            route: "reply".to_string(),
+            message: "hook notifications should only reply on failure".to_string(),
+        }
+        .to_err();
+    };
+    let hook_address = from_json::<Addr>(&msg.payload)?;
+    Response::new()
+        .add_attribute("action", "hook_failed")
+        .add_attribute("hook_address", hook_address.as_str())
+        .add_attribute("error", error)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reply::hook_failure::handle_hook_failure;
+    use crate::store::hooks::HOOK_REPLY_ID;
+    use crate::test::test_helpers::single_attribute_for_key;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::{to_json_binary, Addr, Reply, SubMsgResponse, SubMsgResult};
+
+    fn reply(result: SubMsgResult) -> Reply {
+        Reply {
+            id: HOOK_REPLY_ID,
+            payload: to_json_binary(&Addr::unchecked("hook"))
+                .expect("the payload should serialize"),
+            gas_used: 0,
+            result,
+        }
+    }
+
+    #[test]
+    fn test_failed_hook_is_logged() {
+        let response = handle_hook_failure(reply(SubMsgResult::Err("out of gas".to_string())))
+            .expect("a failed hook should not fail the transaction");
+        assert_eq!(
+            "hook",
+            single_attribute_for_key(&response, "hook_address"),
+            "the failed hook should be identified",
+        );
+        assert_eq!(
+            "out of gas",
+            single_attribute_for_key(&response, "error"),
+            "the hook's error should be logged",
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_rejection_for_successful_result() {
+        let err = handle_hook_failure(reply(SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![],
+        })))
+        .expect_err("a successful result should be rejected");
+        assert!(
+            matches!(err, ContractError::ExecuteError { .. }),
+            "an execute error should be emitted for an unexpected successful reply",
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Contains the functionality used in the [contract file](crate::contract) to handle replies to the
+//! submessages dispatched by the contract.
+
+/// The handling of failed hook notifications.
+pub mod hook_failure;
@@ -0,0 +1,375 @@
+use crate::store::contract_state::ApprovalLimits;
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{Addr, Storage, Uint64};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+
+const NAMESPACE_RECENT_APPROVAL_HEIGHTS: &str = "recent_approval_heights";
+const NAMESPACE_WITHDRAWAL_HEIGHTS: &str = "withdrawal_heights";
+const NAMESPACE_APPROVAL_MEMOS: &str = "approval_memos";
+const RECENT_APPROVAL_HEIGHTS: Map<&Addr, Vec<u64>> = Map::new(NAMESPACE_RECENT_APPROVAL_HEIGHTS);
+const WITHDRAWAL_HEIGHTS: Map<(&Addr, u64), u64> = Map::new(NAMESPACE_WITHDRAWAL_HEIGHTS);
+const APPROVAL_MEMOS: Map<(&Addr, u64), String> = Map::new(NAMESPACE_APPROVAL_MEMOS);
+
+/// Verifies that an account may record an approval for a group at the given block height under the
+/// provided [ApprovalLimits].  Produces a [ReapprovalCooldownError](ContractError::ReapprovalCooldownError)
+/// if the account withdrew its approval of the group too recently, and an
+/// [ApprovalRateLimitError](ContractError::ApprovalRateLimitError) if the account has already
+/// recorded the maximum number of approvals within the current window.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `limits` The limits currently configured in the contract state.
+/// * `account` The bech32 address of the account attempting to record an approval.
+/// * `group_id` The unique identifier of the group being approved.
+/// * `block_height` The block height at which the approval is attempted.
+pub fn check_approval_limits(
+    storage: &dyn Storage,
+    limits: &ApprovalLimits,
+    account: &Addr,
+    group_id: Uint64,
+    block_height: u64,
+) -> Result<(), ContractError> {
+    if !limits.reapproval_cooldown_blocks.is_zero() {
+        if let Some(withdrawal_height) = WITHDRAWAL_HEIGHTS
+            .may_load(storage, (account, group_id.u64()))
+            .map_err(ContractError::storage_error)?
+        {
+            let available_at_height =
+                withdrawal_height.saturating_add(limits.reapproval_cooldown_blocks.u64());
+            if block_height < available_at_height {
+                return ContractError::ReapprovalCooldownError {
+                    account: account.to_string(),
+                    group_id: group_id.u64(),
+                    available_at_height,
+                }
+                .to_err();
+            }
+        }
+    }
+    if let Some(max_approvals) = limits.max_approvals_per_window {
+        let heights = recent_approval_heights(storage, limits, account, block_height)?;
+        if heights.len() as u64 >= max_approvals.u64() {
+            return ContractError::ApprovalRateLimitError {
+                account: account.to_string(),
+                max_approvals: max_approvals.u64(),
+                window_blocks: limits.window_blocks.u64(),
+                available_at_height: heights
+                    .first()
+                    .map(|height| height.saturating_add(limits.window_blocks.u64()))
+                    .unwrap_or(block_height),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
+/// Records an approval by the account at the given block height for use in rate limiting.  Heights
+/// that have fallen outside the configured window are discarded, only the most recent heights up
+/// to the configured maximum are kept, and nothing is tracked when rate limiting is disabled.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `limits` The limits currently configured in the contract state.
+/// * `account` The bech32 address of the account that recorded an approval.
+/// * `block_height` The block height at which the approval occurred.
+pub fn record_account_approval(
+    storage: &mut dyn Storage,
+    limits: &ApprovalLimits,
+    account: &Addr,
+    block_height: u64,
+) -> Result<(), ContractError> {
+    let Some(max_approvals) = limits
+        .max_approvals_per_window
+        .filter(|_| !limits.window_blocks.is_zero())
+    else {
+        RECENT_APPROVAL_HEIGHTS.remove(storage, account);
+        return ().to_ok();
+    };
+    let mut heights = recent_approval_heights(storage, limits, account, block_height)?;
+    heights.push(block_height);
+    let excess = heights.len().saturating_sub(max_approvals.u64() as usize);
+    heights.drain(..excess);
+    RECENT_APPROVAL_HEIGHTS
+        .save(storage, account, &heights)
+        .map_err(ContractError::storage_error)
+}
+
+/// Records a withdrawal of the account's approval of a group at the given block height, which
+/// starts the group's re-approval cooldown for that account.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account that withdrew its approval.
+/// * `group_id` The unique identifier of the group for which the approval was withdrawn.
+/// * `block_height` The block height at which the withdrawal occurred.
+pub fn record_account_withdrawal(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+    block_height: u64,
+) -> Result<(), ContractError> {
+    WITHDRAWAL_HEIGHTS
+        .save(storage, (account, group_id.u64()), &block_height)
+        .map_err(ContractError::storage_error)
+}
+
+/// Stores the memo provided with the account's current approval of a group, replacing the memo of
+/// any earlier approval.  Approvals made without a memo clear the stored value.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account that approved the group.
+/// * `group_id` The unique identifier of the approved group.
+/// * `memo` The previously-validated memo provided with the approval, if any.
+pub fn record_approval_memo(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+    memo: Option<&str>,
+) -> Result<(), ContractError> {
+    match memo {
+        Some(memo) => APPROVAL_MEMOS
+            .save(storage, (account, group_id.u64()), &memo.to_string())
+            .map_err(ContractError::storage_error),
+        None => {
+            clear_approval_memo(storage, account, group_id);
+            ().to_ok()
+        }
+    }
+}
+
+/// Removes the memo stored for the account's approval of a group, such as when the approval is
+/// withdrawn.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose approval was removed.
+/// * `group_id` The unique identifier of the group.
+pub fn clear_approval_memo(storage: &mut dyn Storage, account: &Addr, group_id: Uint64) {
+    APPROVAL_MEMOS.remove(storage, (account, group_id.u64()));
+}
+
+/// Fetches the memo provided with the account's current approval of a group, if one was provided.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `account` The bech32 address of the account.
+/// * `group_id` The unique identifier of the group.
+pub fn get_approval_memo(
+    storage: &dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+) -> Result<Option<String>, ContractError> {
+    APPROVAL_MEMOS
+        .may_load(storage, (account, group_id.u64()))
+        .map_err(ContractError::storage_error)
+}
+
+fn recent_approval_heights(
+    storage: &dyn Storage,
+    limits: &ApprovalLimits,
+    account: &Addr,
+    block_height: u64,
+) -> Result<Vec<u64>, ContractError> {
+    RECENT_APPROVAL_HEIGHTS
+        .may_load(storage, account)
+        .map_err(ContractError::storage_error)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|height| height.saturating_add(limits.window_blocks.u64()) > block_height)
+        .collect::<Vec<u64>>()
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_activity::{
+        check_approval_limits, clear_approval_memo, get_approval_memo, record_account_approval,
+        record_account_withdrawal, record_approval_memo, RECENT_APPROVAL_HEIGHTS,
+    };
+    use crate::store::contract_state::ApprovalLimits;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::{Addr, Storage, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_disabled_limits_never_reject() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let limits = ApprovalLimits::default();
+        for height in 1..=5 {
+            check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), height)
+                .expect("disabled limits should never reject an approval");
+            record_account_approval(&mut deps.storage, &limits, &account, height)
+                .expect("the approval should be recorded");
+            record_account_withdrawal(&mut deps.storage, &account, Uint64::new(1), height)
+                .expect("the withdrawal should be recorded");
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_within_window() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(2)),
+            window_blocks: Uint64::new(10),
+            reapproval_cooldown_blocks: Uint64::new(0),
+        };
+        for height in [100, 105] {
+            check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), height)
+                .expect("approvals below the maximum should be accepted");
+            record_account_approval(&mut deps.storage, &limits, &account, height)
+                .expect("the approval should be recorded");
+        }
+        match check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), 109)
+            .expect_err("an approval beyond the maximum should be rejected")
+        {
+            ContractError::ApprovalRateLimitError {
+                max_approvals,
+                window_blocks,
+                available_at_height,
+                ..
+            } => {
+                assert_eq!(2, max_approvals, "the maximum should be reported");
+                assert_eq!(10, window_blocks, "the window should be reported");
+                assert_eq!(
+                    110, available_at_height,
+                    "the height at which the oldest approval leaves the window should be reported",
+                );
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), 110)
+            .expect("an approval should be accepted once the oldest approval leaves the window");
+        check_approval_limits(
+            &deps.storage,
+            &limits,
+            &Addr::unchecked("other"),
+            Uint64::new(1),
+            109,
+        )
+        .expect("limits should be tracked separately for each account");
+    }
+
+    #[test]
+    fn test_reapproval_cooldown() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let limits = ApprovalLimits {
+            max_approvals_per_window: None,
+            window_blocks: Uint64::new(0),
+            reapproval_cooldown_blocks: Uint64::new(50),
+        };
+        record_account_withdrawal(&mut deps.storage, &account, Uint64::new(1), 100)
+            .expect("the withdrawal should be recorded");
+        match check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), 149)
+            .expect_err("a re-approval during the cooldown should be rejected")
+        {
+            ContractError::ReapprovalCooldownError {
+                group_id,
+                available_at_height,
+                ..
+            } => {
+                assert_eq!(1, group_id, "the group should be reported");
+                assert_eq!(
+                    150, available_at_height,
+                    "the height at which the cooldown ends should be reported",
+                );
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        check_approval_limits(&deps.storage, &limits, &account, Uint64::new(2), 149)
+            .expect("the cooldown should only apply to the withdrawn group");
+        check_approval_limits(&deps.storage, &limits, &account, Uint64::new(1), 150)
+            .expect("a re-approval should be accepted once the cooldown ends");
+    }
+
+    #[test]
+    fn test_recent_approval_heights_are_trimmed_on_write() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let limits = ApprovalLimits {
+            max_approvals_per_window: Some(Uint64::new(3)),
+            window_blocks: Uint64::new(10),
+            reapproval_cooldown_blocks: Uint64::new(0),
+        };
+        for height in [100, 101, 102, 103, 104] {
+            record_account_approval(&mut deps.storage, &limits, &account, height)
+                .expect("the approval should be recorded");
+        }
+        let heights = |storage: &dyn Storage| {
+            RECENT_APPROVAL_HEIGHTS
+                .may_load(storage, &account)
+                .expect("the heights should load")
+        };
+        assert_eq!(
+            Some(vec![102, 103, 104]),
+            heights(&deps.storage),
+            "only the most recent heights up to the maximum should be kept",
+        );
+        record_account_approval(&mut deps.storage, &limits, &account, 113)
+            .expect("the approval should be recorded");
+        assert_eq!(
+            Some(vec![104, 113]),
+            heights(&deps.storage),
+            "heights outside the window should be discarded",
+        );
+        record_account_approval(&mut deps.storage, &ApprovalLimits::default(), &account, 114)
+            .expect("the approval should be recorded");
+        assert_eq!(
+            None,
+            heights(&deps.storage),
+            "nothing should be tracked once rate limiting is disabled",
+        );
+    }
+
+    #[test]
+    fn test_approval_memo_follows_the_current_approval() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let group_id = Uint64::new(1);
+        record_approval_memo(&mut deps.storage, &account, group_id, Some("signer"))
+            .expect("the memo should be recorded");
+        assert_eq!(
+            Some("signer".to_string()),
+            get_approval_memo(&deps.storage, &account, group_id).expect("the memo should load"),
+            "the memo of the current approval should be returned",
+        );
+        assert_eq!(
+            None,
+            get_approval_memo(&deps.storage, &account, Uint64::new(2))
+                .expect("the memo should load"),
+            "memos should be tracked per group",
+        );
+        record_approval_memo(&mut deps.storage, &account, group_id, None)
+            .expect("the memo should be recorded");
+        assert_eq!(
+            None,
+            get_approval_memo(&deps.storage, &account, group_id).expect("the memo should load"),
+            "an approval without a memo should replace the earlier memo",
+        );
+        record_approval_memo(&mut deps.storage, &account, group_id, Some("observer"))
+            .expect("the memo should be recorded");
+        clear_approval_memo(&mut deps.storage, &account, group_id);
+        assert_eq!(
+            None,
+            get_approval_memo(&deps.storage, &account, group_id).expect("the memo should load"),
+            "a cleared memo should no longer be returned",
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use crate::types::core::error::ContractError;
+use crate::util::pagination::query_limit;
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage, Timestamp, Uint64};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, MultiIndex};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_APPROVAL_EVENT_SEQUENCE: &str = "approval_event_sequence";
+const NAMESPACE_APPROVAL_EVENTS: &str = "approval_events";
+const NAMESPACE_APPROVAL_EVENTS_ACCOUNT_INDEX: &str = "approval_events__account";
+const NAMESPACE_APPROVAL_EVENTS_GROUP_INDEX: &str = "approval_events__group_id";
+const APPROVAL_EVENT_SEQUENCE: Item<u64> = Item::new(NAMESPACE_APPROVAL_EVENT_SEQUENCE);
+
+/// Denotes the type of change to an account's consent that an [ApprovalEvent] records.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalAction {
+    /// The account consented to membership in the group.
+    Approve,
+    /// The account withdrew its consent to membership in the group.
+    Withdraw,
+    /// The contract admin removed the account's consent, such as for a compromised or offboarded
+    /// account.  The admin's reason is recorded as the event's memo.
+    ForceRevoke,
+    /// The account accepted the currently published terms of a group it had already approved.
+    /// The approval itself is unchanged.
+    AcknowledgeTerms,
+}
+
+/// A single append-only record of an account's consent being given or withdrawn.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ApprovalEvent {
+    /// The unique, monotonically-increasing identifier of this event.
+    pub sequence: Uint64,
+    /// The bech32 address of the account whose consent changed.
+    pub account: Addr,
+    /// The unique identifier of the group to which the consent applies.
+    pub group_id: Uint64,
+    /// The bech32 address of the group policy account to which the consent applies, if it was
+    /// given for a single policy of the group rather than the whole group.
+    pub policy_address: Option<Addr>,
+    /// The type of change that occurred.
+    pub action: ApprovalAction,
+    /// The block height at which the change occurred.
+    pub block_height: Uint64,
+    /// The block time at which the change occurred.
+    pub time: Timestamp,
+    /// An optional statement provided with an approval, such as the capacity in which the account
+    /// consents to the group, or the admin's reason for a forced revocation.
+    pub memo: Option<String>,
+}
+
+/// Optional criteria used to narrow the results of [get_approval_events].  All provided criteria
+/// must match for an event to be included, and all ranges are inclusive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ApprovalEventFilter {
+    /// Only include events for this bech32 account address.
+    pub account: Option<String>,
+    /// Only include events for this group id.
+    pub group_id: Option<Uint64>,
+    /// Only include events recorded at or after this block height.
+    pub min_height: Option<Uint64>,
+    /// Only include events recorded at or before this block height.
+    pub max_height: Option<Uint64>,
+    /// Only include events recorded at or after this block time.
+    pub min_time: Option<Timestamp>,
+    /// Only include events recorded at or before this block time.
+    pub max_time: Option<Timestamp>,
+}
+impl ApprovalEventFilter {
+    fn matches(&self, event: &ApprovalEvent) -> bool {
+        self.account
+            .as_ref()
+            .is_none_or(|account| event.account.as_str() == account)
+            && self.group_id.is_none_or(|id| event.group_id == id)
+            && self.min_height.is_none_or(|h| event.block_height >= h)
+            && self.max_height.is_none_or(|h| event.block_height <= h)
+            && self.min_time.is_none_or(|t| event.time >= t)
+            && self.max_time.is_none_or(|t| event.time <= t)
+    }
+
+    /// Events are appended in block order, so once an event is past the filter's maximum height
+    /// or time, no later event in sequence order can match.
+    fn is_past_range(&self, event: &ApprovalEvent) -> bool {
+        self.max_height.is_some_and(|h| event.block_height > h)
+            || self.max_time.is_some_and(|t| event.time > t)
+    }
+}
+
+struct ApprovalEventIndexes<'a> {
+    account: MultiIndex<'a, String, ApprovalEvent, u64>,
+    group_id: MultiIndex<'a, u64, ApprovalEvent, u64>,
+}
+impl IndexList<ApprovalEvent> for ApprovalEventIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ApprovalEvent>> + '_> {
+        let indexes: Vec<&dyn Index<ApprovalEvent>> = vec![&self.account, &self.group_id];
+        Box::new(indexes.into_iter())
+    }
+}
+
+fn approval_events<'a>() -> IndexedMap<u64, ApprovalEvent, ApprovalEventIndexes<'a>> {
+    IndexedMap::new(
+        NAMESPACE_APPROVAL_EVENTS,
+        ApprovalEventIndexes {
+            account: MultiIndex::new(
+                |_, event| event.account.to_string(),
+                NAMESPACE_APPROVAL_EVENTS,
+                NAMESPACE_APPROVAL_EVENTS_ACCOUNT_INDEX,
+            ),
+            group_id: MultiIndex::new(
+                |_, event| event.group_id.u64(),
+                NAMESPACE_APPROVAL_EVENTS,
+                NAMESPACE_APPROVAL_EVENTS_GROUP_INDEX,
+            ),
+        },
+    )
+}
+
+/// Appends a new [ApprovalEvent] to the audit trail, assigning it the next available sequence and
+/// stamping it with the current block's height and time.  Existing events are never modified.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `account` The bech32 address of the account whose consent changed.
+/// * `group_id` The unique identifier of the group to which the consent applies.
+/// * `policy_address` The group policy account to which the consent applies, if it was given for a
+///   single policy of the group.
+/// * `action` The type of change that occurred.
+/// * `memo` An optional, previously-validated statement provided with the change.
+pub fn record_approval_event(
+    storage: &mut dyn Storage,
+    env: &Env,
+    account: &Addr,
+    group_id: Uint64,
+    policy_address: Option<&Addr>,
+    action: ApprovalAction,
+    memo: Option<String>,
+) -> Result<ApprovalEvent, ContractError> {
+    let sequence = APPROVAL_EVENT_SEQUENCE
+        .may_load(storage)
+        .map_err(ContractError::storage_error)?
+        .unwrap_or_default()
+        + 1;
+    let event = ApprovalEvent {
+        sequence: Uint64::new(sequence),
+        account: account.to_owned(),
+        group_id,
+        policy_address: policy_address.cloned(),
+        action,
+        block_height: Uint64::new(env.block.height),
+        time: env.block.time,
+        memo,
+    };
+    APPROVAL_EVENT_SEQUENCE
+        .save(storage, &sequence)
+        .map_err(ContractError::storage_error)?;
+    approval_events()
+        .save(storage, sequence, &event)
+        .map_err(ContractError::storage_error)?;
+    event.to_ok()
+}
+
+/// Fetches a page of approval events matching the given filter, in ascending order of sequence.
+/// When an account or group id is provided, the corresponding index is used to avoid scanning
+/// unrelated events, and the scan stops at the first event past a maximum height or time.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `filter` The criteria that each returned event must match.
+/// * `start_after` If provided, only events with a sequence greater than this value will be
+///   returned.
+/// * `limit` The maximum number of events to return.
+pub fn get_approval_events(
+    storage: &dyn Storage,
+    filter: &ApprovalEventFilter,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<ApprovalEvent>, ContractError> {
+    let events = approval_events();
+    let min = start_after.map(Bound::exclusive);
+    let iter: Box<dyn Iterator<Item = StdResult<(u64, ApprovalEvent)>>> = if let Some(account) =
+        &filter.account
+    {
+        events
+            .idx
+            .account
+            .prefix(account.to_owned())
+            .range(storage, min, None, Order::Ascending)
+    } else if let Some(group_id) = filter.group_id {
+        events
+            .idx
+            .group_id
+            .prefix(group_id.u64())
+            .range(storage, min, None, Order::Ascending)
+    } else {
+        events.range(storage, min, None, Order::Ascending)
+    };
+    let mut results = vec![];
+    for result in iter {
+        let (_, event) = result.map_err(ContractError::storage_error)?;
+        if filter.is_past_range(&event) {
+            break;
+        }
+        if filter.matches(&event) {
+            results.push(event);
+            if results.len() >= query_limit(limit) {
+                break;
+            }
+        }
+    }
+    results.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::{
+        get_approval_events, record_approval_event, ApprovalAction, ApprovalEventFilter,
+        NAMESPACE_APPROVAL_EVENTS,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Env, Timestamp, Uint64};
+    use cw_storage_plus::Map;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn env_at(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env.block.time = Timestamp::from_seconds(height * 10);
+        env
+    }
+
+    fn sequences(
+        storage: &dyn cosmwasm_std::Storage,
+        filter: ApprovalEventFilter,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<u64> {
+        get_approval_events(storage, &filter, start_after, limit)
+            .expect("approval events should load")
+            .into_iter()
+            .map(|event| event.sequence.u64())
+            .collect()
+    }
+
+    #[test]
+    fn test_approval_event_filters() {
+        let mut deps = mock_provenance_dependencies();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        for (height, account, group_id, action) in [
+            (1, &alice, 1, ApprovalAction::Approve),
+            (2, &bob, 1, ApprovalAction::Approve),
+            (3, &alice, 2, ApprovalAction::Approve),
+            (4, &alice, 1, ApprovalAction::Withdraw),
+            (5, &bob, 2, ApprovalAction::Approve),
+        ] {
+            record_approval_event(
+                &mut deps.storage,
+                &env_at(height),
+                account,
+                Uint64::new(group_id),
+                None,
+                action,
+                None,
+            )
+            .expect("approval event should record");
+        }
+        let cases: Vec<(ApprovalEventFilter, Vec<u64>)> = vec![
+            (ApprovalEventFilter::default(), vec![1, 2, 3, 4, 5]),
+            (
+                ApprovalEventFilter {
+                    account: Some("alice".to_string()),
+                    ..Default::default()
+                },
+                vec![1, 3, 4],
+            ),
+            (
+                ApprovalEventFilter {
+                    group_id: Some(Uint64::new(1)),
+                    ..Default::default()
+                },
+                vec![1, 2, 4],
+            ),
+            (
+                ApprovalEventFilter {
+                    account: Some("alice".to_string()),
+                    group_id: Some(Uint64::new(1)),
+                    ..Default::default()
+                },
+                vec![1, 4],
+            ),
+            (
+                ApprovalEventFilter {
+                    min_height: Some(Uint64::new(2)),
+                    max_height: Some(Uint64::new(4)),
+                    ..Default::default()
+                },
+                vec![2, 3, 4],
+            ),
+            (
+                ApprovalEventFilter {
+                    group_id: Some(Uint64::new(2)),
+                    min_time: Some(Timestamp::from_seconds(40)),
+                    ..Default::default()
+                },
+                vec![5],
+            ),
+            (
+                ApprovalEventFilter {
+                    max_time: Some(Timestamp::from_seconds(20)),
+                    ..Default::default()
+                },
+                vec![1, 2],
+            ),
+            (
+                ApprovalEventFilter {
+                    account: Some("carol".to_string()),
+                    ..Default::default()
+                },
+                vec![],
+            ),
+        ];
+        for (filter, expected) in cases {
+            assert_eq!(
+                expected,
+                sequences(&deps.storage, filter.clone(), None, None),
+                "unexpected events for filter {filter:?}",
+            );
+        }
+        assert_eq!(
+            vec![3],
+            sequences(
+                &deps.storage,
+                ApprovalEventFilter {
+                    account: Some("alice".to_string()),
+                    ..Default::default()
+                },
+                Some(1),
+                Some(1),
+            ),
+            "pagination should respect start_after and limit within an index",
+        );
+        assert_eq!(
+            vec![4, 5],
+            sequences(&deps.storage, ApprovalEventFilter::default(), Some(3), None),
+            "pagination should respect start_after without a filter",
+        );
+    }
+
+    #[test]
+    fn test_recorded_event_values() {
+        let mut deps = mock_provenance_dependencies();
+        let env = env_at(12);
+        let event = record_approval_event(
+            &mut deps.storage,
+            &env,
+            &Addr::unchecked("account"),
+            Uint64::new(3),
+            None,
+            ApprovalAction::Withdraw,
+            None,
+        )
+        .expect("approval event should record");
+        assert_eq!(
+            Uint64::new(1),
+            event.sequence,
+            "the first event should have sequence 1"
+        );
+        assert_eq!(
+            Uint64::new(12),
+            event.block_height,
+            "the block height should be recorded"
+        );
+        assert_eq!(
+            Timestamp::from_seconds(120),
+            event.time,
+            "the block time should be recorded",
+        );
+        assert_eq!(
+            ApprovalAction::Withdraw,
+            event.action,
+            "the action should be recorded",
+        );
+    }
+
+    #[test]
+    fn test_range_filters_stop_at_first_event_past_the_range() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        for height in 1..=3 {
+            record_approval_event(
+                &mut deps.storage,
+                &env_at(height),
+                &account,
+                Uint64::new(1),
+                None,
+                ApprovalAction::Approve,
+                None,
+            )
+            .expect("approval event should record");
+        }
+        // Replace the final event with a value that cannot be decoded, so any read past the range
+        // fails the query
+        Map::<u64, String>::new(NAMESPACE_APPROVAL_EVENTS)
+            .save(&mut deps.storage, 3, &"corrupt".to_string())
+            .expect("the corrupt value should be written");
+        for filter in [
+            ApprovalEventFilter {
+                max_height: Some(Uint64::new(1)),
+                ..Default::default()
+            },
+            ApprovalEventFilter {
+                max_time: Some(Timestamp::from_seconds(10)),
+                ..Default::default()
+            },
+        ] {
+            assert_eq!(
+                vec![1],
+                sequences(&deps.storage, filter.clone(), None, None),
+                "the scan should stop before the event past the range for filter {filter:?}",
+            );
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::types::core::error::ContractError;
+use crate::util::pagination::query_limit;
+use cosmwasm_std::{Addr, Order, Storage, Uint64};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_BLOCKLIST: &str = "blocklist";
+const BLOCKLIST: Map<&Addr, BlockedAccount> = Map::new(NAMESPACE_BLOCKLIST);
+
+/// An account that the contract admin has barred from recording new approvals.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BlockedAccount {
+    /// The bech32 address of the blocked account.
+    pub account: Addr,
+    /// The admin-provided reason for the block, such as a sanctions or offboarding reference.
+    pub reason: String,
+    /// The block height at which the account was most recently blocked.
+    pub blocked_at_height: Uint64,
+}
+
+/// Adds the account to the blocklist, replacing any existing entry for it.  Returns the previous
+/// entry, if the account was already blocked.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `blocked_account` The blocklist entry to store.
+pub fn block_account(
+    storage: &mut dyn Storage,
+    blocked_account: &BlockedAccount,
+) -> Result<Option<BlockedAccount>, ContractError> {
+    let existing = get_blocked_account(storage, &blocked_account.account)?;
+    BLOCKLIST
+        .save(storage, &blocked_account.account, blocked_account)
+        .map_err(ContractError::storage_error)?;
+    existing.to_ok()
+}
+
+/// Removes the account from the blocklist.  Returns the removed entry, or `None` if the account was
+/// not blocked.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account to unblock.
+pub fn unblock_account(
+    storage: &mut dyn Storage,
+    account: &Addr,
+) -> Result<Option<BlockedAccount>, ContractError> {
+    let existing = get_blocked_account(storage, account)?;
+    BLOCKLIST.remove(storage, account);
+    existing.to_ok()
+}
+
+/// Fetches the blocklist entry for the account, if one exists.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `account` The bech32 address of the account to look up.
+pub fn get_blocked_account(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<Option<BlockedAccount>, ContractError> {
+    BLOCKLIST
+        .may_load(storage, account)
+        .map_err(ContractError::storage_error)
+}
+
+/// Produces a [BlockedAccountError](ContractError::BlockedAccountError) if the account is on the
+/// blocklist.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `account` The bech32 address of the account attempting to record an approval.
+pub fn check_account_not_blocked(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    match get_blocked_account(storage, account)? {
+        Some(blocked_account) => ContractError::BlockedAccountError {
+            account: blocked_account.account.into_string(),
+            reason: blocked_account.reason,
+        }
+        .to_err(),
+        None => ().to_ok(),
+    }
+}
+
+/// Fetches a page of blocklist entries in ascending order of account address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `start_after` If provided, only accounts with an address greater than this value will be
+///   returned.
+/// * `limit` The maximum number of entries to return.
+pub fn get_blocked_accounts(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> Result<Vec<BlockedAccount>, ContractError> {
+    BLOCKLIST
+        .range(
+            storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(query_limit(limit))
+        .map(|result| {
+            result
+                .map(|(_, entry)| entry)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::blocklist::{
+        block_account, check_account_not_blocked, get_blocked_accounts, unblock_account,
+        BlockedAccount,
+    };
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn blocked(account: &str, reason: &str) -> BlockedAccount {
+        BlockedAccount {
+            account: Addr::unchecked(account),
+            reason: reason.to_string(),
+            blocked_at_height: Uint64::new(1),
+        }
+    }
+
+    #[test]
+    fn test_block_and_unblock_account() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        check_account_not_blocked(&deps.storage, &account)
+            .expect("an account should not be blocked by default");
+        assert_eq!(
+            None,
+            block_account(&mut deps.storage, &blocked("account", "first"))
+                .expect("the account should be blocked"),
+            "no previous entry should exist for a newly-blocked account",
+        );
+        assert_eq!(
+            Some(blocked("account", "first")),
+            block_account(&mut deps.storage, &blocked("account", "second"))
+                .expect("the account should be re-blocked"),
+            "re-blocking should return the replaced entry",
+        );
+        match check_account_not_blocked(&deps.storage, &account)
+            .expect_err("a blocked account should fail the check")
+        {
+            ContractError::BlockedAccountError { account, reason } => {
+                assert_eq!("account", account, "the blocked account should be reported");
+                assert_eq!("second", reason, "the latest reason should be reported");
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        assert_eq!(
+            Some(blocked("account", "second")),
+            unblock_account(&mut deps.storage, &account).expect("the account should be unblocked"),
+            "unblocking should return the removed entry",
+        );
+        check_account_not_blocked(&deps.storage, &account)
+            .expect("an unblocked account should pass the check");
+        assert_eq!(
+            None,
+            unblock_account(&mut deps.storage, &account).expect("unblocking should not fail"),
+            "unblocking an account that is not blocked should return nothing",
+        );
+    }
+
+    #[test]
+    fn test_get_blocked_accounts_paginates() {
+        let mut deps = mock_provenance_dependencies();
+        for account in ["c", "a", "b"] {
+            block_account(&mut deps.storage, &blocked(account, "reason"))
+                .expect("the account should be blocked");
+        }
+        let accounts = get_blocked_accounts(&deps.storage, Some(Addr::unchecked("a")), Some(1))
+            .expect("blocked accounts should load")
+            .into_iter()
+            .map(|entry| entry.account.into_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            vec!["b".to_string()],
+            accounts,
+            "pagination should respect start_after and limit",
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::store::contract_state::{get_contract_state, CONTRACT_TYPE, CONTRACT_VERSION};
+use crate::types::core::error::ContractError;
+use cosmwasm_std::Storage;
+use cw2::{ContractVersion, CONTRACT};
+use result_extensions::ResultExtensions;
+
+/// Writes the current [CONTRACT_TYPE] and [CONTRACT_VERSION] to the standard
+/// [cw2](https://github.com/CosmWasm/cw-minus/tree/main/packages/cw2) `contract_info` key, which
+/// allows standard CosmWasm tooling to identify this contract.  An error is returned if the store
+/// write is unsuccessful.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+pub fn set_contract_info(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    cw2::set_contract_version(storage, CONTRACT_TYPE, CONTRACT_VERSION)
+        .map_err(ContractError::storage_error)
+}
+
+/// Fetches the stored contract type and version.  The cw2 `contract_info` value is used if it
+/// exists, but instances created before cw2 values were written will fall back to the values held
+/// in the [ContractState](crate::store::contract_state::ContractState).
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+pub fn get_contract_info(storage: &dyn Storage) -> Result<ContractVersion, ContractError> {
+    match CONTRACT
+        .may_load(storage)
+        .map_err(ContractError::storage_error)?
+    {
+        Some(contract_version) => contract_version.to_ok(),
+        None => {
+            let contract_state = get_contract_state(storage)?;
+            ContractVersion {
+                contract: contract_state.contract_type,
+                version: contract_state.contract_version,
+            }
+            .to_ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_info::{get_contract_info, set_contract_info};
+    use crate::store::contract_state::{
+        set_contract_state, ContractState, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::test::test_constants::{
+        DEFAULT_CONTRACT_ADMIN, DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_CONTRACT_NAME,
+    };
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_set_and_get_contract_info() {
+        let mut deps = mock_provenance_dependencies();
+        set_contract_info(&mut deps.storage).expect("the contract info should be saved");
+        let contract_info =
+            get_contract_info(&deps.storage).expect("the contract info should be loaded");
+        assert_eq!(
+            CONTRACT_TYPE, contract_info.contract,
+            "unexpected contract type",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, contract_info.version,
+            "unexpected contract version",
+        );
+        assert_eq!(
+            contract_info,
+            cw2::get_contract_version(&deps.storage)
+                .expect("the value should be readable by the cw2 library"),
+            "the stored value should be cw2-compatible",
+        );
+    }
+
+    #[test]
+    fn test_get_contract_info_falls_back_to_contract_state() {
+        let mut deps = mock_provenance_dependencies();
+        let mut contract_state = ContractState::new(
+            Addr::unchecked(DEFAULT_CONTRACT_ADMIN),
+            DEFAULT_CONTRACT_ATTRIBUTE,
+            DEFAULT_CONTRACT_NAME,
+        );
+        contract_state.contract_version = "0.0.1".to_string();
+        set_contract_state(&mut deps.storage, &contract_state)
+            .expect("the contract state should be saved");
+        let contract_info = get_contract_info(&deps.storage)
+            .expect("the contract info should be derived from the contract state");
+        assert_eq!(
+            CONTRACT_TYPE, contract_info.contract,
+            "the contract type should be derived from the contract state",
+        );
+        assert_eq!(
+            "0.0.1", contract_info.version,
+            "the contract version should be derived from the contract state",
+        );
+    }
+
+    #[test]
+    fn test_get_contract_info_with_no_values_stored() {
+        let deps = mock_provenance_dependencies();
+        assert!(
+            matches!(
+                get_contract_info(&deps.storage),
+                Err(ContractError::StorageError { .. })
+            ),
+            "a storage error should be emitted when neither source of version info exists",
+        );
+    }
+}
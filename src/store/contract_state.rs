@@ -1,5 +1,5 @@
 use crate::types::core::error::ContractError;
-use cosmwasm_std::{Addr, Storage};
+use cosmwasm_std::{Addr, Storage, Uint64};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -26,7 +26,45 @@ pub struct ContractState {
     /// The crate version, used to ensure that newly-migrated instances do not attempt to use an
     /// identical or older version.
     pub contract_version: String,
+    /// Limits on how frequently a single account may record approvals.  Instances stored before
+    /// limits were introduced load with all limits disabled.
+    #[serde(default)]
+    pub approval_limits: ApprovalLimits,
+    /// Determines how approval routes respond when the chain query for an account's existing
+    /// attributes fails.  Instances stored before this setting was introduced load in strict mode.
+    #[serde(default)]
+    pub attribute_query_mode: AttributeQueryMode,
 }
+
+/// Admin-configured limits that prevent a single account from churning approvals and withdrawals.
+/// All limits are disabled by default.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct ApprovalLimits {
+    /// The maximum number of approvals a single account may record within any window of
+    /// [window_blocks](Self::window_blocks) blocks.  No limit is enforced when omitted.
+    pub max_approvals_per_window: Option<Uint64>,
+    /// The size, in blocks, of the window used with [max_approvals_per_window](Self::max_approvals_per_window).
+    /// Must be greater than zero when a maximum is set.
+    pub window_blocks: Uint64,
+    /// The number of blocks that must pass after an account withdraws its approval of a group
+    /// before it may approve that group again.  Zero disables the cooldown.
+    pub reapproval_cooldown_blocks: Uint64,
+}
+
+/// Determines how approval routes respond when the chain query for an account's existing
+/// attributes fails.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeQueryMode {
+    /// A failed query aborts the route with an [AttributeQueryError](ContractError::AttributeQueryError),
+    /// because duplicate detection cannot be performed.
+    #[default]
+    Strict,
+    /// A failed query is treated as the account having no attributes.  Duplicate detection is
+    /// skipped, so a duplicate attribute may be written.
+    Lenient,
+}
+
 impl ContractState {
     /// Constructs a new instance of this struct.
     ///
@@ -34,10 +72,10 @@ impl ContractState {
     ///
     /// * `admin` The bech32 address of the account that has admin rights within this contract.
     /// * `attribute_name` The [Provenance Name Module](https://docs.provenance.io/modules/name-module)
-    /// fully-qualified name that is used to bind attributes to accounts when consenting to group
-    /// membership.
+    ///   fully-qualified name that is used to bind attributes to accounts when consenting to group
+    ///   membership.
     /// * `contract_name` A free-form name defining this particular contract instance.  Used for
-    /// identification on query purposes only.
+    ///   identification on query purposes only.
     pub fn new<S1: Into<String>, S2: Into<String>>(
         admin: Addr,
         attribute_name: S1,
@@ -49,6 +87,8 @@ impl ContractState {
             contract_name: contract_name.into(),
             contract_type: CONTRACT_TYPE.to_string(),
             contract_version: CONTRACT_VERSION.to_string(),
+            approval_limits: ApprovalLimits::default(),
+            attribute_query_mode: AttributeQueryMode::default(),
         }
     }
 }
@@ -59,7 +99,7 @@ impl ContractState {
 /// # Parameters
 ///
 /// * `storage` A mutable instance of the contract storage value, allowing internal store
-/// manipulation.
+///   manipulation.
 /// * `contract_state` The new value for which an internal storage write will be done.
 pub fn set_contract_state(
     storage: &mut dyn Storage,
@@ -67,9 +107,7 @@ pub fn set_contract_state(
 ) -> Result<(), ContractError> {
     CONTRACT_STATE
         .save(storage, contract_state)
-        .map_err(|e| ContractError::StorageError {
-            message: format!("{e:?}"),
-        })
+        .map_err(ContractError::storage_error)
 }
 
 /// Fetches the current contract instance of contract state.  This call should never fail because
@@ -79,13 +117,11 @@ pub fn set_contract_state(
 /// # Parameters
 ///
 /// * `storage` An immutable instance of the contract storage value, allowing internal store data
-/// fetches.
+///   fetches.
 pub fn get_contract_state(storage: &dyn Storage) -> Result<ContractState, ContractError> {
     CONTRACT_STATE
         .load(storage)
-        .map_err(|e| ContractError::StorageError {
-            message: format!("{e:?}"),
-        })
+        .map_err(ContractError::storage_error)
 }
 
 #[cfg(test)]
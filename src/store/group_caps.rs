@@ -0,0 +1,197 @@
+use crate::store::group_stats::get_group_stats;
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{Storage, Uint64};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_GROUP_MEMBER_CAPS: &str = "group_member_caps";
+const GROUP_MEMBER_CAPS: Map<u64, u64> = Map::new(NAMESPACE_GROUP_MEMBER_CAPS);
+
+/// Describes how many more accounts may consent to membership in a group.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct GroupCapacity {
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// The admin-configured maximum number of consenting members, if the group is capped.
+    pub max_members: Option<Uint64>,
+    /// The number of accounts that currently consent to membership in the group, as counted in the
+    /// group's [statistics](crate::store::group_stats::GroupStats).
+    pub current_approvals: Uint64,
+    /// The number of additional approvals the group can accept, or `None` if the group is uncapped.
+    pub remaining_capacity: Option<Uint64>,
+}
+
+/// Sets the maximum number of consenting members for the given group, or removes the cap if none is
+/// provided.  Returns the previous cap, if one was set.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `group_id` The unique identifier of the group to cap.
+/// * `max_members` The maximum number of consenting members, or `None` to remove the cap.
+pub fn set_group_member_cap(
+    storage: &mut dyn Storage,
+    group_id: Uint64,
+    max_members: Option<u64>,
+) -> Result<Option<u64>, ContractError> {
+    let previous = get_group_member_cap(storage, group_id)?;
+    match max_members {
+        Some(max_members) => GROUP_MEMBER_CAPS
+            .save(storage, group_id.u64(), &max_members)
+            .map_err(ContractError::storage_error)?,
+        None => GROUP_MEMBER_CAPS.remove(storage, group_id.u64()),
+    };
+    previous.to_ok()
+}
+
+/// Fetches the maximum number of consenting members for the given group, if one is set.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group.
+pub fn get_group_member_cap(
+    storage: &dyn Storage,
+    group_id: Uint64,
+) -> Result<Option<u64>, ContractError> {
+    GROUP_MEMBER_CAPS
+        .may_load(storage, group_id.u64())
+        .map_err(ContractError::storage_error)
+}
+
+/// Computes the remaining capacity of the given group from its cap and its contract-side approval
+/// count.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group.
+pub fn get_group_capacity(
+    storage: &dyn Storage,
+    group_id: Uint64,
+) -> Result<GroupCapacity, ContractError> {
+    let max_members = get_group_member_cap(storage, group_id)?.map(Uint64::new);
+    let current_approvals = get_group_stats(storage, group_id)?.current_approvals;
+    GroupCapacity {
+        group_id,
+        max_members,
+        current_approvals,
+        remaining_capacity: max_members.map(|max| max.saturating_sub(current_approvals)),
+    }
+    .to_ok()
+}
+
+/// Produces a [GroupCapacityError](ContractError::GroupCapacityError) if the given group has no
+/// remaining capacity for another approval.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group receiving an approval.
+pub fn check_group_has_capacity(
+    storage: &dyn Storage,
+    group_id: Uint64,
+) -> Result<(), ContractError> {
+    match get_group_capacity(storage, group_id)? {
+        GroupCapacity {
+            max_members: Some(max_members),
+            remaining_capacity: Some(remaining),
+            ..
+        } if remaining.is_zero() => ContractError::GroupCapacityError {
+            group_id: group_id.u64(),
+            max_members: max_members.u64(),
+        }
+        .to_err(),
+        _ => ().to_ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::group_caps::{
+        check_group_has_capacity, get_group_capacity, set_group_member_cap, GroupCapacity,
+    };
+    use crate::store::group_stats::record_group_approval;
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_uncapped_group_always_has_capacity() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(1);
+        record_group_approval(&mut deps.storage, &Addr::unchecked("first"), group_id, 1)
+            .expect("approval should record");
+        check_group_has_capacity(&deps.storage, group_id)
+            .expect("an uncapped group should always have capacity");
+        assert_eq!(
+            GroupCapacity {
+                group_id,
+                max_members: None,
+                current_approvals: Uint64::new(1),
+                remaining_capacity: None,
+            },
+            get_group_capacity(&deps.storage, group_id).expect("capacity should load"),
+            "an uncapped group should report no remaining capacity limit",
+        );
+    }
+
+    #[test]
+    fn test_capped_group_capacity() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(1);
+        assert_eq!(
+            None,
+            set_group_member_cap(&mut deps.storage, group_id, Some(2))
+                .expect("the cap should be set"),
+            "no previous cap should exist",
+        );
+        record_group_approval(&mut deps.storage, &Addr::unchecked("first"), group_id, 1)
+            .expect("approval should record");
+        assert_eq!(
+            Some(Uint64::new(1)),
+            get_group_capacity(&deps.storage, group_id)
+                .expect("capacity should load")
+                .remaining_capacity,
+            "a single slot should remain",
+        );
+        check_group_has_capacity(&deps.storage, group_id)
+            .expect("a group with a remaining slot should have capacity");
+        record_group_approval(&mut deps.storage, &Addr::unchecked("second"), group_id, 2)
+            .expect("approval should record");
+        match check_group_has_capacity(&deps.storage, group_id)
+            .expect_err("a full group should not have capacity")
+        {
+            ContractError::GroupCapacityError {
+                group_id,
+                max_members,
+            } => {
+                assert_eq!(1, group_id, "the full group should be reported");
+                assert_eq!(2, max_members, "the group's cap should be reported");
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        assert_eq!(
+            Some(2),
+            set_group_member_cap(&mut deps.storage, group_id, Some(1))
+                .expect("the cap should be lowered"),
+            "the previous cap should be returned",
+        );
+        assert_eq!(
+            Some(Uint64::new(0)),
+            get_group_capacity(&deps.storage, group_id)
+                .expect("capacity should load")
+                .remaining_capacity,
+            "a cap below the current approval count should never underflow",
+        );
+        set_group_member_cap(&mut deps.storage, group_id, None).expect("the cap should be removed");
+        check_group_has_capacity(&deps.storage, group_id)
+            .expect("a group should have capacity after its cap is removed");
+    }
+}
@@ -0,0 +1,451 @@
+use crate::types::core::error::ContractError;
+use crate::util::pagination::query_limit;
+use cosmwasm_std::{Addr, Order, Storage, Uint64};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_GROUP_STATS: &str = "group_stats";
+const GROUP_STATS: Map<u64, GroupStats> = Map::new(NAMESPACE_GROUP_STATS);
+const NAMESPACE_TRACKED_GROUP_APPROVALS: &str = "tracked_group_approvals";
+const TRACKED_GROUP_APPROVALS: Map<(&Addr, u64), Uint64> =
+    Map::new(NAMESPACE_TRACKED_GROUP_APPROVALS);
+const NAMESPACE_TRACKED_POLICY_APPROVALS: &str = "tracked_policy_approvals";
+const TRACKED_POLICY_APPROVALS: Map<(&Addr, &Addr), Uint64> =
+    Map::new(NAMESPACE_TRACKED_POLICY_APPROVALS);
+
+/// Approval counters maintained by the contract for a single group.  Counters only include
+/// approvals and revocations processed after the contract began tracking statistics.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct GroupStats {
+    /// The unique identifier of the group to which these counters apply.
+    pub group_id: Uint64,
+    /// The number of accounts that currently consent to membership in the group.
+    pub current_approvals: Uint64,
+    /// The number of approvals ever recorded for the group, including those later revoked.
+    pub total_approvals: Uint64,
+    /// The number of approvals that have been revoked for the group.
+    pub revocations: Uint64,
+    /// The number of revocations that were forced by the contract admin rather than withdrawn by
+    /// the account.  These are also included in `revocations` or `policy_revocations`.
+    pub forced_revocations: Uint64,
+    /// The block height of the most recent approval for the group, if one has been recorded.
+    pub last_approval_height: Option<Uint64>,
+    /// The number of accounts that currently consent to participation in one of the group's policy
+    /// accounts.  These are not included in `current_approvals`.
+    pub current_policy_approvals: Uint64,
+    /// The number of policy approvals ever recorded for the group, including those later revoked.
+    pub total_policy_approvals: Uint64,
+    /// The number of policy approvals that have been revoked for the group.
+    pub policy_revocations: Uint64,
+}
+impl GroupStats {
+    /// Constructs a new instance of this struct with all counters set to zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `group_id` The unique identifier of the group to which these counters apply.
+    pub fn new(group_id: Uint64) -> Self {
+        Self {
+            group_id,
+            current_approvals: Uint64::zero(),
+            total_approvals: Uint64::zero(),
+            revocations: Uint64::zero(),
+            forced_revocations: Uint64::zero(),
+            last_approval_height: None,
+            current_policy_approvals: Uint64::zero(),
+            total_policy_approvals: Uint64::zero(),
+            policy_revocations: Uint64::zero(),
+        }
+    }
+}
+
+/// Increments the current and total approval counters for the given group and sets its last
+/// approval height.  The approval is marked as tracked for the account, so that only a later
+/// revocation of this approval decrements the current approval counter.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account that approved the group.
+/// * `group_id` The unique identifier of the group that received an approval.
+/// * `block_height` The block height at which the approval occurred.
+pub fn record_group_approval(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+    block_height: u64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = get_group_stats(storage, group_id)?;
+    stats.current_approvals += Uint64::one();
+    stats.total_approvals += Uint64::one();
+    stats.last_approval_height = Some(Uint64::new(block_height));
+    TRACKED_GROUP_APPROVALS
+        .save(
+            storage,
+            (account, group_id.u64()),
+            &Uint64::new(block_height),
+        )
+        .map_err(ContractError::storage_error)?;
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Increments the current and total policy approval counters for the given group.  The approval
+/// is marked as tracked for the account, so that only a later revocation of this approval
+/// decrements the current policy approval counter.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account that approved the policy account.
+/// * `policy_address` The bech32 address of the approved group policy account.
+/// * `group_id` The unique identifier of the group that owns the approved policy account.
+/// * `block_height` The block height at which the approval occurred.
+pub fn record_group_policy_approval(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    policy_address: &Addr,
+    group_id: Uint64,
+    block_height: u64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = get_group_stats(storage, group_id)?;
+    stats.current_policy_approvals += Uint64::one();
+    stats.total_policy_approvals += Uint64::one();
+    TRACKED_POLICY_APPROVALS
+        .save(
+            storage,
+            (account, policy_address),
+            &Uint64::new(block_height),
+        )
+        .map_err(ContractError::storage_error)?;
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Increments the policy revocation counter for the given group.  The current policy approval
+/// counter is only decremented if the revoked approval was counted by
+/// [record_group_policy_approval].
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose policy approval was revoked.
+/// * `policy_address` The bech32 address of the group policy account.
+/// * `group_id` The unique identifier of the group that owns the policy account.
+pub fn record_group_policy_revocation(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    policy_address: &Addr,
+    group_id: Uint64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = get_group_stats(storage, group_id)?;
+    if TRACKED_POLICY_APPROVALS.has(storage, (account, policy_address)) {
+        TRACKED_POLICY_APPROVALS.remove(storage, (account, policy_address));
+        stats.current_policy_approvals -= Uint64::one();
+    }
+    stats.policy_revocations += Uint64::one();
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Increments the revocation counter for the given group.  The current approval counter is only
+/// decremented if the revoked approval was counted by [record_group_approval].
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose approval was revoked.
+/// * `group_id` The unique identifier of the group that had an approval revoked.
+pub fn record_group_revocation(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = get_group_stats(storage, group_id)?;
+    if TRACKED_GROUP_APPROVALS.has(storage, (account, group_id.u64())) {
+        TRACKED_GROUP_APPROVALS.remove(storage, (account, group_id.u64()));
+        stats.current_approvals -= Uint64::one();
+    }
+    stats.revocations += Uint64::one();
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Records a revocation for the given group in the same manner as [record_group_revocation], and
+/// additionally increments the forced revocation counter.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose approval was revoked.
+/// * `group_id` The unique identifier of the group that had an approval revoked by the admin.
+pub fn record_forced_group_revocation(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = record_group_revocation(storage, account, group_id)?;
+    stats.forced_revocations += Uint64::one();
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Records a policy revocation for the given group in the same manner as
+/// [record_group_policy_revocation], and additionally increments the forced revocation counter.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose policy approval was revoked.
+/// * `policy_address` The bech32 address of the group policy account.
+/// * `group_id` The unique identifier of the group that owns the policy account whose approval was
+///   revoked by the admin.
+pub fn record_forced_group_policy_revocation(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    policy_address: &Addr,
+    group_id: Uint64,
+) -> Result<GroupStats, ContractError> {
+    let mut stats = record_group_policy_revocation(storage, account, policy_address, group_id)?;
+    stats.forced_revocations += Uint64::one();
+    save_group_stats(storage, &stats)?;
+    stats.to_ok()
+}
+
+/// Fetches the counters for the given group.  Groups that have never had an approval or revocation
+/// recorded produce a value with all counters set to zero.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group for which to fetch counters.
+pub fn get_group_stats(
+    storage: &dyn Storage,
+    group_id: Uint64,
+) -> Result<GroupStats, ContractError> {
+    GROUP_STATS
+        .may_load(storage, group_id.u64())
+        .map_err(ContractError::storage_error)?
+        .unwrap_or_else(|| GroupStats::new(group_id))
+        .to_ok()
+}
+
+/// Fetches a page of counters for all groups with recorded activity, in ascending order of group
+/// id.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `start_after` If provided, only groups with an id greater than this value will be returned.
+/// * `limit` The maximum number of groups to return.
+pub fn get_all_group_stats(
+    storage: &dyn Storage,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Vec<GroupStats>, ContractError> {
+    GROUP_STATS
+        .range(
+            storage,
+            start_after.map(|id| Bound::exclusive(id.u64())),
+            None,
+            Order::Ascending,
+        )
+        .take(query_limit(limit))
+        .map(|result| {
+            result
+                .map(|(_, stats)| stats)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+fn save_group_stats(storage: &mut dyn Storage, stats: &GroupStats) -> Result<(), ContractError> {
+    GROUP_STATS
+        .save(storage, stats.group_id.u64(), stats)
+        .map_err(ContractError::storage_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::group_stats::{
+        get_all_group_stats, get_group_stats, record_forced_group_revocation,
+        record_group_approval, record_group_policy_approval, record_group_policy_revocation,
+        record_group_revocation, GroupStats,
+    };
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_get_group_stats_for_unknown_group() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            GroupStats::new(Uint64::new(1)),
+            get_group_stats(&deps.storage, Uint64::new(1)).expect("stats should load"),
+            "an unknown group should produce zeroed counters",
+        );
+    }
+
+    #[test]
+    fn test_record_approvals_and_revocations() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(7);
+        let first = Addr::unchecked("first");
+        let second = Addr::unchecked("second");
+        let policy = Addr::unchecked("policy");
+        record_group_approval(&mut deps.storage, &first, group_id, 10)
+            .expect("approval should record");
+        record_group_approval(&mut deps.storage, &second, group_id, 15)
+            .expect("approval should record");
+        record_group_revocation(&mut deps.storage, &first, group_id)
+            .expect("revocation should record");
+        record_group_policy_approval(&mut deps.storage, &first, &policy, group_id, 16)
+            .expect("policy approval should record");
+        record_group_policy_approval(&mut deps.storage, &second, &policy, group_id, 17)
+            .expect("policy approval should record");
+        record_group_policy_revocation(&mut deps.storage, &first, &policy, group_id)
+            .expect("policy revocation should record");
+        assert_eq!(
+            GroupStats {
+                group_id,
+                current_approvals: Uint64::new(1),
+                total_approvals: Uint64::new(2),
+                revocations: Uint64::new(1),
+                forced_revocations: Uint64::new(0),
+                last_approval_height: Some(Uint64::new(15)),
+                current_policy_approvals: Uint64::new(1),
+                total_policy_approvals: Uint64::new(2),
+                policy_revocations: Uint64::new(1),
+            },
+            get_group_stats(&deps.storage, group_id).expect("stats should load"),
+            "the counters should reflect all recorded activity",
+        );
+    }
+
+    #[test]
+    fn test_untracked_revocations_do_not_decrement_current_approvals() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(1);
+        let policy = Addr::unchecked("policy");
+        record_group_approval(&mut deps.storage, &Addr::unchecked("tracked"), group_id, 1)
+            .expect("approval should record");
+        record_group_policy_approval(
+            &mut deps.storage,
+            &Addr::unchecked("tracked"),
+            &policy,
+            group_id,
+            1,
+        )
+        .expect("policy approval should record");
+        let untracked = Addr::unchecked("untracked");
+        record_group_revocation(&mut deps.storage, &untracked, group_id)
+            .expect("revocation should record");
+        let stats =
+            record_group_policy_revocation(&mut deps.storage, &untracked, &policy, group_id)
+                .expect("policy revocation should record");
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "revoking an approval that was never counted should not lower current approvals",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_policy_approvals,
+            "revoking a policy approval that was never counted should not lower current policy approvals",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.revocations,
+            "the revocation should be counted"
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.policy_revocations,
+            "the policy revocation should be counted"
+        );
+    }
+
+    #[test]
+    fn test_revoking_the_same_approval_twice_only_decrements_once() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(1);
+        let account = Addr::unchecked("account");
+        record_group_approval(&mut deps.storage, &Addr::unchecked("other"), group_id, 1)
+            .expect("approval should record");
+        record_group_approval(&mut deps.storage, &account, group_id, 2)
+            .expect("approval should record");
+        record_group_revocation(&mut deps.storage, &account, group_id)
+            .expect("revocation should record");
+        let stats = record_group_revocation(&mut deps.storage, &account, group_id)
+            .expect("revocation should record");
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "only the tracked approval should be removed from the current approvals",
+        );
+    }
+
+    #[test]
+    fn test_forced_revocations_are_counted_separately() {
+        let mut deps = mock_provenance_dependencies();
+        let group_id = Uint64::new(3);
+        let first = Addr::unchecked("first");
+        let second = Addr::unchecked("second");
+        record_group_approval(&mut deps.storage, &first, group_id, 1)
+            .expect("approval should record");
+        record_group_approval(&mut deps.storage, &second, group_id, 2)
+            .expect("approval should record");
+        record_group_revocation(&mut deps.storage, &first, group_id)
+            .expect("revocation should record");
+        let stats = record_forced_group_revocation(&mut deps.storage, &second, group_id)
+            .expect("forced revocation should record");
+        assert_eq!(
+            Uint64::new(0),
+            stats.current_approvals,
+            "both approvals should be removed"
+        );
+        assert_eq!(
+            Uint64::new(2),
+            stats.revocations,
+            "both revocations should be counted"
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.forced_revocations,
+            "only the forced revocation should be counted as forced",
+        );
+    }
+
+    #[test]
+    fn test_get_all_group_stats_paginates() {
+        let mut deps = mock_provenance_dependencies();
+        for group_id in [3u64, 1, 2] {
+            record_group_approval(
+                &mut deps.storage,
+                &Addr::unchecked("account"),
+                Uint64::new(group_id),
+                1,
+            )
+            .expect("approval should record");
+        }
+        let ids = get_all_group_stats(&deps.storage, Some(Uint64::new(1)), Some(1))
+            .expect("stats should load")
+            .into_iter()
+            .map(|stats| stats.group_id.u64())
+            .collect::<Vec<u64>>();
+        assert_eq!(
+            vec![2],
+            ids,
+            "pagination should respect start_after and limit",
+        );
+    }
+}
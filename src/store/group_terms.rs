@@ -0,0 +1,298 @@
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{Addr, Storage, Uint64};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_GROUP_TERMS: &str = "group_terms";
+const GROUP_TERMS: Map<u64, GroupTerms> = Map::new(NAMESPACE_GROUP_TERMS);
+const NAMESPACE_ACCEPTED_TERMS: &str = "accepted_terms";
+const ACCEPTED_TERMS: Map<(&Addr, u64), Uint64> = Map::new(NAMESPACE_ACCEPTED_TERMS);
+
+/// The terms most recently published by the contract admin for a group, which members must
+/// acknowledge by content hash when approving the group.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct GroupTerms {
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// The version of the terms, starting at 1 and incremented with each publication.
+    pub version: Uint64,
+    /// The location at which the full text of the terms can be found.
+    pub uri: String,
+    /// The lowercase hex-encoded hash of the terms' content.
+    pub content_hash: String,
+    /// The block height at which this version was published.
+    pub published_at_height: Uint64,
+}
+
+/// Describes which version of a group's terms an account accepted when approving the group.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TermsAcceptance {
+    /// The bech32 address of the account.
+    pub account: Addr,
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// Whether the account currently holds an approval of the group.
+    pub approved: bool,
+    /// The version of the terms accepted with the account's approval, if any were acknowledged.
+    pub accepted_version: Option<Uint64>,
+    /// The version of the group's currently published terms, if any have been published.
+    pub current_version: Option<Uint64>,
+    /// Whether the account's approval was recorded against terms older than the current version,
+    /// or before any terms were published.  Always false if the account has not approved the group.
+    pub outdated: bool,
+}
+
+/// Publishes a new version of the terms for a group, replacing any previously published version.
+/// Returns the previous terms, if any were published.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `group_id` The unique identifier of the group.
+/// * `uri` The location at which the full text of the terms can be found.
+/// * `content_hash` The lowercase hex-encoded hash of the terms' content.
+/// * `block_height` The current block height.
+pub fn publish_group_terms<S1: Into<String>, S2: Into<String>>(
+    storage: &mut dyn Storage,
+    group_id: Uint64,
+    uri: S1,
+    content_hash: S2,
+    block_height: u64,
+) -> Result<(Option<GroupTerms>, GroupTerms), ContractError> {
+    let previous = get_group_terms(storage, group_id)?;
+    let terms = GroupTerms {
+        group_id,
+        version: previous
+            .as_ref()
+            .map_or(Uint64::one(), |terms| terms.version + Uint64::one()),
+        uri: uri.into(),
+        content_hash: content_hash.into(),
+        published_at_height: Uint64::new(block_height),
+    };
+    GROUP_TERMS
+        .save(storage, group_id.u64(), &terms)
+        .map_err(ContractError::storage_error)?;
+    (previous, terms).to_ok()
+}
+
+/// Fetches the terms currently published for a group, if any.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group.
+pub fn get_group_terms(
+    storage: &dyn Storage,
+    group_id: Uint64,
+) -> Result<Option<GroupTerms>, ContractError> {
+    GROUP_TERMS
+        .may_load(storage, group_id.u64())
+        .map_err(ContractError::storage_error)
+}
+
+/// Verifies that an approval of a group acknowledges the group's currently published terms,
+/// returning the version being accepted.  A hash must be provided if and only if terms have been
+/// published, and it must match the published content hash, ignoring case.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `group_id` The unique identifier of the group being approved.
+/// * `terms_hash` The content hash of the terms acknowledged by the approving account.
+pub fn check_terms_acknowledged(
+    storage: &dyn Storage,
+    group_id: Uint64,
+    terms_hash: Option<&str>,
+) -> Result<Option<Uint64>, ContractError> {
+    let message = match (get_group_terms(storage, group_id)?, terms_hash) {
+        (None, None) => return None.to_ok(),
+        (Some(terms), Some(hash)) if terms.content_hash.eq_ignore_ascii_case(hash) => {
+            return Some(terms.version).to_ok();
+        }
+        (Some(terms), Some(hash)) => format!(
+            "hash [{hash}] does not match version [{}] of the terms with hash [{}]",
+            terms.version, terms.content_hash,
+        ),
+        (Some(terms), None) => format!(
+            "version [{}] of the terms with hash [{}] must be acknowledged",
+            terms.version, terms.content_hash,
+        ),
+        (None, Some(hash)) => format!("hash [{hash}] was provided but no terms are published"),
+    };
+    ContractError::TermsAcknowledgementError {
+        group_id: group_id.u64(),
+        message,
+    }
+    .to_err()
+}
+
+/// Stores the version of a group's terms accepted with an account's approval of the group, or
+/// clears it if the approval acknowledged no terms.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the approving account.
+/// * `group_id` The unique identifier of the approved group.
+/// * `version` The version of the terms accepted, if any.
+pub fn record_accepted_terms(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+    version: Option<Uint64>,
+) -> Result<(), ContractError> {
+    match version {
+        Some(version) => ACCEPTED_TERMS
+            .save(storage, (account, group_id.u64()), &version)
+            .map_err(ContractError::storage_error),
+        None => {
+            clear_accepted_terms(storage, account, group_id);
+            ().to_ok()
+        }
+    }
+}
+
+/// Removes the record of the terms accepted with an account's approval of a group.  Used when
+/// the approval is revoked.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `account` The bech32 address of the account whose approval was revoked.
+/// * `group_id` The unique identifier of the group.
+pub fn clear_accepted_terms(storage: &mut dyn Storage, account: &Addr, group_id: Uint64) {
+    ACCEPTED_TERMS.remove(storage, (account, group_id.u64()));
+}
+
+/// Fetches the version of a group's terms accepted with an account's approval of the group, if
+/// any were acknowledged.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `account` The bech32 address of the account.
+/// * `group_id` The unique identifier of the group.
+pub fn get_accepted_terms_version(
+    storage: &dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+) -> Result<Option<Uint64>, ContractError> {
+    ACCEPTED_TERMS
+        .may_load(storage, (account, group_id.u64()))
+        .map_err(ContractError::storage_error)
+}
+
+/// Describes the version of a group's terms accepted with an account's approval, flagging the
+/// approval as outdated when newer terms have been published since.  Approvals recorded before
+/// any terms were published are also outdated once terms exist.  Whether the account holds an
+/// approval is determined from its attributes, so it must be provided by the caller.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `account` The bech32 address of the account.
+/// * `group_id` The unique identifier of the group.
+/// * `approved` Whether the account currently holds an approval of the group.
+pub fn get_terms_acceptance(
+    storage: &dyn Storage,
+    account: &Addr,
+    group_id: Uint64,
+    approved: bool,
+) -> Result<TermsAcceptance, ContractError> {
+    let accepted_version = get_accepted_terms_version(storage, account, group_id)?;
+    let current_version = get_group_terms(storage, group_id)?.map(|terms| terms.version);
+    TermsAcceptance {
+        account: account.to_owned(),
+        group_id,
+        approved,
+        accepted_version,
+        current_version,
+        outdated: approved && current_version.is_some() && accepted_version < current_version,
+    }
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::group_terms::{
+        check_terms_acknowledged, get_terms_acceptance, publish_group_terms, record_accepted_terms,
+    };
+    use crate::types::core::error::ContractError;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Addr, Uint64};
+
+    #[test]
+    fn test_check_terms_acknowledged() {
+        let mut storage = MockStorage::new();
+        let group_id = Uint64::new(1);
+        assert_eq!(
+            None,
+            check_terms_acknowledged(&storage, group_id, None)
+                .expect("a group without terms should need no acknowledgement"),
+            "no version should be accepted for a group without terms",
+        );
+        publish_group_terms(&mut storage, group_id, "ipfs://one", "abcd", 1)
+            .expect("the first terms should publish");
+        let (_, terms) = publish_group_terms(&mut storage, group_id, "ipfs://two", "ef01", 2)
+            .expect("the second terms should publish");
+        assert_eq!(
+            Uint64::new(2),
+            terms.version,
+            "each publication should increment the version"
+        );
+        assert_eq!(
+            Some(Uint64::new(2)),
+            check_terms_acknowledged(&storage, group_id, Some("EF01"))
+                .expect("a matching hash should be accepted regardless of case"),
+            "the current version should be accepted",
+        );
+        for hash in [None, Some("abcd")] {
+            assert!(
+                matches!(
+                    check_terms_acknowledged(&storage, group_id, hash),
+                    Err(ContractError::TermsAcknowledgementError { group_id: 1, .. }),
+                ),
+                "a missing or superseded hash should be rejected",
+            );
+        }
+    }
+
+    #[test]
+    fn test_terms_acceptance_is_outdated_after_new_publication() {
+        let mut storage = MockStorage::new();
+        let account = Addr::unchecked("account");
+        let group_id = Uint64::new(1);
+        publish_group_terms(&mut storage, group_id, "ipfs://one", "abcd", 1)
+            .expect("the first terms should publish");
+        record_accepted_terms(&mut storage, &account, group_id, Some(Uint64::new(1)))
+            .expect("the accepted version should be recorded");
+        assert!(
+            !get_terms_acceptance(&storage, &account, group_id, true)
+                .expect("the acceptance should load")
+                .outdated,
+            "an approval of the current terms should not be outdated",
+        );
+        publish_group_terms(&mut storage, group_id, "ipfs://two", "ef01", 2)
+            .expect("the second terms should publish");
+        let acceptance = get_terms_acceptance(&storage, &account, group_id, true)
+            .expect("the acceptance should load");
+        assert_eq!(
+            (Some(Uint64::new(1)), Some(Uint64::new(2)), true),
+            (
+                acceptance.accepted_version,
+                acceptance.current_version,
+                acceptance.outdated,
+            ),
+            "an approval of superseded terms should be outdated",
+        );
+    }
+}
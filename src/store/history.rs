@@ -0,0 +1,303 @@
+use crate::types::core::error::ContractError;
+use crate::util::pagination::query_limit;
+use cosmwasm_std::{Addr, Env, Order, Storage, Timestamp, Uint64};
+use cw_storage_plus::{Bound, Item, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_HISTORY_SEQUENCE: &str = "history_sequence";
+const NAMESPACE_HISTORY: &str = "history";
+const HISTORY_SEQUENCE: Item<u64> = Item::new(NAMESPACE_HISTORY_SEQUENCE);
+const HISTORY: Map<u64, HistoryEntry> = Map::new(NAMESPACE_HISTORY);
+
+/// Describes a single auditable event that modified the contract's configuration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryEvent {
+    /// Recorded each time the contract is migrated to a new code instance.
+    Migration {
+        /// The contract version that was stored before the migration.
+        from_version: String,
+        /// The contract version that was stored after the migration.
+        to_version: String,
+    },
+    /// Recorded each time a privileged account changes the contract's configuration.
+    AdminAction {
+        /// The bech32 address of the account that performed the action.
+        actor: Addr,
+        /// The name of the action that was performed.
+        action: String,
+        /// A description of the configuration value before the action, if one existed.
+        old_value: Option<String>,
+        /// A description of the configuration value after the action, if one exists.
+        new_value: Option<String>,
+    },
+    /// Recorded each time a member accepts newly published terms for a group it has already
+    /// approved.
+    TermsAcknowledgement {
+        /// The bech32 address of the member that accepted the terms.
+        account: Addr,
+        /// The unique identifier of the group to which the terms apply.
+        group_id: Uint64,
+        /// The version of the terms the member previously accepted, if any.
+        previous_version: Option<Uint64>,
+        /// The version of the terms the member accepted.
+        terms_version: Uint64,
+    },
+}
+
+/// A single append-only record in the contract's history log.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct HistoryEntry {
+    /// The unique, monotonically-increasing identifier of this entry.
+    pub id: Uint64,
+    /// The block height at which the event occurred.
+    pub block_height: Uint64,
+    /// The block time at which the event occurred.
+    pub time: Timestamp,
+    /// The details of the event that occurred.
+    pub event: HistoryEvent,
+}
+
+/// Appends a new [HistoryEntry] to the history log, assigning it the next available identifier
+/// and stamping it with the current block's height and time.  Existing entries are never
+/// modified.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `event` The event to record.
+pub fn add_history_entry(
+    storage: &mut dyn Storage,
+    env: &Env,
+    event: HistoryEvent,
+) -> Result<HistoryEntry, ContractError> {
+    let id = HISTORY_SEQUENCE
+        .may_load(storage)
+        .map_err(ContractError::storage_error)?
+        .unwrap_or_default()
+        + 1;
+    let entry = HistoryEntry {
+        id: Uint64::new(id),
+        block_height: Uint64::new(env.block.height),
+        time: env.block.time,
+        event,
+    };
+    HISTORY_SEQUENCE
+        .save(storage, &id)
+        .map_err(ContractError::storage_error)?;
+    HISTORY
+        .save(storage, id, &entry)
+        .map_err(ContractError::storage_error)?;
+    entry.to_ok()
+}
+
+/// Appends a [Migration](HistoryEvent::Migration) entry to the history log.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `from_version` The contract version that was stored before the migration.
+/// * `to_version` The contract version that is stored after the migration.
+pub fn record_migration<S1: Into<String>, S2: Into<String>>(
+    storage: &mut dyn Storage,
+    env: &Env,
+    from_version: S1,
+    to_version: S2,
+) -> Result<HistoryEntry, ContractError> {
+    add_history_entry(
+        storage,
+        env,
+        HistoryEvent::Migration {
+            from_version: from_version.into(),
+            to_version: to_version.into(),
+        },
+    )
+}
+
+/// Appends an [AdminAction](HistoryEvent::AdminAction) entry to the history log.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `actor` The bech32 address of the account that performed the action.
+/// * `action` The name of the action that was performed.
+/// * `old_value` A description of the configuration value before the action, if one existed.
+/// * `new_value` A description of the configuration value after the action, if one exists.
+pub fn record_admin_action<S: Into<String>>(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: &Addr,
+    action: S,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> Result<HistoryEntry, ContractError> {
+    add_history_entry(
+        storage,
+        env,
+        HistoryEvent::AdminAction {
+            actor: actor.to_owned(),
+            action: action.into(),
+            old_value,
+            new_value,
+        },
+    )
+}
+
+/// Appends a [TermsAcknowledgement](HistoryEvent::TermsAcknowledgement) entry to the history log.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
+/// * `account` The bech32 address of the member that accepted the terms.
+/// * `group_id` The unique identifier of the group to which the terms apply.
+/// * `previous_version` The version of the terms the member previously accepted, if any.
+/// * `terms_version` The version of the terms the member accepted.
+pub fn record_terms_acknowledgement(
+    storage: &mut dyn Storage,
+    env: &Env,
+    account: &Addr,
+    group_id: Uint64,
+    previous_version: Option<Uint64>,
+    terms_version: Uint64,
+) -> Result<HistoryEntry, ContractError> {
+    add_history_entry(
+        storage,
+        env,
+        HistoryEvent::TermsAcknowledgement {
+            account: account.to_owned(),
+            group_id,
+            previous_version,
+            terms_version,
+        },
+    )
+}
+
+/// Fetches a page of history entries in ascending order of their identifiers.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `start_after` If provided, only entries with an identifier greater than this value will be
+///   returned.
+/// * `limit` The maximum number of entries to return.  Bounded by the query limits defined in
+///   [pagination](crate::util::pagination).
+pub fn get_history_entries(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<HistoryEntry>, ContractError> {
+    HISTORY
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(query_limit(limit))
+        .map(|result| {
+            result
+                .map(|(_, entry)| entry)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::history::{
+        get_history_entries, record_admin_action, record_migration, HistoryEvent,
+    };
+    use crate::test::test_constants::DEFAULT_CONTRACT_ADMIN;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_history_entries_are_appended_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let migration = record_migration(&mut deps.storage, &env, "1.0.0", "1.0.1")
+            .expect("a migration should be recorded");
+        let admin_action = record_admin_action(
+            &mut deps.storage,
+            &env,
+            &Addr::unchecked(DEFAULT_CONTRACT_ADMIN),
+            "some_action",
+            None,
+            Some("new".to_string()),
+        )
+        .expect("an admin action should be recorded");
+        assert_eq!(
+            Uint64::new(1),
+            migration.id,
+            "the first entry should have id 1"
+        );
+        assert_eq!(
+            Uint64::new(2),
+            admin_action.id,
+            "the second entry should have id 2"
+        );
+        assert_eq!(
+            Uint64::new(env.block.height),
+            migration.block_height,
+            "the entry should record the block height",
+        );
+        assert_eq!(
+            env.block.time, migration.time,
+            "the entry should record the block time",
+        );
+        assert_eq!(
+            HistoryEvent::Migration {
+                from_version: "1.0.0".to_string(),
+                to_version: "1.0.1".to_string(),
+            },
+            migration.event,
+            "the migration event should be recorded",
+        );
+        let entries = get_history_entries(&deps.storage, None, None)
+            .expect("history entries should be fetched");
+        assert_eq!(
+            vec![migration, admin_action.clone()],
+            entries,
+            "all entries should be returned in ascending order",
+        );
+        let entries = get_history_entries(&deps.storage, Some(1), None)
+            .expect("history entries should be fetched after an id");
+        assert_eq!(
+            vec![admin_action],
+            entries,
+            "only entries after the start_after value should be returned",
+        );
+    }
+
+    #[test]
+    fn test_history_entries_respect_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        for _ in 0..5 {
+            record_migration(&mut deps.storage, &env, "1.0.0", "1.0.1")
+                .expect("a migration should be recorded");
+        }
+        let ids = get_history_entries(&deps.storage, Some(1), Some(2))
+            .expect("history entries should be fetched")
+            .into_iter()
+            .map(|entry| entry.id.u64())
+            .collect::<Vec<u64>>();
+        assert_eq!(vec![2, 3], ids, "the limit should be respected");
+    }
+}
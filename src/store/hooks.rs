@@ -0,0 +1,249 @@
+use crate::types::core::error::ContractError;
+use crate::types::core::hook::ApprovalHookMsg;
+use cosmwasm_std::{to_json_binary, Addr, Order, Storage, SubMsg, Uint64};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of hooks that may be registered at once.  Every approval change sends a
+/// message to each hook, so the cap bounds the cost of approvals.
+pub const MAX_HOOKS: usize = 10;
+
+/// The reply id attached to each hook notification.  Notifications are dispatched with
+/// [SubMsg::reply_on_error], so a failing hook is reported to the contract's reply entry point
+/// instead of reverting the approval change that triggered it.
+pub const HOOK_REPLY_ID: u64 = 1;
+
+/// The maximum amount of gas each hook notification may consume.  A hook that exceeds it fails
+/// like any other hook error and is reported via [HOOK_REPLY_ID], so a single hook cannot consume
+/// the gas of the approval change that triggered it.
+pub const HOOK_GAS_LIMIT: u64 = 200_000;
+
+const NAMESPACE_HOOKS: &str = "hooks";
+const HOOKS: Map<&Addr, RegisteredHook> = Map::new(NAMESPACE_HOOKS);
+
+/// A contract registered by the admin to be notified with an [ApprovalHookMsg] whenever an account
+/// approves or withdraws its approval of a group.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RegisteredHook {
+    /// The bech32 address of the contract to notify.
+    pub contract: Addr,
+    /// The block height at which the hook was registered.
+    pub added_at_height: Uint64,
+}
+
+/// Registers a new hook.  An error is returned if the contract is already registered or if
+/// [MAX_HOOKS] hooks are already registered.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `hook` The hook to register.
+pub fn add_hook(storage: &mut dyn Storage, hook: &RegisteredHook) -> Result<(), ContractError> {
+    if HOOKS.has(storage, &hook.contract) {
+        return ContractError::ExecuteError {
+            route: "add_hook".to_string(),
+            message: format!(
+                "contract [{}] is already registered as a hook",
+                hook.contract
+            ),
+        }
+        .to_err();
+    }
+    if HOOKS.keys(storage, None, None, Order::Ascending).count() >= MAX_HOOKS {
+        return ContractError::ExecuteError {
+            route: "add_hook".to_string(),
+            message: format!("no more than [{MAX_HOOKS}] hooks may be registered"),
+        }
+        .to_err();
+    }
+    HOOKS
+        .save(storage, &hook.contract, hook)
+        .map_err(ContractError::storage_error)
+}
+
+/// Removes a registered hook.  Returns the removed hook, or `None` if the contract was not
+/// registered.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `contract` The bech32 address of the hook contract to remove.
+pub fn remove_hook(
+    storage: &mut dyn Storage,
+    contract: &Addr,
+) -> Result<Option<RegisteredHook>, ContractError> {
+    let existing = HOOKS
+        .may_load(storage, contract)
+        .map_err(ContractError::storage_error)?;
+    HOOKS.remove(storage, contract);
+    existing.to_ok()
+}
+
+/// Fetches every registered hook in ascending order of contract address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+pub fn get_hooks(storage: &dyn Storage) -> Result<Vec<RegisteredHook>, ContractError> {
+    HOOKS
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| {
+            result
+                .map(|(_, hook)| hook)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+/// Builds a submessage delivering the notification to each registered hook.  Each submessage is
+/// limited to [HOOK_GAS_LIMIT] gas, replies with [HOOK_REPLY_ID] only on failure, and carries the
+/// hook's address as its payload so that the failure can be attributed to it.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `hook_msg` The notification to deliver.
+pub fn prepare_hook_messages(
+    storage: &dyn Storage,
+    hook_msg: &ApprovalHookMsg,
+) -> Result<Vec<SubMsg>, ContractError> {
+    get_hooks(storage)?
+        .into_iter()
+        .map(|hook| {
+            let payload = to_json_binary(&hook.contract)?;
+            SubMsg::reply_on_error(
+                hook_msg.to_owned().into_cosmos_msg(hook.contract)?,
+                HOOK_REPLY_ID,
+            )
+            .with_payload(payload)
+            .with_gas_limit(HOOK_GAS_LIMIT)
+            .to_ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::ApprovalAction;
+    use crate::store::hooks::{
+        add_hook, get_hooks, prepare_hook_messages, remove_hook, RegisteredHook, HOOK_GAS_LIMIT,
+        HOOK_REPLY_ID, MAX_HOOKS,
+    };
+    use crate::types::core::error::ContractError;
+    use crate::types::core::hook::ApprovalHookMsg;
+    use cosmwasm_std::{from_json, Addr, CosmosMsg, ReplyOn, Uint64, WasmMsg};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn hook(contract: &str) -> RegisteredHook {
+        RegisteredHook {
+            contract: Addr::unchecked(contract),
+            added_at_height: Uint64::new(1),
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_hooks() {
+        let mut deps = mock_provenance_dependencies();
+        add_hook(&mut deps.storage, &hook("hook_b")).expect("the first hook should be added");
+        add_hook(&mut deps.storage, &hook("hook_a")).expect("the second hook should be added");
+        assert!(
+            matches!(
+                add_hook(&mut deps.storage, &hook("hook_a")),
+                Err(ContractError::ExecuteError { .. })
+            ),
+            "a duplicate hook should be rejected",
+        );
+        assert_eq!(
+            vec![hook("hook_a"), hook("hook_b")],
+            get_hooks(&deps.storage).expect("hooks should load"),
+            "hooks should be listed in ascending order of address",
+        );
+        assert_eq!(
+            Some(hook("hook_a")),
+            remove_hook(&mut deps.storage, &Addr::unchecked("hook_a"))
+                .expect("the hook should be removed"),
+            "the removed hook should be returned",
+        );
+        assert_eq!(
+            None,
+            remove_hook(&mut deps.storage, &Addr::unchecked("hook_a"))
+                .expect("removing a missing hook should not fail"),
+            "nothing should be returned for a missing hook",
+        );
+    }
+
+    #[test]
+    fn test_hook_cap_is_enforced() {
+        let mut deps = mock_provenance_dependencies();
+        for index in 0..MAX_HOOKS {
+            add_hook(&mut deps.storage, &hook(&format!("hook_{index}")))
+                .expect("hooks up to the cap should be added");
+        }
+        assert!(
+            matches!(
+                add_hook(&mut deps.storage, &hook("one_too_many")),
+                Err(ContractError::ExecuteError { .. })
+            ),
+            "a hook beyond the cap should be rejected",
+        );
+    }
+
+    #[test]
+    fn test_prepare_hook_messages_targets_each_hook() {
+        let mut deps = mock_provenance_dependencies();
+        add_hook(&mut deps.storage, &hook("hook_a")).expect("the first hook should be added");
+        add_hook(&mut deps.storage, &hook("hook_b")).expect("the second hook should be added");
+        let messages = prepare_hook_messages(
+            &deps.storage,
+            &ApprovalHookMsg {
+                account: Addr::unchecked("member"),
+                group_id: Uint64::new(1),
+                policy_address: None,
+                action: ApprovalAction::Approve,
+                memo: None,
+                block_height: Uint64::new(1),
+            },
+        )
+        .expect("hook messages should be prepared");
+        assert_eq!(
+            vec!["hook_a", "hook_b"],
+            messages
+                .iter()
+                .map(|sub_msg| match &sub_msg.msg {
+                    CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) =>
+                        contract_addr.as_str(),
+                    msg => panic!("unexpected message: {msg:?}"),
+                })
+                .collect::<Vec<_>>(),
+            "a message should be sent to each hook",
+        );
+        for sub_msg in &messages {
+            assert_eq!(
+                (HOOK_REPLY_ID, ReplyOn::Error),
+                (sub_msg.id, sub_msg.reply_on.to_owned()),
+                "hook failures should be replied to rather than reverting the transaction",
+            );
+            assert_eq!(
+                Some(HOOK_GAS_LIMIT),
+                sub_msg.gas_limit,
+                "each hook should be limited to a bounded amount of gas",
+            );
+            let CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) = &sub_msg.msg else {
+                panic!("unexpected message: {:?}", sub_msg.msg);
+            };
+            assert_eq!(
+                contract_addr.as_str(),
+                from_json::<Addr>(&sub_msg.payload)
+                    .expect("the payload should deserialize")
+                    .as_str(),
+                "the payload should identify the hook",
+            );
+        }
+    }
+}
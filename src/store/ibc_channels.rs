@@ -0,0 +1,428 @@
+use crate::types::core::error::ContractError;
+use crate::types::core::hook::ApprovalHookMsg;
+use crate::types::core::ibc::{ApprovalPacket, ApprovalPacketV1, IBC_PACKET_TIMEOUT_SECONDS};
+use cosmwasm_std::{to_json_binary, CosmosMsg, Env, IbcEndpoint, IbcMsg, Order, Storage, Uint64};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of IBC channels that may be connected at once.  Every approval change sends
+/// a packet over each channel, so the cap bounds the cost of approvals.
+pub const MAX_IBC_CHANNELS: usize = 10;
+
+/// The maximum number of IBC connections that may be allowlisted at once.
+pub const MAX_ALLOWED_IBC_CONNECTIONS: usize = 10;
+
+const NAMESPACE_IBC_CHANNELS: &str = "ibc_channels";
+const IBC_CHANNELS: Map<&str, ConnectedChannel> = Map::new(NAMESPACE_IBC_CHANNELS);
+const NAMESPACE_ALLOWED_IBC_CONNECTIONS: &str = "allowed_ibc_connections";
+const ALLOWED_IBC_CONNECTIONS: Map<&str, AllowedConnection> =
+    Map::new(NAMESPACE_ALLOWED_IBC_CONNECTIONS);
+
+/// An IBC channel over which approval changes are relayed to a counterparty.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ConnectedChannel {
+    /// The identifier of the channel on this chain.
+    pub channel_id: String,
+    /// The port and channel of the counterparty.
+    pub counterparty_endpoint: IbcEndpoint,
+    /// The identifier of the underlying IBC connection.
+    pub connection_id: String,
+    /// The block height at which the channel handshake completed.
+    pub connected_at_height: Uint64,
+}
+
+/// An IBC connection over which the admin permits counterparties to open channels.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AllowedConnection {
+    /// The identifier of the IBC connection on this chain.
+    pub connection_id: String,
+    /// The block height at which the connection was allowlisted.
+    pub allowed_at_height: Uint64,
+}
+
+/// Stores a channel after its handshake completes.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `channel` The connected channel.
+pub fn save_channel(
+    storage: &mut dyn Storage,
+    channel: &ConnectedChannel,
+) -> Result<(), ContractError> {
+    IBC_CHANNELS
+        .save(storage, &channel.channel_id, channel)
+        .map_err(ContractError::storage_error)
+}
+
+/// Removes a closed channel.  Returns the removed channel, or `None` if it was never connected.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `channel_id` The identifier of the channel on this chain.
+pub fn remove_channel(
+    storage: &mut dyn Storage,
+    channel_id: &str,
+) -> Result<Option<ConnectedChannel>, ContractError> {
+    let existing = IBC_CHANNELS
+        .may_load(storage, channel_id)
+        .map_err(ContractError::storage_error)?;
+    IBC_CHANNELS.remove(storage, channel_id);
+    existing.to_ok()
+}
+
+/// Fetches every connected channel in ascending order of channel id.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+pub fn get_channels(storage: &dyn Storage) -> Result<Vec<ConnectedChannel>, ContractError> {
+    IBC_CHANNELS
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| {
+            result
+                .map(|(_, channel)| channel)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+/// Determines whether the number of connected channels has reached [MAX_IBC_CHANNELS].
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+pub fn channel_limit_reached(storage: &dyn Storage) -> bool {
+    IBC_CHANNELS
+        .keys(storage, None, None, Order::Ascending)
+        .count()
+        >= MAX_IBC_CHANNELS
+}
+
+/// Adds a connection to the allowlist.  An error is returned if the connection is already
+/// allowlisted or if [MAX_ALLOWED_IBC_CONNECTIONS] connections are already allowlisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `connection` The connection to allowlist.
+pub fn allow_connection(
+    storage: &mut dyn Storage,
+    connection: &AllowedConnection,
+) -> Result<(), ContractError> {
+    if ALLOWED_IBC_CONNECTIONS.has(storage, &connection.connection_id) {
+        return ContractError::ExecuteError {
+            route: "allow_ibc_connection".to_string(),
+            message: format!(
+                "connection [{}] is already allowlisted",
+                connection.connection_id
+            ),
+        }
+        .to_err();
+    }
+    if ALLOWED_IBC_CONNECTIONS
+        .keys(storage, None, None, Order::Ascending)
+        .count()
+        >= MAX_ALLOWED_IBC_CONNECTIONS
+    {
+        return ContractError::ExecuteError {
+            route: "allow_ibc_connection".to_string(),
+            message: format!(
+                "no more than [{MAX_ALLOWED_IBC_CONNECTIONS}] connections may be allowlisted"
+            ),
+        }
+        .to_err();
+    }
+    ALLOWED_IBC_CONNECTIONS
+        .save(storage, &connection.connection_id, connection)
+        .map_err(ContractError::storage_error)
+}
+
+/// Removes a connection from the allowlist.  Returns the removed entry, or `None` if the
+/// connection was not allowlisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+///   manipulation.
+/// * `connection_id` The identifier of the IBC connection on this chain.
+pub fn disallow_connection(
+    storage: &mut dyn Storage,
+    connection_id: &str,
+) -> Result<Option<AllowedConnection>, ContractError> {
+    let existing = ALLOWED_IBC_CONNECTIONS
+        .may_load(storage, connection_id)
+        .map_err(ContractError::storage_error)?;
+    ALLOWED_IBC_CONNECTIONS.remove(storage, connection_id);
+    existing.to_ok()
+}
+
+/// Fetches every allowlisted connection in ascending order of connection id.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+pub fn get_allowed_connections(
+    storage: &dyn Storage,
+) -> Result<Vec<AllowedConnection>, ContractError> {
+    ALLOWED_IBC_CONNECTIONS
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| {
+            result
+                .map(|(_, connection)| connection)
+                .map_err(ContractError::storage_error)
+        })
+        .collect()
+}
+
+/// Determines whether channels may be opened over the given connection.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `connection_id` The identifier of the IBC connection on this chain.
+pub fn is_connection_allowed(storage: &dyn Storage, connection_id: &str) -> bool {
+    ALLOWED_IBC_CONNECTIONS.has(storage, connection_id)
+}
+
+/// Builds a packet relaying the approval change over each connected channel.  The packets time out
+/// [IBC_PACKET_TIMEOUT_SECONDS] after the current block time.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `env` An environment object provided by the cosmwasm framework, used to derive the timeout.
+/// * `notification` The approval change to relay.
+pub fn prepare_relay_messages(
+    storage: &dyn Storage,
+    env: &Env,
+    notification: &ApprovalHookMsg,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let channels = get_channels(storage)?;
+    if channels.is_empty() {
+        return vec![].to_ok();
+    }
+    let data = to_json_binary(&ApprovalPacket::V1(ApprovalPacketV1::ApprovalChanged(
+        notification.to_owned(),
+    )))?;
+    channels
+        .into_iter()
+        .map(|channel| {
+            IbcMsg::SendPacket {
+                channel_id: channel.channel_id,
+                data: data.to_owned(),
+                timeout: env
+                    .block
+                    .time
+                    .plus_seconds(IBC_PACKET_TIMEOUT_SECONDS)
+                    .into(),
+            }
+            .into()
+        })
+        .collect::<Vec<_>>()
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::ApprovalAction;
+    use crate::store::ibc_channels::{
+        allow_connection, channel_limit_reached, disallow_connection, get_allowed_connections,
+        get_channels, is_connection_allowed, prepare_relay_messages, remove_channel, save_channel,
+        AllowedConnection, ConnectedChannel, MAX_ALLOWED_IBC_CONNECTIONS, MAX_IBC_CHANNELS,
+    };
+    use crate::types::core::error::ContractError;
+    use crate::types::core::hook::ApprovalHookMsg;
+    use crate::types::core::ibc::{ApprovalPacket, ApprovalPacketV1, IBC_PACKET_TIMEOUT_SECONDS};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Addr, CosmosMsg, IbcEndpoint, IbcMsg, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn channel(channel_id: &str) -> ConnectedChannel {
+        ConnectedChannel {
+            channel_id: channel_id.to_string(),
+            counterparty_endpoint: IbcEndpoint {
+                port_id: "their_port".to_string(),
+                channel_id: "channel-7".to_string(),
+            },
+            connection_id: "connection-2".to_string(),
+            connected_at_height: Uint64::new(1),
+        }
+    }
+
+    #[test]
+    fn test_save_and_remove_channels() {
+        let mut deps = mock_provenance_dependencies();
+        save_channel(&mut deps.storage, &channel("channel-1")).expect("the channel should save");
+        save_channel(&mut deps.storage, &channel("channel-0")).expect("the channel should save");
+        assert_eq!(
+            vec![channel("channel-0"), channel("channel-1")],
+            get_channels(&deps.storage).expect("channels should load"),
+            "channels should be listed in ascending order of id",
+        );
+        assert_eq!(
+            Some(channel("channel-0")),
+            remove_channel(&mut deps.storage, "channel-0").expect("the channel should be removed"),
+            "the removed channel should be returned",
+        );
+        assert_eq!(
+            None,
+            remove_channel(&mut deps.storage, "channel-0")
+                .expect("removing a missing channel should not fail"),
+            "nothing should be returned for a missing channel",
+        );
+    }
+
+    #[test]
+    fn test_channel_limit() {
+        let mut deps = mock_provenance_dependencies();
+        for index in 0..MAX_IBC_CHANNELS {
+            assert!(
+                !channel_limit_reached(&deps.storage),
+                "the limit should not be reached below the cap",
+            );
+            save_channel(&mut deps.storage, &channel(&format!("channel-{index}")))
+                .expect("the channel should save");
+        }
+        assert!(
+            channel_limit_reached(&deps.storage),
+            "the limit should be reached at the cap",
+        );
+    }
+
+    fn connection(connection_id: &str) -> AllowedConnection {
+        AllowedConnection {
+            connection_id: connection_id.to_string(),
+            allowed_at_height: Uint64::new(1),
+        }
+    }
+
+    #[test]
+    fn test_allow_and_disallow_connections() {
+        let mut deps = mock_provenance_dependencies();
+        assert!(
+            !is_connection_allowed(&deps.storage, "connection-0"),
+            "no connection should be allowed by default",
+        );
+        allow_connection(&mut deps.storage, &connection("connection-1"))
+            .expect("the first connection should be allowlisted");
+        allow_connection(&mut deps.storage, &connection("connection-0"))
+            .expect("the second connection should be allowlisted");
+        assert!(
+            matches!(
+                allow_connection(&mut deps.storage, &connection("connection-0")),
+                Err(ContractError::ExecuteError { .. })
+            ),
+            "a duplicate connection should be rejected",
+        );
+        assert_eq!(
+            vec![connection("connection-0"), connection("connection-1")],
+            get_allowed_connections(&deps.storage).expect("connections should load"),
+            "connections should be listed in ascending order of id",
+        );
+        assert!(
+            is_connection_allowed(&deps.storage, "connection-0"),
+            "an allowlisted connection should be allowed",
+        );
+        assert_eq!(
+            Some(connection("connection-0")),
+            disallow_connection(&mut deps.storage, "connection-0")
+                .expect("the connection should be removed"),
+            "the removed connection should be returned",
+        );
+        assert!(
+            !is_connection_allowed(&deps.storage, "connection-0"),
+            "a removed connection should no longer be allowed",
+        );
+    }
+
+    #[test]
+    fn test_allowed_connection_cap_is_enforced() {
+        let mut deps = mock_provenance_dependencies();
+        for index in 0..MAX_ALLOWED_IBC_CONNECTIONS {
+            allow_connection(
+                &mut deps.storage,
+                &connection(&format!("connection-{index}")),
+            )
+            .expect("connections up to the cap should be allowlisted");
+        }
+        assert!(
+            matches!(
+                allow_connection(&mut deps.storage, &connection("one_too_many")),
+                Err(ContractError::ExecuteError { .. })
+            ),
+            "a connection beyond the cap should be rejected",
+        );
+    }
+
+    #[test]
+    fn test_prepare_relay_messages() {
+        let mut deps = mock_provenance_dependencies();
+        let notification = ApprovalHookMsg {
+            account: Addr::unchecked("member"),
+            group_id: Uint64::new(1),
+            policy_address: None,
+            action: ApprovalAction::Approve,
+            memo: None,
+            block_height: Uint64::new(1),
+        };
+        assert!(
+            prepare_relay_messages(&deps.storage, &mock_env(), &notification)
+                .expect("relay messages should be prepared")
+                .is_empty(),
+            "no packets should be sent without connected channels",
+        );
+        save_channel(&mut deps.storage, &channel("channel-0")).expect("the channel should save");
+        save_channel(&mut deps.storage, &channel("channel-1")).expect("the channel should save");
+        let messages = prepare_relay_messages(&deps.storage, &mock_env(), &notification)
+            .expect("relay messages should be prepared");
+        assert_eq!(
+            2,
+            messages.len(),
+            "a packet should be sent over each channel"
+        );
+        for (message, expected_channel) in messages.iter().zip(["channel-0", "channel-1"]) {
+            match message {
+                CosmosMsg::Ibc(IbcMsg::SendPacket {
+                    channel_id,
+                    data,
+                    timeout,
+                }) => {
+                    assert_eq!(
+                        expected_channel, channel_id,
+                        "the channel should be targeted"
+                    );
+                    assert_eq!(
+                        ApprovalPacket::V1(ApprovalPacketV1::ApprovalChanged(
+                            notification.to_owned()
+                        )),
+                        from_json(data).expect("the packet should deserialize"),
+                        "the packet should carry the approval change",
+                    );
+                    assert_eq!(
+                        Some(
+                            mock_env()
+                                .block
+                                .time
+                                .plus_seconds(IBC_PACKET_TIMEOUT_SECONDS)
+                        ),
+                        timeout.timestamp(),
+                        "the packet should time out relative to the block time",
+                    );
+                }
+                msg => panic!("unexpected message: {msg:?}"),
+            }
+        }
+    }
+}
@@ -1,4 +1,25 @@
 //! Contains all type definitions and functionality for interacting with contract internal storage.
 
+/// Contains the functionality for tracking per-account approval activity used in rate limiting.
+pub mod approval_activity;
+/// Contains the functionality for interacting with the append-only approval audit trail.
+pub mod approval_events;
+/// Contains the functionality for interacting with the admin-managed account blocklist.
+pub mod blocklist;
+/// Contains the functionality for interacting with the cw2-compatible contract version info.
+pub mod contract_info;
 /// Contains the functionality for interacting with the singleton contract state value.
 pub mod contract_state;
+/// Contains the functionality for interacting with the admin-configured per-group member caps.
+pub mod group_caps;
+/// Contains the functionality for interacting with the per-group approval counters.
+pub mod group_stats;
+/// Contains the functionality for interacting with the admin-published per-group terms and the
+/// versions accepted by each approval.
+pub mod group_terms;
+/// Contains the functionality for interacting with the append-only contract history log.
+pub mod history;
+/// Contains the functionality for interacting with the admin-managed approval hook registrations.
+pub mod hooks;
+/// Contains the functionality for interacting with the IBC channels over which approvals are relayed.
+pub mod ibc_channels;
@@ -0,0 +1,960 @@
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::test::test_constants::{
+    DEFAULT_CONTRACT_ADMIN, DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_CONTRACT_NAME,
+};
+use crate::types::core::error::ContractError;
+use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockStorage};
+use cosmwasm_std::{
+    from_json, Addr, AnyMsg, Binary, ContractResult, CosmosMsg, Empty, Env, Order, OwnedDeps,
+    QuerierResult, Response, Storage, SystemResult,
+};
+use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+use provwasm_std::types::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use provwasm_std::types::provenance::attribute::v1::{
+    Attribute, MsgAddAttributeRequest, MsgDeleteDistinctAttributeRequest, QueryAttributeRequest,
+    QueryAttributeResponse, QueryAttributesRequest, QueryAttributesResponse,
+};
+use provwasm_std::types::provenance::name::v1::{
+    MsgBindNameRequest, QueryResolveRequest, QueryResolveResponse,
+};
+use provwasm_std::types::tendermint::abci::ResponseQuery;
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+pub const ATTRIBUTES_QUERY_PATH: &str = "/provenance.attribute.v1.Query/Attributes";
+pub const ATTRIBUTE_QUERY_PATH: &str = "/provenance.attribute.v1.Query/Attribute";
+pub const RESOLVE_QUERY_PATH: &str = "/provenance.name.v1.Query/Resolve";
+
+/// The page size used by the simulated attribute module when a request does not specify a limit,
+/// matching the chain's default.
+const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// A name bound in the simulated name module.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NameBinding {
+    pub address: String,
+    pub restricted: bool,
+}
+
+/// The simulated state of the Provenance Blockchain modules that the contract interacts with.
+#[derive(Clone, Debug, Default)]
+pub struct ChainState {
+    pub names: BTreeMap<String, NameBinding>,
+    pub attributes: BTreeMap<String, Vec<Attribute>>,
+    /// The number of stargate queries served for each query path, for asserting query costs.
+    pub query_counts: BTreeMap<String, u64>,
+    /// Messages emitted by the contract that are not handled by a simulated module.
+    pub unhandled_messages: Vec<CosmosMsg>,
+}
+
+#[derive(Debug)]
+pub enum SimulationError {
+    /// The contract rejected the message.
+    Contract(ContractError),
+    /// The contract accepted the message, but the chain rejected one of its emitted messages.
+    Chain(String),
+}
+
+pub type SimulationResult<T> = Result<T, SimulationError>;
+
+/// An in-memory stand-in for the Provenance Blockchain that runs the contract's entry points,
+/// applies the attribute and name messages they emit to a simulated chain state, and serves the
+/// attribute and name stargate queries from that same state.  As on chain, a transaction is atomic:
+/// when either the contract or an emitted message fails, both the contract's storage and the chain
+/// state are rolled back.
+pub struct ProvenanceSimulator {
+    pub deps: OwnedDeps<MockStorage, MockApi, MockProvenanceQuerier, Empty>,
+    pub env: Env,
+    chain: Rc<RefCell<ChainState>>,
+}
+
+impl Default for ProvenanceSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProvenanceSimulator {
+    pub fn new() -> Self {
+        let mut deps = mock_provenance_dependencies();
+        let chain = Rc::new(RefCell::new(ChainState::default()));
+        let attributes_chain = chain.clone();
+        deps.querier.registered_custom_queries.insert(
+            ATTRIBUTES_QUERY_PATH.to_string(),
+            Box::new(move |data| serve_attributes(&attributes_chain, data)),
+        );
+        let attribute_chain = chain.clone();
+        deps.querier.registered_custom_queries.insert(
+            ATTRIBUTE_QUERY_PATH.to_string(),
+            Box::new(move |data| serve_attribute(&attribute_chain, data)),
+        );
+        let resolve_chain = chain.clone();
+        deps.querier.registered_custom_queries.insert(
+            RESOLVE_QUERY_PATH.to_string(),
+            Box::new(move |data| serve_resolve(&resolve_chain, data)),
+        );
+        Self {
+            deps,
+            env: mock_env(),
+            chain,
+        }
+    }
+
+    /// Creates a simulator with an unrestricted parent name for [DEFAULT_CONTRACT_ATTRIBUTE] and a
+    /// contract instantiated by [DEFAULT_CONTRACT_ADMIN] that binds the attribute name.
+    pub fn with_default_contract() -> Self {
+        let mut simulator = Self::new().with_name("test.pb", "chain", false);
+        simulator
+            .instantiate(
+                DEFAULT_CONTRACT_ADMIN,
+                InstantiateMsg {
+                    contract_name: DEFAULT_CONTRACT_NAME.to_string(),
+                    attribute_name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    bind_attribute_name: true,
+                },
+            )
+            .expect("the default contract should be instantiated in the simulator");
+        simulator
+    }
+
+    /// Binds a name directly in the simulated name module, as if done in an earlier transaction.
+    pub fn with_name<S1: Into<String>, S2: Into<String>>(
+        self,
+        name: S1,
+        address: S2,
+        restricted: bool,
+    ) -> Self {
+        self.chain.borrow_mut().names.insert(
+            name.into(),
+            NameBinding {
+                address: address.into(),
+                restricted,
+            },
+        );
+        self
+    }
+
+    /// Writes an attribute directly to an account, as if done by another name owner.
+    pub fn with_attribute(self, attribute: Attribute) -> Self {
+        self.chain
+            .borrow_mut()
+            .attributes
+            .entry(attribute.address.to_owned())
+            .or_default()
+            .push(attribute);
+        self
+    }
+
+    /// Produces a valid bech32 address for the given label.
+    pub fn addr(&self, label: &str) -> Addr {
+        self.deps.api.addr_make(label)
+    }
+
+    pub fn contract_address(&self) -> String {
+        self.env.contract.address.to_string()
+    }
+
+    pub fn instantiate<S: Into<String>>(
+        &mut self,
+        sender: S,
+        msg: InstantiateMsg,
+    ) -> SimulationResult<Response> {
+        let info = message_info(&Addr::unchecked(sender.into()), &[]);
+        let env = self.env.clone();
+        self.transact(|deps| instantiate(deps, env, info, msg))
+    }
+
+    pub fn execute<S: Into<String>>(
+        &mut self,
+        sender: S,
+        msg: ExecuteMsg,
+    ) -> SimulationResult<Response> {
+        let info = message_info(&Addr::unchecked(sender.into()), &[]);
+        let env = self.env.clone();
+        self.transact(|deps| execute(deps, env, info, msg))
+    }
+
+    pub fn migrate(&mut self, msg: MigrateMsg) -> SimulationResult<Response> {
+        let env = self.env.clone();
+        self.transact(|deps| migrate(deps, env, msg))
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, msg: QueryMsg) -> Result<T, ContractError> {
+        let binary = query(self.deps.as_ref(), self.env.clone(), msg)?;
+        from_json(binary).map_err(ContractError::from)
+    }
+
+    /// Moves the simulated chain forward, advancing the block time by five seconds per block.
+    pub fn advance_blocks(&mut self, blocks: u64) {
+        self.env.block.height += blocks;
+        self.env.block.time = self.env.block.time.plus_seconds(blocks * 5);
+    }
+
+    /// All attributes currently held by the given account in the simulated attribute module.
+    pub fn attributes<S: Into<String>>(&self, account: S) -> Vec<Attribute> {
+        self.chain
+            .borrow()
+            .attributes
+            .get(&account.into())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn name_binding(&self, name: &str) -> Option<NameBinding> {
+        self.chain.borrow().names.get(name).cloned()
+    }
+
+    pub fn query_count(&self, path: &str) -> u64 {
+        self.chain
+            .borrow()
+            .query_counts
+            .get(path)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn reset_query_counts(&mut self) {
+        self.chain.borrow_mut().query_counts.clear();
+    }
+
+    pub fn unhandled_messages(&self) -> Vec<CosmosMsg> {
+        self.chain.borrow().unhandled_messages.clone()
+    }
+
+    /// Runs a contract entry point and applies its emitted messages, rolling back the contract's
+    /// storage and the chain state if any step fails.
+    fn transact<F>(&mut self, entry_point: F) -> SimulationResult<Response>
+    where
+        F: FnOnce(cosmwasm_std::DepsMut) -> Result<Response, ContractError>,
+    {
+        let storage_snapshot = snapshot_storage(&self.deps.storage);
+        let chain_snapshot = self.chain.borrow().clone();
+        let result = entry_point(self.deps.as_mut())
+            .map_err(SimulationError::Contract)
+            .and_then(|response| {
+                let signer = self.contract_address();
+                let mut chain = self.chain.borrow_mut();
+                for sub_msg in &response.messages {
+                    apply_message(&mut chain, &signer, &sub_msg.msg)
+                        .map_err(SimulationError::Chain)?;
+                }
+                Ok(response)
+            });
+        if result.is_err() {
+            restore_storage(&mut self.deps.storage, storage_snapshot);
+            let query_counts = self.chain.borrow().query_counts.clone();
+            *self.chain.borrow_mut() = ChainState {
+                query_counts,
+                ..chain_snapshot
+            };
+        }
+        result
+    }
+}
+
+fn snapshot_storage(storage: &MockStorage) -> Vec<(Vec<u8>, Vec<u8>)> {
+    storage.range(None, None, Order::Ascending).collect()
+}
+
+fn restore_storage(storage: &mut MockStorage, snapshot: Vec<(Vec<u8>, Vec<u8>)>) {
+    let keys = storage
+        .range(None, None, Order::Ascending)
+        .map(|(key, _)| key)
+        .collect::<Vec<_>>();
+    for key in keys {
+        storage.remove(&key);
+    }
+    for (key, value) in snapshot {
+        storage.set(&key, &value);
+    }
+}
+
+fn apply_message(chain: &mut ChainState, signer: &str, msg: &CosmosMsg) -> Result<(), String> {
+    let AnyMsg { type_url, value } = match msg {
+        CosmosMsg::Any(any) => any,
+        other => {
+            chain.unhandled_messages.push(other.to_owned());
+            return Ok(());
+        }
+    };
+    let decode_error = |e: cosmwasm_std::StdError| format!("failed to decode {type_url}: {e}");
+    match type_url.as_str() {
+        MsgAddAttributeRequest::TYPE_URL => {
+            let msg = MsgAddAttributeRequest::try_from(value.to_owned()).map_err(decode_error)?;
+            check_signer(signer, &msg.owner)?;
+            check_name_owner(chain, &msg.name, &msg.owner)?;
+            let attributes = chain.attributes.entry(msg.account.to_owned()).or_default();
+            if attributes.iter().any(|attr| {
+                attr.name == msg.name
+                    && attr.value == msg.value
+                    && attr.attribute_type == msg.attribute_type
+            }) {
+                return Err(format!(
+                    "attribute [{}] with the given value already exists on account [{}]",
+                    msg.name, msg.account,
+                ));
+            }
+            attributes.push(Attribute {
+                name: msg.name,
+                value: msg.value,
+                attribute_type: msg.attribute_type,
+                address: msg.account,
+                expiration_date: msg.expiration_date,
+            });
+        }
+        MsgDeleteDistinctAttributeRequest::TYPE_URL => {
+            let msg = MsgDeleteDistinctAttributeRequest::try_from(value.to_owned())
+                .map_err(decode_error)?;
+            check_signer(signer, &msg.owner)?;
+            check_name_owner(chain, &msg.name, &msg.owner)?;
+            let attributes = chain.attributes.entry(msg.account.to_owned()).or_default();
+            let starting_len = attributes.len();
+            attributes.retain(|attr| !(attr.name == msg.name && attr.value == msg.value));
+            if attributes.len() == starting_len {
+                return Err(format!(
+                    "no attribute [{}] with the given value exists on account [{}]",
+                    msg.name, msg.account,
+                ));
+            }
+        }
+        MsgBindNameRequest::TYPE_URL => {
+            let msg = MsgBindNameRequest::try_from(value.to_owned()).map_err(decode_error)?;
+            let record = msg
+                .record
+                .ok_or_else(|| "name bind requires a record".to_string())?;
+            let name = match &msg.parent {
+                Some(parent) => {
+                    let parent_binding = chain
+                        .names
+                        .get(&parent.name)
+                        .ok_or_else(|| format!("parent name [{}] does not exist", parent.name))?;
+                    if parent_binding.restricted && parent_binding.address != signer {
+                        return Err(format!(
+                            "parent name [{}] is restricted and not owned by [{signer}]",
+                            parent.name,
+                        ));
+                    }
+                    format!("{}.{}", record.name, parent.name)
+                }
+                None => record.name.to_owned(),
+            };
+            if chain.names.contains_key(&name) {
+                return Err(format!("name [{name}] is already bound"));
+            }
+            chain.names.insert(
+                name,
+                NameBinding {
+                    address: record.address,
+                    restricted: record.restricted,
+                },
+            );
+        }
+        _ => chain.unhandled_messages.push(msg.to_owned()),
+    }
+    Ok(())
+}
+
+fn check_signer(signer: &str, owner: &str) -> Result<(), String> {
+    if signer != owner {
+        return Err(format!(
+            "message owner [{owner}] did not sign the transaction, signer was [{signer}]",
+        ));
+    }
+    Ok(())
+}
+
+fn check_name_owner(chain: &ChainState, name: &str, owner: &str) -> Result<(), String> {
+    match chain.names.get(name) {
+        Some(binding) if binding.address == owner => Ok(()),
+        Some(binding) => Err(format!(
+            "name [{name}] is owned by [{}], not [{owner}]",
+            binding.address,
+        )),
+        None => Err(format!("name [{name}] is not bound")),
+    }
+}
+
+fn serve_attributes(chain: &Rc<RefCell<ChainState>>, data: &Binary) -> QuerierResult {
+    let request = match QueryAttributesRequest::try_from(data.to_owned()) {
+        Ok(request) => request,
+        Err(e) => return failed_query(format!("invalid attributes request: {e}")),
+    };
+    let mut chain = chain.borrow_mut();
+    record_query(&mut chain, ATTRIBUTES_QUERY_PATH);
+    let all_attributes = chain
+        .attributes
+        .get(&request.account)
+        .cloned()
+        .unwrap_or_default();
+    let (attributes, pagination) = paginate(all_attributes, request.pagination);
+    successful_query(
+        QueryAttributesResponse {
+            account: request.account,
+            attributes,
+            pagination,
+        }
+        .to_proto_bytes(),
+    )
+}
+
+fn serve_attribute(chain: &Rc<RefCell<ChainState>>, data: &Binary) -> QuerierResult {
+    let request = match QueryAttributeRequest::try_from(data.to_owned()) {
+        Ok(request) => request,
+        Err(e) => return failed_query(format!("invalid attribute request: {e}")),
+    };
+    let mut chain = chain.borrow_mut();
+    record_query(&mut chain, ATTRIBUTE_QUERY_PATH);
+    let named_attributes = chain
+        .attributes
+        .get(&request.account)
+        .map(|attributes| {
+            attributes
+                .iter()
+                .filter(|attr| attr.name == request.name)
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let (attributes, pagination) = paginate(named_attributes, request.pagination);
+    successful_query(
+        QueryAttributeResponse {
+            account: request.account,
+            attributes,
+            pagination,
+        }
+        .to_proto_bytes(),
+    )
+}
+
+fn serve_resolve(chain: &Rc<RefCell<ChainState>>, data: &Binary) -> QuerierResult {
+    let request = match QueryResolveRequest::try_from(data.to_owned()) {
+        Ok(request) => request,
+        Err(e) => return failed_query(format!("invalid resolve request: {e}")),
+    };
+    let mut chain = chain.borrow_mut();
+    record_query(&mut chain, RESOLVE_QUERY_PATH);
+    match chain.names.get(&request.name) {
+        Some(binding) => successful_query(
+            QueryResolveResponse {
+                address: binding.address.to_owned(),
+                restricted: binding.restricted,
+            }
+            .to_proto_bytes(),
+        ),
+        None => failed_query(format!("name [{}] does not exist", request.name)),
+    }
+}
+
+fn record_query(chain: &mut ChainState, path: &str) {
+    *chain.query_counts.entry(path.to_string()).or_default() += 1;
+}
+
+/// Pages through items the way the chain's key-based pagination does, using the big-endian offset
+/// of the next item as the next key.
+fn paginate<T>(items: Vec<T>, page_request: Option<PageRequest>) -> (Vec<T>, Option<PageResponse>) {
+    let page_request = page_request.unwrap_or_default();
+    let start = if page_request.key.is_empty() {
+        page_request.offset as usize
+    } else {
+        let mut key_bytes = [0u8; 8];
+        let key_len = page_request.key.len().min(8);
+        key_bytes[8 - key_len..].copy_from_slice(&page_request.key[..key_len]);
+        u64::from_be_bytes(key_bytes) as usize
+    };
+    let limit = match page_request.limit {
+        0 => DEFAULT_PAGE_LIMIT,
+        limit => limit,
+    } as usize;
+    let total = items.len();
+    let end = start.saturating_add(limit).min(total);
+    let next_key = (end < total).then(|| (end as u64).to_be_bytes().to_vec());
+    let page = items
+        .into_iter()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect();
+    (
+        page,
+        Some(PageResponse {
+            next_key,
+            total: if page_request.count_total {
+                total as u64
+            } else {
+                0
+            },
+        }),
+    )
+}
+
+fn successful_query(value: Vec<u8>) -> QuerierResult {
+    SystemResult::Ok(ContractResult::Ok(Binary::new(
+        ResponseQuery {
+            code: 0,
+            log: "".to_string(),
+            info: "".to_string(),
+            index: 0,
+            key: vec![],
+            value,
+            proof_ops: None,
+            height: 0,
+            codespace: "".to_string(),
+        }
+        .to_proto_bytes(),
+    )))
+}
+
+fn failed_query(message: String) -> QuerierResult {
+    SystemResult::Ok(ContractResult::Err(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::ApprovalAction;
+    use crate::store::contract_state::{ContractState, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::group_stats::GroupStats;
+    use crate::test::chain_simulator::{
+        ProvenanceSimulator, SimulationError, ATTRIBUTE_QUERY_PATH,
+    };
+    use crate::test::test_constants::{
+        DEFAULT_CONTRACT_ADMIN, DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_CONTRACT_NAME,
+        DEFAULT_GROUP_MEMBER,
+    };
+    use crate::test::test_helpers::set_stored_contract_version;
+    use crate::types::core::error::ContractError;
+    use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+    use crate::types::core::response::QueryApprovalEventsResponse;
+    use crate::util::prov_helpers::GROUP_POLICY_VALUE_PREFIX;
+    use cosmwasm_std::{from_json, to_json_vec, Uint64};
+    use cw2::ContractVersion;
+    use provwasm_std::types::cosmos::group::v1::{
+        GroupPolicyInfo, QueryGroupPolicyInfoRequest, QueryGroupPolicyInfoResponse,
+    };
+    use provwasm_std::types::provenance::attribute::v1::{Attribute, AttributeType};
+
+    fn approve(group_id: u64) -> ExecuteMsg {
+        ExecuteMsg::ApproveGroupMembership {
+            group_id: Uint64::new(group_id),
+            memo: None,
+            terms_hash: None,
+        }
+    }
+
+    fn group_stats(simulator: &ProvenanceSimulator, group_id: u64) -> GroupStats {
+        simulator
+            .query(QueryMsg::QueryGroupStats {
+                group_id: Uint64::new(group_id),
+            })
+            .expect("group stats should be queryable")
+    }
+
+    #[test]
+    fn test_instantiate_binds_attribute_name_to_contract() {
+        let simulator = ProvenanceSimulator::with_default_contract();
+        let binding = simulator
+            .name_binding(DEFAULT_CONTRACT_ATTRIBUTE)
+            .expect("the attribute name should be bound on the simulated chain");
+        assert_eq!(
+            simulator.contract_address(),
+            binding.address,
+            "the attribute name should be bound to the contract",
+        );
+        assert!(
+            binding.restricted,
+            "the attribute name should be restricted"
+        );
+        let state: ContractState = simulator
+            .query(QueryMsg::QueryContractState {})
+            .expect("contract state should be queryable");
+        assert_eq!(
+            DEFAULT_CONTRACT_NAME, state.contract_name,
+            "the contract name should be stored",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_is_rolled_back_when_parent_name_is_restricted() {
+        let mut simulator = ProvenanceSimulator::new().with_name("test.pb", "someone", true);
+        let err = simulator
+            .instantiate(
+                DEFAULT_CONTRACT_ADMIN,
+                InstantiateMsg {
+                    contract_name: DEFAULT_CONTRACT_NAME.to_string(),
+                    attribute_name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    bind_attribute_name: true,
+                },
+            )
+            .expect_err("binding under a restricted parent owned by another account should fail");
+        assert!(
+            matches!(err, SimulationError::Chain(_)),
+            "the chain should reject the name bind, but got: {err:?}",
+        );
+        assert!(
+            simulator
+                .query::<ContractState>(QueryMsg::QueryContractState {})
+                .is_err(),
+            "the contract state should be rolled back when the name bind fails",
+        );
+        assert!(
+            simulator.name_binding(DEFAULT_CONTRACT_ATTRIBUTE).is_none(),
+            "the attribute name should not be bound",
+        );
+    }
+
+    #[test]
+    fn test_approve_then_approve_again_is_rejected_from_chain_state() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(1))
+            .expect("the first approval should succeed");
+        let attributes = simulator.attributes(DEFAULT_GROUP_MEMBER);
+        assert_eq!(
+            1,
+            attributes.len(),
+            "the approval attribute should be written to the member",
+        );
+        assert_eq!(
+            1u64,
+            from_json::<u64>(&attributes[0].value).unwrap(),
+            "the attribute should hold the group id",
+        );
+        simulator.advance_blocks(1);
+        let err = simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(1))
+            .expect_err("a second approval for the same group should fail");
+        assert!(
+            matches!(
+                err,
+                SimulationError::Contract(ContractError::ExecuteError { .. })
+            ),
+            "the contract should detect the duplicate from the chain state, but got: {err:?}",
+        );
+        assert_eq!(
+            1,
+            simulator.attributes(DEFAULT_GROUP_MEMBER).len(),
+            "no duplicate attribute should be written",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            group_stats(&simulator, 1).total_approvals,
+            "the rejected approval should not be counted",
+        );
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(2))
+            .expect("approving a different group should succeed");
+        assert_eq!(
+            2,
+            simulator.attributes(DEFAULT_GROUP_MEMBER).len(),
+            "each approved group should have its own attribute",
+        );
+    }
+
+    #[test]
+    fn test_approve_revoke_and_reapprove() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(7))
+            .expect("the approval should succeed");
+        simulator.advance_blocks(1);
+        simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::RevokeGroupMembership {
+                    group_id: Uint64::new(7),
+                },
+            )
+            .expect("the revocation should succeed");
+        assert!(
+            simulator.attributes(DEFAULT_GROUP_MEMBER).is_empty(),
+            "the revocation should delete the attribute from the chain",
+        );
+        let err = simulator
+            .execute(
+                DEFAULT_GROUP_MEMBER,
+                ExecuteMsg::RevokeGroupMembership {
+                    group_id: Uint64::new(7),
+                },
+            )
+            .expect_err("revoking a missing approval should fail");
+        assert!(
+            matches!(err, SimulationError::Contract(_)),
+            "the contract should reject revoking a missing approval, but got: {err:?}",
+        );
+        simulator.advance_blocks(1);
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(7))
+            .expect("re-approving after a revocation should succeed");
+        let stats = group_stats(&simulator, 7);
+        assert_eq!(
+            Uint64::new(1),
+            stats.current_approvals,
+            "one approval should be current"
+        );
+        assert_eq!(
+            Uint64::new(2),
+            stats.total_approvals,
+            "both approvals should be counted"
+        );
+        assert_eq!(
+            Uint64::new(1),
+            stats.revocations,
+            "the revocation should be counted"
+        );
+        let events: QueryApprovalEventsResponse = simulator
+            .query(QueryMsg::QueryApprovalEvents {
+                account: Some(DEFAULT_GROUP_MEMBER.to_string()),
+                group_id: None,
+                min_height: None,
+                max_height: None,
+                min_time: None,
+                max_time: None,
+                start_after: None,
+                limit: None,
+            })
+            .expect("approval events should be queryable");
+        assert_eq!(
+            vec![
+                ApprovalAction::Approve,
+                ApprovalAction::Withdraw,
+                ApprovalAction::Approve
+            ],
+            events
+                .events
+                .iter()
+                .map(|event| event.action.to_owned())
+                .collect::<Vec<_>>(),
+            "the audit trail should record each step in order",
+        );
+    }
+
+    #[test]
+    fn test_policy_approval_coexists_with_group_approval() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        let member = simulator.addr(DEFAULT_GROUP_MEMBER);
+        let policy = simulator.addr("policy");
+        QueryGroupPolicyInfoRequest::mock_response(
+            &mut simulator.deps.querier,
+            QueryGroupPolicyInfoResponse {
+                info: Some(GroupPolicyInfo {
+                    address: policy.to_string(),
+                    group_id: 1,
+                    admin: "admin".to_string(),
+                    metadata: "".to_string(),
+                    version: 1,
+                    decision_policy: None,
+                    created_at: None,
+                }),
+            },
+        );
+        simulator
+            .execute(&member, approve(1))
+            .expect("the group approval should succeed");
+        simulator
+            .execute(
+                &member,
+                ExecuteMsg::ApproveGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect("the policy approval should succeed alongside the group approval");
+        let attributes = simulator.attributes(&member);
+        assert_eq!(2, attributes.len(), "both approvals should be written");
+        assert!(
+            attributes
+                .iter()
+                .any(|attr| attr.attribute_type() == AttributeType::String
+                    && attr.value == format!("{GROUP_POLICY_VALUE_PREFIX}{policy}").into_bytes()),
+            "the policy approval should be written as a prefixed string value",
+        );
+        let err = simulator
+            .execute(
+                &member,
+                ExecuteMsg::ApproveGroupPolicy {
+                    policy_address: policy.to_string(),
+                },
+            )
+            .expect_err("a duplicate policy approval should fail");
+        assert!(
+            matches!(err, SimulationError::Contract(_)),
+            "the contract should detect the duplicate policy approval, but got: {err:?}",
+        );
+    }
+
+    #[test]
+    fn test_failed_attribute_write_rolls_back_contract_storage() {
+        let mut simulator = ProvenanceSimulator::new();
+        simulator
+            .instantiate(
+                DEFAULT_CONTRACT_ADMIN,
+                InstantiateMsg {
+                    contract_name: DEFAULT_CONTRACT_NAME.to_string(),
+                    attribute_name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    bind_attribute_name: false,
+                },
+            )
+            .expect("instantiation without a name bind should succeed");
+        let err = simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(1))
+            .expect_err("an approval should fail when the contract does not own the name");
+        assert!(
+            matches!(err, SimulationError::Chain(_)),
+            "the chain should reject the attribute write, but got: {err:?}",
+        );
+        assert_eq!(
+            Uint64::new(0),
+            group_stats(&simulator, 1).total_approvals,
+            "the approval should be rolled back with the failed attribute write",
+        );
+        assert!(
+            simulator.attributes(DEFAULT_GROUP_MEMBER).is_empty(),
+            "no attribute should be written",
+        );
+    }
+
+    #[test]
+    fn test_attribute_queries_are_served_from_chain_state() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        simulator.reset_query_counts();
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(1))
+            .expect("the approval should succeed");
+        assert!(
+            simulator.query_count(ATTRIBUTE_QUERY_PATH) > 0,
+            "the approval should query attributes from the simulated chain",
+        );
+    }
+
+    #[test]
+    fn test_migration_preserves_approvals() {
+        let mut simulator = ProvenanceSimulator::with_default_contract();
+        simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(3))
+            .expect("the approval should succeed");
+        set_stored_contract_version(&mut simulator.deps.storage, CONTRACT_TYPE, "0.0.1");
+        simulator
+            .migrate(MigrateMsg::ContractUpgrade { options: None })
+            .expect("migrating from an older version should succeed");
+        let info: ContractVersion = simulator
+            .query(QueryMsg::QueryContractInfo {})
+            .expect("contract info should be queryable");
+        assert_eq!(
+            CONTRACT_VERSION, info.version,
+            "the migration should store the current version",
+        );
+        simulator.advance_blocks(1);
+        let err = simulator
+            .execute(DEFAULT_GROUP_MEMBER, approve(3))
+            .expect_err("approvals made before the migration should still be detected");
+        assert!(
+            matches!(err, SimulationError::Contract(_)),
+            "the contract should reject the duplicate approval, but got: {err:?}",
+        );
+        assert_eq!(
+            Uint64::new(1),
+            group_stats(&simulator, 3).current_approvals,
+            "group stats should survive the migration",
+        );
+    }
+
+    #[test]
+    fn test_group_cap_only_counts_approvals_made_after_tracking_began() {
+        let simulator = ProvenanceSimulator::with_default_contract();
+        let untracked = simulator.addr("untracked");
+        let mut simulator = simulator.with_attribute(Attribute {
+            name: DEFAULT_CONTRACT_ATTRIBUTE.into(),
+            value: to_json_vec(&1u64).unwrap(),
+            attribute_type: AttributeType::Int.into(),
+            address: untracked.to_string(),
+            expiration_date: None,
+        });
+        simulator
+            .execute(
+                DEFAULT_CONTRACT_ADMIN,
+                ExecuteMsg::SetGroupMemberCap {
+                    group_id: Uint64::new(1),
+                    max_members: Some(Uint64::new(1)),
+                },
+            )
+            .expect("the admin should cap the group");
+        assert_eq!(
+            Uint64::zero(),
+            group_stats(&simulator, 1).current_approvals,
+            "an approval written before tracking began should not be counted",
+        );
+        let first = simulator.addr("first");
+        simulator
+            .execute(first.as_str(), approve(1))
+            .expect("the untracked approval should not consume the cap");
+        let second = simulator.addr("second");
+        let err = simulator
+            .execute(second.as_str(), approve(1))
+            .expect_err("the tracked approval should fill the cap");
+        assert!(
+            matches!(
+                err,
+                SimulationError::Contract(ContractError::GroupCapacityError { .. })
+            ),
+            "a group capacity error should be emitted, but got: {err:?}",
+        );
+        assert_eq!(
+            2,
+            [untracked, first]
+                .iter()
+                .filter(|account| !simulator.attributes(account.as_str()).is_empty())
+                .count(),
+            "both the untracked and tracked approvals should remain on chain",
+        );
+    }
+
+    #[test]
+    fn test_revoking_an_untracked_approval_does_not_free_group_capacity() {
+        let simulator = ProvenanceSimulator::with_default_contract();
+        let untracked = simulator.addr("untracked");
+        let mut simulator = simulator.with_attribute(Attribute {
+            name: DEFAULT_CONTRACT_ATTRIBUTE.into(),
+            value: to_json_vec(&1u64).unwrap(),
+            attribute_type: AttributeType::Int.into(),
+            address: untracked.to_string(),
+            expiration_date: None,
+        });
+        simulator
+            .execute(
+                DEFAULT_CONTRACT_ADMIN,
+                ExecuteMsg::SetGroupMemberCap {
+                    group_id: Uint64::new(1),
+                    max_members: Some(Uint64::new(1)),
+                },
+            )
+            .expect("the admin should cap the group");
+        let first = simulator.addr("first");
+        simulator
+            .execute(first.as_str(), approve(1))
+            .expect("the first tracked approval should fill the cap");
+        simulator
+            .execute(
+                untracked.as_str(),
+                ExecuteMsg::RevokeGroupMembership {
+                    group_id: Uint64::new(1),
+                },
+            )
+            .expect("the untracked approval should be revocable");
+        assert_eq!(
+            Uint64::one(),
+            group_stats(&simulator, 1).current_approvals,
+            "revoking an untracked approval should not lower the current approvals",
+        );
+        let second = simulator.addr("second");
+        let err = simulator
+            .execute(second.as_str(), approve(1))
+            .expect_err("the group should still be full");
+        assert!(
+            matches!(
+                err,
+                SimulationError::Contract(ContractError::GroupCapacityError { .. })
+            ),
+            "a group capacity error should be emitted, but got: {err:?}",
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod chain_simulator;
 pub mod test_constants;
 pub mod test_helpers;
 pub mod test_instantiate;
@@ -1,4 +1,18 @@
-use cosmwasm_std::Response;
+use crate::store::contract_state::{get_contract_state, set_contract_state};
+use crate::test::test_constants::DEFAULT_GROUP_MEMBER;
+use cosmwasm_std::{
+    from_json, to_json_vec, Binary, ContractResult, QuerierResult, Response, Storage, SystemResult,
+};
+use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::types::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use provwasm_std::types::cosmos::group::v1::{
+    GroupInfo, GroupMember, Member, QueryGroupMembersRequest, QueryGroupMembersResponse,
+    QueryGroupsByMemberRequest, QueryGroupsByMemberResponse,
+};
+use provwasm_std::types::provenance::attribute::v1::{
+    QueryAttributeRequest, QueryAttributeResponse,
+};
+use provwasm_std::types::tendermint::abci::ResponseQuery;
 
 pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str) -> &'a str {
     response
@@ -9,3 +23,134 @@ pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str)
         .value
         .as_str()
 }
+
+/// Overwrites the contract type and version in both the contract state and the cw2 contract info
+/// in order to simulate an instance stored by different code.
+pub fn set_stored_contract_version(
+    storage: &mut dyn Storage,
+    contract_type: &str,
+    contract_version: &str,
+) {
+    let mut contract_state =
+        get_contract_state(storage).expect("contract state should load for a version override");
+    contract_state.contract_type = contract_type.to_string();
+    contract_state.contract_version = contract_version.to_string();
+    set_contract_state(storage, &contract_state)
+        .expect("contract state should save for a version override");
+    cw2::set_contract_version(storage, contract_type, contract_version)
+        .expect("cw2 contract version should save for a version override");
+}
+
+/// Registers an empty response for the by-name attribute query, simulating an account that holds no
+/// attributes under the contract's attribute name.
+pub fn mock_no_attributes(querier: &mut MockProvenanceQuerier) {
+    QueryAttributeRequest::mock_response(
+        querier,
+        QueryAttributeResponse {
+            account: DEFAULT_GROUP_MEMBER.to_string(),
+            attributes: vec![],
+            pagination: None,
+        },
+    );
+}
+
+/// Registers a paginated response for the group module's `GroupsByMember` query.  Each inner vector
+/// holds the group ids returned on one page, and the next key is the json-encoded index of the next
+/// page.
+pub fn mock_groups_by_member(querier: &mut MockProvenanceQuerier, pages: Vec<Vec<u64>>) {
+    querier.registered_custom_queries.insert(
+        "/cosmos.group.v1.Query/GroupsByMember".to_string(),
+        Box::new(move |data: &Binary| {
+            let request = QueryGroupsByMemberRequest::try_from(data.to_owned())
+                .expect("the groups by member request should decode");
+            let index = page_index(request.pagination);
+            let response = QueryGroupsByMemberResponse {
+                groups: pages[index]
+                    .iter()
+                    .map(|group_id| GroupInfo {
+                        id: *group_id,
+                        admin: "admin".to_string(),
+                        metadata: "".to_string(),
+                        version: 1,
+                        total_weight: "1".to_string(),
+                        created_at: None,
+                    })
+                    .collect(),
+                pagination: next_page(index, pages.len()),
+            };
+            proto_query_response(response.to_proto_bytes())
+        }),
+    );
+}
+
+/// Registers a paginated response for the group module's `GroupMembers` query.  Each inner vector
+/// holds the member addresses returned on one page, and the next key is the json-encoded index of
+/// the next page.
+pub fn mock_group_members<S: Into<String>>(
+    querier: &mut MockProvenanceQuerier,
+    pages: Vec<Vec<S>>,
+) {
+    let pages = pages
+        .into_iter()
+        .map(|page| page.into_iter().map(Into::into).collect::<Vec<String>>())
+        .collect::<Vec<_>>();
+    querier.registered_custom_queries.insert(
+        "/cosmos.group.v1.Query/GroupMembers".to_string(),
+        Box::new(move |data: &Binary| {
+            let request = QueryGroupMembersRequest::try_from(data.to_owned())
+                .expect("the group members request should decode");
+            let index = page_index(request.pagination);
+            let response = QueryGroupMembersResponse {
+                members: pages[index]
+                    .iter()
+                    .map(|address| GroupMember {
+                        group_id: request.group_id,
+                        member: Some(Member {
+                            address: address.to_owned(),
+                            weight: "1".to_string(),
+                            metadata: "".to_string(),
+                            added_at: None,
+                        }),
+                    })
+                    .collect(),
+                pagination: Some(PageResponse {
+                    total: pages.iter().map(|page| page.len() as u64).sum(),
+                    ..next_page(index, pages.len()).unwrap_or_default()
+                }),
+            };
+            proto_query_response(response.to_proto_bytes())
+        }),
+    );
+}
+
+fn page_index(pagination: Option<PageRequest>) -> usize {
+    pagination
+        .map(|page| page.key)
+        .filter(|key| !key.is_empty())
+        .map(|key| from_json(key).expect("the page key should decode"))
+        .unwrap_or_default()
+}
+
+fn next_page(index: usize, page_count: usize) -> Option<PageResponse> {
+    (index + 1 < page_count).then(|| PageResponse {
+        next_key: Some(to_json_vec(&(index + 1)).expect("the page key should encode")),
+        total: 0,
+    })
+}
+
+fn proto_query_response(value: Vec<u8>) -> QuerierResult {
+    SystemResult::Ok(ContractResult::Ok(Binary::new(
+        ResponseQuery {
+            code: 0,
+            log: "".to_string(),
+            info: "".to_string(),
+            index: 0,
+            key: vec![],
+            value,
+            proof_ops: None,
+            height: 1,
+            codespace: "".to_string(),
+        }
+        .to_proto_bytes(),
+    )))
+}
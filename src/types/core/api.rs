@@ -0,0 +1,53 @@
+use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cosmwasm_schema::{generate_api, Api};
+
+/// Builds the combined API description of this contract, which includes the schema of each message
+/// type alongside the response schema of each query.  This value is written to the `schema`
+/// directory by the schema example.
+pub fn contract_api() -> Api {
+    generate_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+        migrate: MigrateMsg,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::core::api::contract_api;
+    use std::fs::read_to_string;
+    use std::path::PathBuf;
+
+    fn read_schema_file(path: &str) -> String {
+        let mut full_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        full_path.push("schema");
+        full_path.push(path);
+        read_to_string(&full_path).unwrap_or_else(|e| {
+            panic!(
+                "failed to read schema file [{}]: {e}. Run `make schema` to regenerate it",
+                full_path.display(),
+            )
+        })
+    }
+
+    #[test]
+    fn test_committed_schema_is_current() {
+        let api = contract_api().render();
+        assert_eq!(
+            api.to_string().expect("the api should serialize") + "\n",
+            read_schema_file(concat!(env!("CARGO_PKG_NAME"), ".json")),
+            "the combined api schema is stale. Run `make schema` and commit the result",
+        );
+        for (file_name, json) in api
+            .to_schema_files()
+            .expect("the schema files should serialize")
+        {
+            assert_eq!(
+                json + "\n",
+                read_schema_file(&format!("raw/{file_name}")),
+                "the raw schema file [{file_name}] is stale. Run `make schema` and commit the result",
+            );
+        }
+    }
+}
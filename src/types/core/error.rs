@@ -13,6 +13,74 @@ pub enum ContractError {
         message: String,
     },
 
+    /// Occurs when an account attempts to record more approvals within a block-height window than
+    /// the contract's [ApprovalLimits](crate::store::contract_state::ApprovalLimits) allow.
+    #[error("Account [{account}] has reached the maximum of [{max_approvals}] approvals per [{window_blocks}] blocks; approvals resume at height [{available_at_height}]")]
+    ApprovalRateLimitError {
+        /// The bech32 address of the rate-limited account.
+        account: String,
+        /// The configured maximum number of approvals per window.
+        max_approvals: u64,
+        /// The configured window size, in blocks.
+        window_blocks: u64,
+        /// The block height at which the account may next record an approval.
+        available_at_height: u64,
+    },
+
+    /// Occurs when the chain query for an account's attributes fails while the contract is in
+    /// [strict](crate::store::contract_state::AttributeQueryMode::Strict) attribute query mode.
+    #[error("Attribute query for account [{account}] failed: {message}")]
+    AttributeQueryError {
+        /// The bech32 address of the account whose attributes were being fetched.
+        account: String,
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// Occurs when paging through an account's attributes would require more pages than allowed.
+    #[error("Attributes for account [{account}] exceed the maximum of [{max_pages}] pages")]
+    AttributePageLimitError {
+        /// The bech32 address of the account whose attributes were being fetched.
+        account: String,
+        /// The maximum number of pages that may be fetched.
+        max_pages: u32,
+    },
+
+    /// Occurs when an account on the contract's blocklist attempts to record a new approval.
+    #[error("Account [{account}] is blocked from recording approvals: {reason}")]
+    BlockedAccountError {
+        /// The bech32 address of the blocked account.
+        account: String,
+        /// The reason recorded by the admin when the account was blocked.
+        reason: String,
+    },
+
+    /// Occurs when an approval is attempted for a group that has reached its admin-configured member
+    /// cap.
+    #[error("Group [{group_id}] has reached its maximum of [{max_members}] consenting members")]
+    GroupCapacityError {
+        /// The unique identifier of the full group.
+        group_id: u64,
+        /// The group's configured maximum number of consenting members.
+        max_members: u64,
+    },
+
+    /// Occurs when a query to the Cosmos group module fails or exceeds its page bound.
+    #[error("Group query failed: {message}")]
+    GroupQueryError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// Occurs when an IBC channel handshake is rejected.
+    #[error("IBC channel [{channel_id}] rejected: {message}")]
+    IbcChannelError {
+        /// The identifier of the channel on this chain.
+        channel_id: String,
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
     /// Occurs when an error is encountered during contract instantiation.
     #[error("Contract instantiation failed: {message}")]
     InstantiationError {
@@ -41,6 +109,18 @@ pub enum ContractError {
         message: String,
     },
 
+    /// Occurs when an account attempts to re-approve a group before the cooldown that follows its
+    /// withdrawal of that group has elapsed.
+    #[error("Account [{account}] may not re-approve group [{group_id}] until height [{available_at_height}]")]
+    ReapprovalCooldownError {
+        /// The bech32 address of the account.
+        account: String,
+        /// The unique identifier of the group that was previously withdrawn.
+        group_id: u64,
+        /// The block height at which the account may re-approve the group.
+        available_at_height: u64,
+    },
+
     /// Occurs when the semver library fails an operation.  This wraps the original error to allow
     /// it to conform with the [ContractError] typing.
     #[error("{0}")]
@@ -57,4 +137,34 @@ pub enum ContractError {
         /// A free-form message describing the nature of the error.
         message: String,
     },
+
+    /// Occurs when an approval does not acknowledge the terms currently published for its group.
+    #[error("Terms for group [{group_id}] were not acknowledged: {message}")]
+    TermsAcknowledgementError {
+        /// The unique identifier of the group being approved.
+        group_id: u64,
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// Occurs when an account attempts to invoke a route that it does not have permission to use.
+    #[error("Unauthorized: {message}")]
+    UnauthorizedError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+}
+
+impl ContractError {
+    /// Wraps an error encountered during contract store communication in a
+    /// [StorageError](ContractError::StorageError).
+    ///
+    /// # Parameters
+    ///
+    /// * `e` The error produced by the storage operation.
+    pub fn storage_error<E: std::fmt::Debug>(e: E) -> Self {
+        ContractError::StorageError {
+            message: format!("{e:?}"),
+        }
+    }
 }
@@ -0,0 +1,97 @@
+use crate::store::approval_events::ApprovalAction;
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, StdResult, Uint64, WasmMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The notification sent to each [registered hook](crate::store::hooks::RegisteredHook) when an
+/// account approves, withdraws its approval of, or accepts new terms for a group.  Receiving
+/// contracts must accept it as the `approval_hook` variant of their execute message, ex:
+/// `{"approval_hook": {"account": "tp1...", "group_id": "1", ...}}`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ApprovalHookMsg {
+    /// The bech32 address of the account whose approval changed.
+    pub account: Addr,
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// The bech32 address of the group policy account, if the approval applies to a single policy
+    /// of the group.
+    pub policy_address: Option<Addr>,
+    /// Whether the approval was recorded, withdrawn, or brought up to date with new terms.
+    pub action: ApprovalAction,
+    /// The memo provided with the approval, if any.
+    pub memo: Option<String>,
+    /// The block height at which the change was recorded.
+    pub block_height: Uint64,
+}
+impl ApprovalHookMsg {
+    /// Wraps this notification in the `approval_hook` execute variant expected by receiving
+    /// contracts and builds a message that delivers it to the given contract.
+    ///
+    /// # Parameters
+    ///
+    /// * `contract_addr` The bech32 address of the receiving contract.
+    pub fn into_cosmos_msg<S: Into<String>>(self, contract_addr: S) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&ApprovalHookExecuteMsg::ApprovalHook(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The execute message envelope in which an [ApprovalHookMsg] is delivered.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalHookExecuteMsg {
+    /// Notifies the receiving contract of a change to an account's approval of a group.
+    ApprovalHook(ApprovalHookMsg),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::approval_events::ApprovalAction;
+    use crate::types::core::hook::{ApprovalHookExecuteMsg, ApprovalHookMsg};
+    use cosmwasm_std::{from_json, Addr, CosmosMsg, Uint64, WasmMsg};
+
+    #[test]
+    fn test_into_cosmos_msg_wraps_payload() {
+        let hook_msg = ApprovalHookMsg {
+            account: Addr::unchecked("member"),
+            group_id: Uint64::new(3),
+            policy_address: None,
+            action: ApprovalAction::Approve,
+            memo: Some("signer".to_string()),
+            block_height: Uint64::new(12),
+        };
+        match hook_msg
+            .to_owned()
+            .into_cosmos_msg("receiver")
+            .expect("the hook message should serialize")
+        {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                assert_eq!(
+                    "receiver", contract_addr,
+                    "the hook contract should be targeted"
+                );
+                assert!(funds.is_empty(), "no funds should be sent to hooks");
+                assert_eq!(
+                    ApprovalHookExecuteMsg::ApprovalHook(hook_msg),
+                    from_json(&msg).expect("the payload should deserialize"),
+                    "the payload should be wrapped in the approval_hook variant",
+                );
+                assert!(
+                    String::from_utf8(msg.to_vec())
+                        .expect("the payload should be utf8 json")
+                        .starts_with("{\"approval_hook\":"),
+                    "the payload should use the snake_case approval_hook key",
+                );
+            }
+            msg => panic!("unexpected message: {msg:?}"),
+        }
+    }
+}
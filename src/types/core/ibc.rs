@@ -0,0 +1,49 @@
+use crate::types::core::hook::ApprovalHookMsg;
+use cosmwasm_std::Uint64;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The channel version negotiated with counterparties during the IBC channel handshake.  A change
+/// to the packet format that counterparties cannot decode must be released under a new version.
+pub const IBC_APP_VERSION: &str = "group-member-approval-1";
+
+/// The number of seconds after which an unrelayed approval packet times out on the counterparty.
+pub const IBC_PACKET_TIMEOUT_SECONDS: u64 = 600;
+
+/// The envelope of every packet sent or received over this contract's IBC channels.  Each variant
+/// wraps the packets defined by a single version of the packet format, ex:
+/// `{"v1": {"has_approved": {"account": "tp1...", "group_id": "1"}}}`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalPacket {
+    /// The first version of the packet format.
+    V1(ApprovalPacketV1),
+}
+
+/// The packets defined by the first version of the packet format.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalPacketV1 {
+    /// Sent by this contract to each connected counterparty when an account approves or withdraws
+    /// its approval of a group.  Counterparties may not send this packet to this contract.
+    ApprovalChanged(ApprovalHookMsg),
+    /// Sent by a counterparty to ask whether an account has approved a group.  Answered with a
+    /// [HasApprovedAck] in a successful acknowledgement.
+    HasApproved {
+        /// The bech32 address of the account on this chain.
+        account: String,
+        /// The unique identifier of the group on this chain.
+        group_id: Uint64,
+    },
+}
+
+/// The data returned in the successful acknowledgement of an [ApprovalPacketV1::HasApproved] packet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct HasApprovedAck {
+    /// The bech32 address of the account that was checked.
+    pub account: String,
+    /// The unique identifier of the group that was checked.
+    pub group_id: Uint64,
+    /// Whether the account holds an approval of the group.
+    pub approved: bool,
+}
@@ -1,6 +1,14 @@
 //! Contains all the driving types for base functionality.
 
+/// Defines the combined API schema of the contract's messages and query responses.
+pub mod api;
 /// Defines each custom error that can occur throughout contract execution.
 pub mod error;
+/// Defines the notification payload sent to registered approval hooks.
+pub mod hook;
+/// Defines the versioned packet format exchanged with IBC counterparties.
+pub mod ibc;
 /// Defines each input msg utilized by contract invocations.
 pub mod msg;
+/// Defines each response payload produced by contract queries.
+pub mod response;
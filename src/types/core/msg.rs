@@ -1,4 +1,15 @@
-use cosmwasm_std::Uint64;
+use crate::store::contract_state::{ApprovalLimits, AttributeQueryMode, ContractState};
+use crate::store::group_caps::GroupCapacity;
+use crate::store::group_stats::GroupStats;
+use crate::store::group_terms::TermsAcceptance;
+use crate::types::core::response::{
+    QueryAllGroupStatsResponse, QueryApprovalEventsResponse, QueryAttributeDiagnosticsResponse,
+    QueryBlockedAccountsResponse, QueryGroupConsentStatusResponse, QueryGroupTermsResponse,
+    QueryHistoryResponse, QueryHooksResponse, QueryIbcChannelsResponse,
+};
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Binary, Timestamp, Uint64};
+use cw2::ContractVersion;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -33,16 +44,290 @@ pub enum ExecuteMsg {
     ApproveGroupMembership {
         /// The unique identifier of the group for which the signing account consents to membership.
         group_id: Uint64,
+        /// An optional statement describing the capacity in which the signing account consents to
+        /// membership (ex: "signer").  Limited to [MAX_MEMO_LENGTH](crate::util::route_helpers::MAX_MEMO_LENGTH)
+        /// characters.
+        #[serde(default)]
+        memo: Option<String>,
+        /// The content hash of the terms published for the group, acknowledging that the signing
+        /// account accepts them.  Required if and only if the contract admin has published terms
+        /// for the group.
+        #[serde(default)]
+        terms_hash: Option<String>,
+    },
+    /// A route that allows the signing account to approve its participation in a specific
+    /// [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy)
+    /// rather than a whole group.  This invokes the functionality defined in
+    /// [approve_group_policy](crate::execute::approve_group_policy::approve_group_policy).
+    ApproveGroupPolicy {
+        /// The bech32 address of the group policy account.  The route fails if the policy does not
+        /// exist in the group module.
+        policy_address: String,
+    },
+    /// A route that allows the signing account to approve, in a single transaction, every
+    /// [Provenance Blockchain Group](https://docs.cosmos.network/main/modules/group) in which it is
+    /// currently a member and that it has not yet approved.  This invokes the functionality defined
+    /// in [approve_all_current_groups](crate::execute::approve_all_current_groups::approve_all_current_groups).
+    ApproveAllCurrentGroups {},
+    /// A route that allows the signing account to accept the currently published terms of a group
+    /// it has already approved, without recording a new approval.  This invokes the functionality
+    /// defined in [acknowledge_group_terms](crate::execute::acknowledge_group_terms::acknowledge_group_terms).
+    AcknowledgeGroupTerms {
+        /// The unique identifier of the approved group.
+        group_id: Uint64,
+        /// The content hash of the terms currently published for the group.
+        terms_hash: String,
+    },
+    /// A route that allows the signing account to withdraw a previously-recorded approval of its
+    /// membership to a [Provenance Blockchain Group](https://docs.cosmos.network/main/modules/group)
+    /// by removing the group id from its attribute.  This invokes the functionality defined in
+    /// [revoke_group_membership](crate::execute::revoke_group_membership::revoke_group_membership).
+    RevokeGroupMembership {
+        /// The unique identifier of the group for which the signing account withdraws its consent.
+        group_id: Uint64,
+    },
+    /// A route that allows the signing account to withdraw a previously-recorded approval of its
+    /// participation in a specific [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy).
+    /// This invokes the functionality defined in [revoke_group_policy](crate::execute::revoke_group_policy::revoke_group_policy).
+    RevokeGroupPolicy {
+        /// The bech32 address of the group policy account for which the signing account withdraws
+        /// its consent.
+        policy_address: String,
+    },
+    /// A route that allows the contract admin to bar an account from recording new approvals.
+    /// This invokes the functionality defined in [block_account](crate::execute::block_account::block_account).
+    BlockAccount {
+        /// The bech32 address of the account to block.
+        account: String,
+        /// A non-blank explanation for the block, such as a sanctions or offboarding reference.
+        /// Limited to [MAX_REASON_LENGTH](crate::util::route_helpers::MAX_REASON_LENGTH) characters.
+        reason: String,
+    },
+    /// A route that allows the contract admin to remove an account from the blocklist.  This
+    /// invokes the functionality defined in [unblock_account](crate::execute::unblock_account::unblock_account).
+    UnblockAccount {
+        /// The bech32 address of the account to unblock.
+        account: String,
+    },
+    /// A route that allows the contract admin to set or remove the maximum number of accounts that
+    /// may consent to membership in a group.  This invokes the functionality defined in
+    /// [set_group_member_cap](crate::execute::set_group_member_cap::set_group_member_cap).
+    SetGroupMemberCap {
+        /// The unique identifier of the group to cap.
+        group_id: Uint64,
+        /// The maximum number of consenting members.  Omitting this value removes the cap.
+        max_members: Option<Uint64>,
+    },
+    /// A route that allows the contract admin to publish a new version of the terms that members
+    /// must acknowledge when approving a group.  This invokes the functionality defined in
+    /// [publish_group_terms](crate::execute::publish_group_terms::publish_group_terms).
+    PublishGroupTerms {
+        /// The unique identifier of the group to which the terms apply.
+        group_id: Uint64,
+        /// The location at which the full text of the terms can be found.
+        uri: String,
+        /// The hex-encoded hash of the terms' content.
+        content_hash: String,
+    },
+    /// A route that allows the contract admin to configure how frequently a single account may
+    /// record approvals.  This invokes the functionality defined in
+    /// [update_approval_limits](crate::execute::update_approval_limits::update_approval_limits).
+    UpdateApprovalLimits {
+        /// The new limits to store in the contract state.
+        approval_limits: ApprovalLimits,
+    },
+    /// A route that allows the contract admin to choose whether a failed chain query for an
+    /// account's existing attributes aborts approvals and revocations.  This invokes the
+    /// functionality defined in [set_attribute_query_mode](crate::execute::set_attribute_query_mode::set_attribute_query_mode).
+    SetAttributeQueryMode {
+        /// The new mode to store in the contract state.
+        mode: AttributeQueryMode,
+    },
+    /// A route that allows the contract admin to remove an account's approval of a group on its
+    /// behalf, such as for a compromised or offboarded account.  This invokes the functionality
+    /// defined in [force_revoke_approval](crate::execute::force_revoke_approval::force_revoke_approval).
+    ForceRevokeApproval {
+        /// The bech32 address of the account whose approval is removed.
+        account: String,
+        /// The unique identifier of the group for which the approval is removed.
+        group_id: Uint64,
+        /// A non-blank explanation for the revocation, such as a compliance case reference.
+        /// Limited to [MAX_REASON_LENGTH](crate::util::route_helpers::MAX_REASON_LENGTH) characters.
+        reason: String,
+    },
+    /// A route that allows the contract admin to remove an account's approval of a specific
+    /// [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy) on
+    /// its behalf.  This invokes the functionality defined in
+    /// [force_revoke_policy_approval](crate::execute::force_revoke_approval::force_revoke_policy_approval).
+    ForceRevokePolicyApproval {
+        /// The bech32 address of the account whose approval is removed.
+        account: String,
+        /// The bech32 address of the group policy account for which the approval is removed.
+        policy_address: String,
+        /// A non-blank explanation for the revocation, such as a compliance case reference.
+        /// Limited to [MAX_REASON_LENGTH](crate::util::route_helpers::MAX_REASON_LENGTH) characters.
+        reason: String,
+    },
+    /// A route that allows the contract admin to register a contract to be notified each time an
+    /// account approves or withdraws its approval of a group.  This invokes the functionality
+    /// defined in [add_hook](crate::execute::add_hook::add_hook).
+    AddHook {
+        /// The bech32 address of the contract to notify.
+        contract: String,
+    },
+    /// A route that allows the contract admin to stop notifying a registered hook contract.  This
+    /// invokes the functionality defined in [remove_hook](crate::execute::remove_hook::remove_hook).
+    RemoveHook {
+        /// The bech32 address of the hook contract to remove.
+        contract: String,
+    },
+    /// A route that allows the contract admin to permit counterparties to open IBC channels over a
+    /// connection.  This invokes the functionality defined in
+    /// [allow_ibc_connection](crate::execute::allow_ibc_connection::allow_ibc_connection).
+    AllowIbcConnection {
+        /// The identifier of the IBC connection on this chain, such as `connection-0`.
+        connection_id: String,
+    },
+    /// A route that allows the contract admin to stop permitting new IBC channels over a
+    /// connection.  This invokes the functionality defined in
+    /// [disallow_ibc_connection](crate::execute::disallow_ibc_connection::disallow_ibc_connection).
+    DisallowIbcConnection {
+        /// The identifier of the IBC connection on this chain to remove from the allowlist.
+        connection_id: String,
+    },
+    /// A route that allows the contract admin to close a connected IBC channel.  This invokes the
+    /// functionality defined in [close_ibc_channel](crate::execute::close_ibc_channel::close_ibc_channel).
+    CloseIbcChannel {
+        /// The identifier of the connected channel on this chain.
+        channel_id: String,
     },
 }
 
-/// All defined payloads to be used when querying routes on this contract instance.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+/// All defined payloads to be used when querying routes on this contract instance.  Each variant
+/// declares the type of its response, which is included in the generated API schema.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, QueryResponses)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     /// A route that returns the current [ContractState](crate::store::contract_state::ContractState)
     /// value stored in state.  Invokes the functionality defined in [query_contract_state](crate::query::query_contract_state::query_contract_state).
+    #[returns(ContractState)]
     QueryContractState {},
+    /// A route that returns the [cw2](https://github.com/CosmWasm/cw-minus/tree/main/packages/cw2)
+    /// contract version info for this contract.  Invokes the functionality defined in
+    /// [query_contract_info](crate::query::query_contract_info::query_contract_info).
+    #[returns(ContractVersion)]
+    QueryContractInfo {},
+    /// A route that returns a page of the contract's append-only history log, which records each
+    /// migration and privileged configuration change.  Invokes the functionality defined in
+    /// [query_history](crate::query::query_history::query_history).
+    #[returns(QueryHistoryResponse)]
+    QueryHistory {
+        /// If provided, only entries with an identifier greater than this value will be returned.
+        start_after: Option<Uint64>,
+        /// The maximum number of entries to return.
+        limit: Option<u32>,
+    },
+    /// A route that returns the approval counters maintained for a single group.  Invokes the
+    /// functionality defined in [query_group_stats](crate::query::query_group_stats::query_group_stats).
+    #[returns(GroupStats)]
+    QueryGroupStats {
+        /// The unique identifier of the group for which to fetch counters.
+        group_id: Uint64,
+    },
+    /// A route that returns a page of the approval counters maintained for every group with
+    /// recorded activity.  Invokes the functionality defined in
+    /// [query_all_group_stats](crate::query::query_group_stats::query_all_group_stats).
+    #[returns(QueryAllGroupStatsResponse)]
+    QueryAllGroupStats {
+        /// If provided, only groups with an id greater than this value will be returned.
+        start_after: Option<Uint64>,
+        /// The maximum number of groups to return.
+        limit: Option<u32>,
+    },
+    /// A route that returns a page of the append-only audit trail of approvals and withdrawals,
+    /// narrowed by the provided criteria.  All criteria are optional and all ranges are inclusive.
+    /// Invokes the functionality defined in [query_approval_events](crate::query::query_approval_events::query_approval_events).
+    #[returns(QueryApprovalEventsResponse)]
+    QueryApprovalEvents {
+        /// Only include events for this bech32 account address.
+        account: Option<String>,
+        /// Only include events for this group id.
+        group_id: Option<Uint64>,
+        /// Only include events recorded at or after this block height.
+        min_height: Option<Uint64>,
+        /// Only include events recorded at or before this block height.
+        max_height: Option<Uint64>,
+        /// Only include events recorded at or after this block time.
+        min_time: Option<Timestamp>,
+        /// Only include events recorded at or before this block time.
+        max_time: Option<Timestamp>,
+        /// If provided, only events with a sequence greater than this value will be returned.
+        start_after: Option<Uint64>,
+        /// The maximum number of events to return.
+        limit: Option<u32>,
+    },
+    /// A route that returns a page of the accounts barred from recording approvals.  Invokes the
+    /// functionality defined in [query_blocked_accounts](crate::query::query_blocked_accounts::query_blocked_accounts).
+    #[returns(QueryBlockedAccountsResponse)]
+    QueryBlockedAccounts {
+        /// If provided, only accounts with an address greater than this value will be returned.
+        start_after: Option<String>,
+        /// The maximum number of accounts to return.
+        limit: Option<u32>,
+    },
+    /// A route that returns the member cap, current approval count, and remaining capacity of a
+    /// group.  Invokes the functionality defined in [query_group_capacity](crate::query::query_group_capacity::query_group_capacity).
+    #[returns(GroupCapacity)]
+    QueryGroupCapacity {
+        /// The unique identifier of the group for which to fetch capacity.
+        group_id: Uint64,
+    },
+    /// A route that pages a group's members via the group module and reports which of them have
+    /// recorded an approval for the group.  Invokes the functionality defined in
+    /// [query_group_consent_status](crate::query::query_group_consent_status::query_group_consent_status).
+    #[returns(QueryGroupConsentStatusResponse)]
+    QueryGroupConsentStatus {
+        /// The unique identifier of the group for which to report consent.
+        group_id: Uint64,
+        /// The `next_key` returned with the previous page.  Omit to fetch the first page.
+        page_key: Option<Binary>,
+        /// The maximum number of members to return.  Defaults to 10 and is capped at 100.
+        limit: Option<u32>,
+    },
+    /// A route that returns the terms currently published for a group.  Invokes the functionality
+    /// defined in [query_group_terms](crate::query::query_group_terms::query_group_terms).
+    #[returns(QueryGroupTermsResponse)]
+    QueryGroupTerms {
+        /// The unique identifier of the group for which to fetch terms.
+        group_id: Uint64,
+    },
+    /// A route that returns the version of a group's terms accepted with an account's approval,
+    /// flagging approvals of superseded terms as outdated.  Invokes the functionality defined in
+    /// [query_terms_acceptance](crate::query::query_group_terms::query_terms_acceptance).
+    #[returns(TermsAcceptance)]
+    QueryTermsAcceptance {
+        /// The bech32 address of the account to inspect.
+        account: String,
+        /// The unique identifier of the group.
+        group_id: Uint64,
+    },
+    /// A route that lists the values written under the contract's attribute name on an account that
+    /// cannot be decoded as a group id or group policy approval.  Invokes the functionality defined
+    /// in [query_attribute_diagnostics](crate::query::query_attribute_diagnostics::query_attribute_diagnostics).
+    #[returns(QueryAttributeDiagnosticsResponse)]
+    QueryAttributeDiagnostics {
+        /// The bech32 address of the account to inspect.
+        account: String,
+    },
+    /// A route that lists the contracts registered to be notified of approval changes.  Invokes the
+    /// functionality defined in [query_hooks](crate::query::query_hooks::query_hooks).
+    #[returns(QueryHooksResponse)]
+    QueryHooks {},
+    /// A route that lists the connected IBC channels and the connections over which channels may
+    /// be opened.  Invokes the functionality defined in
+    /// [query_ibc_channels](crate::query::query_ibc_channels::query_ibc_channels).
+    #[returns(QueryIbcChannelsResponse)]
+    QueryIbcChannels {},
 }
 
 /// All defined payloads to be used when migrating to a new instance of this contract.
@@ -52,5 +337,23 @@ pub enum MigrateMsg {
     /// The standard migration route that modifies [ContractState](crate::store::contract_state::ContractState)
     /// to include the new values defined in a target code instance.  Invokes the functionality
     /// defined in [contract_upgrade](crate::migrate::contract_upgrade::contract_upgrade).
-    ContractUpgrade {},
+    ContractUpgrade {
+        /// Optional overrides for the version checks performed during the migration.  When omitted,
+        /// only migrations to a strictly newer version are allowed.
+        options: Option<MigrationOptions>,
+    },
+}
+
+/// Explicit overrides for the version checks performed during a
+/// [contract_upgrade](crate::migrate::contract_upgrade::contract_upgrade).  The contract type check
+/// can never be overridden.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(default, rename_all = "snake_case")]
+pub struct MigrationOptions {
+    /// If true, a migration to the same version as the stored version is allowed.  Used to
+    /// redeploy a hotfix without changing the version.
+    pub allow_same_version: bool,
+    /// If true, a migration to a version lower than the stored version is allowed.  Used to
+    /// perform emergency rollbacks.
+    pub allow_downgrade: bool,
 }
@@ -0,0 +1,141 @@
+use crate::store::approval_events::ApprovalEvent;
+use crate::store::blocklist::BlockedAccount;
+use crate::store::group_stats::GroupStats;
+use crate::store::group_terms::GroupTerms;
+use crate::store::history::HistoryEntry;
+use crate::store::hooks::RegisteredHook;
+use crate::store::ibc_channels::{AllowedConnection, ConnectedChannel};
+use cosmwasm_std::{Binary, Uint64};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The response returned by the [query_history](crate::query::query_history::query_history) route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryHistoryResponse {
+    /// A page of history entries in ascending order of their identifiers.
+    pub entries: Vec<HistoryEntry>,
+}
+
+/// The response returned by the [query_all_group_stats](crate::query::query_group_stats::query_all_group_stats)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryAllGroupStatsResponse {
+    /// A page of group counters in ascending order of group id.
+    pub stats: Vec<GroupStats>,
+}
+
+/// The response returned by the [query_approval_events](crate::query::query_approval_events::query_approval_events)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryApprovalEventsResponse {
+    /// A page of matching approval events in ascending order of sequence.
+    pub events: Vec<ApprovalEvent>,
+}
+
+/// The response returned by the [query_blocked_accounts](crate::query::query_blocked_accounts::query_blocked_accounts)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryBlockedAccountsResponse {
+    /// A page of blocked accounts in ascending order of address.
+    pub accounts: Vec<BlockedAccount>,
+}
+
+/// The response returned by the [query_hooks](crate::query::query_hooks::query_hooks) route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryHooksResponse {
+    /// Every registered hook in ascending order of contract address.
+    pub hooks: Vec<RegisteredHook>,
+}
+
+/// The response returned by the [query_ibc_channels](crate::query::query_ibc_channels::query_ibc_channels)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryIbcChannelsResponse {
+    /// Every connected channel in ascending order of channel id.
+    pub channels: Vec<ConnectedChannel>,
+    /// Every allowlisted connection in ascending order of connection id.
+    pub allowed_connections: Vec<AllowedConnection>,
+}
+
+/// The response returned by the [query_group_consent_status](crate::query::query_group_consent_status::query_group_consent_status)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryGroupConsentStatusResponse {
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// A page of the group's members in the order maintained by the group module.
+    pub members: Vec<MemberConsentStatus>,
+    /// The number of members in this page that have recorded an approval for the group.
+    pub page_consented_count: Uint64,
+    /// The number of members in this page that have not recorded an approval for the group.
+    pub page_not_consented_count: Uint64,
+    /// The number of members in this page whose approval could not be determined.
+    pub page_unknown_count: Uint64,
+    /// The total number of members in the group, as reported by the group module.  Only reported
+    /// for the first page.
+    pub total_members: Option<Uint64>,
+    /// The key to provide as `page_key` to fetch the next page, or `None` if this is the last page.
+    pub next_key: Option<Binary>,
+}
+
+/// Whether a group member has recorded an approval for the group.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentState {
+    /// The member holds an approval attribute for the group.
+    Consented,
+    /// The member does not hold an approval attribute for the group.
+    NotConsented,
+    /// The member's attributes could not be fetched, which is only tolerated in
+    /// [lenient](crate::store::contract_state::AttributeQueryMode::Lenient) mode.
+    Unknown,
+}
+
+/// Whether a single group member has recorded an approval for the group.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MemberConsentStatus {
+    /// The bech32 address of the member.
+    pub address: String,
+    /// Whether the member holds an approval attribute for the group.
+    pub consent: ConsentState,
+    /// The version of the group's terms accepted with the member's approval, if any.
+    pub accepted_terms_version: Option<Uint64>,
+    /// Whether the member's approval predates the group's currently published terms.
+    pub terms_outdated: bool,
+    /// The memo provided with the member's approval, if any.
+    pub memo: Option<String>,
+}
+
+/// The response returned by the [query_group_terms](crate::query::query_group_terms::query_group_terms)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryGroupTermsResponse {
+    /// The unique identifier of the group.
+    pub group_id: Uint64,
+    /// The terms currently published for the group, if any.
+    pub terms: Option<GroupTerms>,
+}
+
+/// The response returned by the [query_attribute_diagnostics](crate::query::query_attribute_diagnostics::query_attribute_diagnostics)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct QueryAttributeDiagnosticsResponse {
+    /// The bech32 address of the inspected account.
+    pub account: String,
+    /// The contract's attribute name, under which the account's values were inspected.
+    pub attribute_name: String,
+    /// Each value that could not be decoded as a group id or a group policy approval.
+    pub undecodable_values: Vec<UndecodableAttributeValue>,
+}
+
+/// An attribute value written under the contract's attribute name that the approval routes ignore
+/// because it cannot be decoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct UndecodableAttributeValue {
+    /// The name of the attribute's value type (ex: `ATTRIBUTE_TYPE_STRING`).
+    pub attribute_type: String,
+    /// The raw stored value.
+    pub value: Binary,
+    /// A description of why the value could not be decoded.
+    pub reason: String,
+}
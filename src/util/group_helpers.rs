@@ -0,0 +1,267 @@
+use crate::types::core::error::ContractError;
+use cosmwasm_std::{Binary, CustomQuery, Uint64};
+use provwasm_std::types::cosmos::base::query::v1beta1::PageRequest;
+use provwasm_std::types::cosmos::group::v1::GroupQuerier;
+use result_extensions::ResultExtensions;
+
+/// The number of groups requested per page when paging through the group module.
+pub const DEFAULT_GROUP_PAGE_SIZE: u64 = 100;
+
+/// The maximum number of pages fetched when paging through every group of a member.  This bounds
+/// the gas spent on a single member's groups.
+pub const DEFAULT_MAX_GROUP_PAGES: u32 = 10;
+
+/// Fetches the id of every group in which the address is a member via `GroupsByMember` Stargate
+/// queries to the group module, following the module's pagination.  An error is returned if the
+/// query fails or if more than [DEFAULT_MAX_GROUP_PAGES] pages would be required.
+///
+/// # Parameters
+///
+/// * `querier` The group module querier used to fetch pages of groups.
+/// * `member` The bech32 address of the member.
+pub fn get_group_ids_by_member<Q: CustomQuery>(
+    querier: &GroupQuerier<Q>,
+    member: &str,
+) -> Result<Vec<Uint64>, ContractError> {
+    let mut group_ids = vec![];
+    let mut next_key = vec![];
+    for _ in 0..DEFAULT_MAX_GROUP_PAGES {
+        let response = querier
+            .groups_by_member(
+                member.to_string(),
+                Some(PageRequest {
+                    key: next_key,
+                    offset: 0,
+                    limit: DEFAULT_GROUP_PAGE_SIZE,
+                    count_total: false,
+                    reverse: false,
+                }),
+            )
+            .map_err(|e| ContractError::GroupQueryError {
+                message: format!("groups for member [{member}] could not be fetched: {e}"),
+            })?;
+        group_ids.extend(response.groups.iter().map(|group| Uint64::new(group.id)));
+        match response
+            .pagination
+            .and_then(|page| page.next_key)
+            .filter(|key| !key.is_empty())
+        {
+            Some(key) => next_key = key,
+            None => return group_ids.to_ok(),
+        }
+    }
+    ContractError::GroupQueryError {
+        message: format!(
+            "groups for member [{member}] exceed the maximum of [{DEFAULT_MAX_GROUP_PAGES}] pages"
+        ),
+    }
+    .to_err()
+}
+
+/// Resolves the group that owns a group policy account via a `GroupPolicyInfo` Stargate query to
+/// the group module.  An error is returned if the query fails or if the policy does not exist.
+///
+/// # Parameters
+///
+/// * `querier` The group module querier used to fetch the policy.
+/// * `policy_address` The bech32 address of the group policy account.
+pub fn get_group_policy_group_id<Q: CustomQuery>(
+    querier: &GroupQuerier<Q>,
+    policy_address: &str,
+) -> Result<Uint64, ContractError> {
+    querier
+        .group_policy_info(policy_address.to_string())
+        .map_err(|e| ContractError::GroupQueryError {
+            message: format!("group policy [{policy_address}] could not be fetched: {e}"),
+        })?
+        .info
+        .map(|info| Uint64::new(info.group_id))
+        .ok_or_else(|| ContractError::GroupQueryError {
+            message: format!("group policy [{policy_address}] does not exist"),
+        })
+}
+
+/// A page of the addresses of a group's members, in the order maintained by the group module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupMemberPage {
+    /// The bech32 addresses of the members in the page.
+    pub members: Vec<String>,
+    /// The total number of members in the group, as reported by the group module.  The module only
+    /// counts members for the first page, so this is `None` when a page key is provided.
+    pub total_members: Option<u64>,
+    /// The key from which the next page should be fetched, or `None` if this is the last page.
+    pub next_key: Option<Binary>,
+}
+
+/// Fetches a page of a group's members via a single `GroupMembers` Stargate query to the group
+/// module.  The module's own `next_key` is passed through as the cursor for the next page, so each
+/// page costs one query regardless of how far into the group it is.  An error is returned if the
+/// query fails.
+///
+/// # Parameters
+///
+/// * `querier` The group module querier used to fetch the page of members.
+/// * `group_id` The unique identifier of the group.
+/// * `page_key` The `next_key` of the previous page, or `None` to fetch the first page.
+/// * `limit` The maximum number of members to return.
+pub fn get_group_member_page<Q: CustomQuery>(
+    querier: &GroupQuerier<Q>,
+    group_id: Uint64,
+    page_key: Option<&Binary>,
+    limit: u64,
+) -> Result<GroupMemberPage, ContractError> {
+    let key = page_key.map(|key| key.to_vec()).unwrap_or_default();
+    let first_page = key.is_empty();
+    let response = querier
+        .group_members(
+            group_id.u64(),
+            Some(PageRequest {
+                key,
+                offset: 0,
+                limit,
+                count_total: first_page,
+                reverse: false,
+            }),
+        )
+        .map_err(|e| ContractError::GroupQueryError {
+            message: format!("members of group [{group_id}] could not be fetched: {e}"),
+        })?;
+    GroupMemberPage {
+        members: response
+            .members
+            .into_iter()
+            .filter_map(|group_member| group_member.member)
+            .map(|member| member.address)
+            .collect(),
+        total_members: response
+            .pagination
+            .as_ref()
+            .filter(|_| first_page)
+            .map(|pagination| pagination.total),
+        next_key: response
+            .pagination
+            .and_then(|pagination| pagination.next_key)
+            .filter(|key| !key.is_empty())
+            .map(Binary::new),
+    }
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_helpers::{mock_group_members, mock_groups_by_member};
+    use crate::types::core::error::ContractError;
+    use crate::util::group_helpers::{
+        get_group_ids_by_member, get_group_member_page, DEFAULT_GROUP_PAGE_SIZE,
+        DEFAULT_MAX_GROUP_PAGES,
+    };
+    use cosmwasm_std::{Empty, QuerierWrapper, Uint64};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::cosmos::group::v1::{GroupQuerier, QueryGroupsByMemberRequest};
+
+    #[test]
+    fn test_get_group_ids_follows_pagination() {
+        let mut deps = mock_provenance_dependencies();
+        mock_groups_by_member(&mut deps.querier, vec![vec![1, 2], vec![5], vec![9]]);
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        assert_eq!(
+            vec![1u64, 2, 5, 9]
+                .into_iter()
+                .map(Uint64::new)
+                .collect::<Vec<_>>(),
+            get_group_ids_by_member(&GroupQuerier::new(&querier), "member")
+                .expect("the groups should be fetched"),
+            "the groups from every page should be returned in order",
+        );
+    }
+
+    #[test]
+    fn test_get_group_ids_is_bounded() {
+        let mut deps = mock_provenance_dependencies();
+        mock_groups_by_member(
+            &mut deps.querier,
+            (0..=DEFAULT_MAX_GROUP_PAGES as u64)
+                .map(|page| vec![page * DEFAULT_GROUP_PAGE_SIZE])
+                .collect(),
+        );
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        assert!(
+            matches!(
+                get_group_ids_by_member(&GroupQuerier::new(&querier), "member"),
+                Err(ContractError::GroupQueryError { .. })
+            ),
+            "a group query error should be emitted when the page bound is exceeded",
+        );
+    }
+
+    #[test]
+    fn test_get_group_ids_reports_query_failures() {
+        let mut deps = mock_provenance_dependencies();
+        QueryGroupsByMemberRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        assert!(
+            matches!(
+                get_group_ids_by_member(&GroupQuerier::new(&querier), "member"),
+                Err(ContractError::GroupQueryError { .. })
+            ),
+            "a group query error should be emitted when the query fails",
+        );
+    }
+
+    #[test]
+    fn test_get_group_member_page_follows_next_key() {
+        let mut deps = mock_provenance_dependencies();
+        mock_group_members(
+            &mut deps.querier,
+            vec![vec!["zed", "amy"], vec!["bob", "cat"], vec!["dan"]],
+        );
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        let querier = GroupQuerier::new(&querier);
+        let first = get_group_member_page(&querier, Uint64::new(1), None, 2)
+            .expect("the first page should be fetched");
+        assert_eq!(
+            (vec!["zed".to_string(), "amy".to_string()], Some(5)),
+            (first.members, first.total_members),
+            "the first page should follow the group module's order and report the total",
+        );
+        let second = get_group_member_page(&querier, Uint64::new(1), first.next_key.as_ref(), 2)
+            .expect("the second page should be fetched");
+        assert_eq!(
+            (vec!["bob".to_string(), "cat".to_string()], None),
+            (second.members, second.total_members),
+            "the second page should resume from the module's next key",
+        );
+        let last = get_group_member_page(&querier, Uint64::new(1), second.next_key.as_ref(), 2)
+            .expect("the last page should be fetched");
+        assert_eq!(
+            (vec!["dan".to_string()], None),
+            (last.members, last.next_key),
+            "the last page should not have a next key",
+        );
+    }
+
+    #[test]
+    fn test_get_group_member_page_uses_a_single_query() {
+        let mut deps = mock_provenance_dependencies();
+        mock_group_members(
+            &mut deps.querier,
+            (0..=DEFAULT_MAX_GROUP_PAGES as u64)
+                .map(|page| vec![format!("member{page}")])
+                .collect(),
+        );
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        let querier = GroupQuerier::new(&querier);
+        let mut page_key = None;
+        for page in 0..=DEFAULT_MAX_GROUP_PAGES {
+            let response = get_group_member_page(&querier, Uint64::new(1), page_key.as_ref(), 1)
+                .expect("every page should be reachable regardless of its depth");
+            assert_eq!(
+                vec![format!("member{page}")],
+                response.members,
+                "each page should be fetched from the previous page's key",
+            );
+            page_key = response.next_key;
+        }
+        assert_eq!(None, page_key, "the final page should not have a next key");
+    }
+}
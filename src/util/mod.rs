@@ -1,6 +1,12 @@
 //! Additional functionality that does not strictly belong to a category.  Global helpers.
 
+/// Utility functions that assist in querying the Cosmos group module.
+pub mod group_helpers;
+/// Utility functions that assist in paginating query results.
+pub mod pagination;
 /// Utility functions that assist in Provenance Blockchain communication and data parsing.
 pub mod prov_helpers;
+/// A validated representation of a fully-qualified Provenance Blockchain name.
+pub mod provenance_name;
 /// Utility functions that assist in performing common tasks needed by routes.
 pub mod route_helpers;
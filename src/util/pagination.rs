@@ -0,0 +1,34 @@
+/// The number of records returned by a paginated query when no limit is specified.
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+/// The maximum number of records that a paginated query can return in a single page.
+pub const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Derives the number of records to return from a paginated query, defaulting to
+/// [DEFAULT_QUERY_LIMIT] when no limit is specified and never exceeding [MAX_QUERY_LIMIT].
+///
+/// # Parameters
+///
+/// * `limit` The optional limit provided in a query msg.
+pub fn query_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::pagination::{query_limit, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+
+    #[test]
+    fn test_query_limit() {
+        assert_eq!(
+            DEFAULT_QUERY_LIMIT as usize,
+            query_limit(None),
+            "the default limit should be used when no limit is provided",
+        );
+        assert_eq!(5, query_limit(Some(5)), "a provided limit should be used");
+        assert_eq!(
+            MAX_QUERY_LIMIT as usize,
+            query_limit(Some(MAX_QUERY_LIMIT + 1)),
+            "limits above the maximum should be reduced to the maximum",
+        );
+    }
+}
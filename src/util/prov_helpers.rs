@@ -1,108 +1,161 @@
 use cosmwasm_std::{from_json, CustomQuery, Uint64};
 use provwasm_std::types::{
-    cosmos::base::query::v1beta1::{PageRequest, PageResponse},
+    cosmos::base::query::v1beta1::PageRequest,
     provenance::{
-        attribute::v1::{AttributeQuerier, AttributeType, QueryAttributesResponse},
+        attribute::v1::{
+            Attribute, AttributeQuerier, AttributeType, MsgDeleteDistinctAttributeRequest,
+            QueryAttributesResponse,
+        },
         name::v1::{MsgBindNameRequest, NameRecord},
     },
 };
 use result_extensions::ResultExtensions;
+use std::collections::BTreeSet;
 
+use crate::store::contract_state::AttributeQueryMode;
 use crate::types::core::error::ContractError;
+use crate::util::provenance_name::ProvenanceName;
+
+/// The prefix applied to attribute values that record consent to a [group policy account](https://docs.cosmos.network/main/build/modules/group#group-policy).
+/// Group policy approvals are written with a [String](AttributeType::String) value type, which
+/// keeps them distinct from the [Int](AttributeType::Int) values used for plain group ids.
+pub const GROUP_POLICY_VALUE_PREFIX: &str = "group_policy:";
 
 /// Parses all group ids from the [Provenance Blockchain Attributes](https://docs.provenance.io/modules/account)
 /// provided by filtering for all values that match the given name and have an assigned int value.
 ///
 /// # Parameters
 ///
-/// * `attributes` Pages of Attributes fetched via a chain query.
+/// * `attributes` Attributes fetched via a chain query.
 /// * `name` A [Provenance Blockchain Name Module](https://docs.provenance.io/modules/name-module)
-/// name used to write the attribute.
-pub fn get_group_id_attribute_values_paginated<S: Into<String>>(
-    attributes: Vec<QueryAttributesResponse>,
+///   name used to write the attribute.
+pub fn get_group_id_attribute_values<S: Into<String>>(
+    attributes: &QueryAttributesResponse,
     name: S,
 ) -> Vec<Uint64> {
     let name = name.into();
     attributes
+        .attributes
         .iter()
-        .flat_map(|page| &page.attributes)
-        .filter(|attr| attr.name == name && attr.attribute_type() == AttributeType::Int)
-        .filter_map(|attr| from_json::<u64>(&attr.value).ok())
-        .map(Uint64::new)
+        .filter_map(|attr| parse_group_id_attribute(attr, &name))
         .collect()
 }
 
-/// Parses all group ids from the [Provenance Blockchain Attributes](https://docs.provenance.io/modules/account)
-/// provided by filtering for all values that match the given name and have an assigned int value.
+/// Parses a group id from a single [Provenance Blockchain Attribute](https://docs.provenance.io/modules/account),
+/// returning `None` unless the attribute matches the given name and its value can be decoded by
+/// [decode_group_id].
 ///
 /// # Parameters
 ///
-/// * `attributes` Attributes fetched via a chain query.
+/// * `attribute` An attribute fetched via a chain query.
 /// * `name` A [Provenance Blockchain Name Module](https://docs.provenance.io/modules/name-module)
-/// name used to write the attribute.
-pub fn get_group_id_attribute_values<S: Into<String>>(
-    attributes: &QueryAttributesResponse,
-    name: S,
-) -> Vec<Uint64> {
-    get_group_id_attribute_values_paginated(vec![attributes.clone()], name)
+///   name used to write the attribute.
+pub fn parse_group_id_attribute(attribute: &Attribute, name: &str) -> Option<Uint64> {
+    if attribute.name != name {
+        return None;
+    }
+    decode_group_id(attribute).ok().map(Uint64::new)
+}
+
+/// Decodes the group id held by an attribute value.  In addition to the json integers written by
+/// this contract with the [Int](AttributeType::Int) type, the encodings found on older attributes
+/// are accepted: json strings holding an integer (ex: `"1"`), and [String](AttributeType::String)
+/// typed values holding an integer.  Values that cannot be decoded produce a description of the
+/// problem.
+///
+/// # Parameters
+///
+/// * `attribute` An attribute fetched via a chain query.
+pub fn decode_group_id(attribute: &Attribute) -> Result<u64, String> {
+    let attribute_type = attribute.attribute_type();
+    if !matches!(attribute_type, AttributeType::Int | AttributeType::String) {
+        return format!(
+            "attribute type [{}] cannot hold a group id",
+            attribute_type.as_str_name(),
+        )
+        .to_err();
+    }
+    if let Ok(group_id) = from_json::<u64>(&attribute.value) {
+        return group_id.to_ok();
+    }
+    let text = std::str::from_utf8(&attribute.value)
+        .map_err(|_| "value is not valid utf-8".to_string())?
+        .trim();
+    if text.starts_with(GROUP_POLICY_VALUE_PREFIX) {
+        return "value records a group policy approval, not a group id"
+            .to_string()
+            .to_err();
+    }
+    let unquoted = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+        .trim();
+    unquoted
+        .parse::<u64>()
+        .map_err(|_| format!("value [{text}] is not an unsigned integer"))
+}
+
+/// Produces the attribute value bytes that denote consent to the given group policy account.
+/// The value is the policy address prefixed by [GROUP_POLICY_VALUE_PREFIX], and should be written
+/// with the [String](AttributeType::String) value type.
+///
+/// # Parameters
+///
+/// * `policy_address` The bech32 address of the group policy account.
+pub fn group_policy_attribute_value<S: Into<String>>(policy_address: S) -> Vec<u8> {
+    format!("{GROUP_POLICY_VALUE_PREFIX}{}", policy_address.into()).into_bytes()
+}
+
+/// Parses a group policy address from a single [Provenance Blockchain Attribute](https://docs.provenance.io/modules/account),
+/// returning `None` unless the attribute matches the given name, has an assigned string value, and
+/// is prefixed with [GROUP_POLICY_VALUE_PREFIX].
+///
+/// # Parameters
+///
+/// * `attribute` An attribute fetched via a chain query.
+/// * `name` A [Provenance Blockchain Name Module](https://docs.provenance.io/modules/name-module)
+///   name used to write the attribute.
+pub fn parse_group_policy_attribute(attribute: &Attribute, name: &str) -> Option<String> {
+    if attribute.name != name || attribute.attribute_type() != AttributeType::String {
+        return None;
+    }
+    std::str::from_utf8(&attribute.value)
+        .ok()
+        .and_then(|value| value.strip_prefix(GROUP_POLICY_VALUE_PREFIX))
+        .map(|address| address.to_string())
 }
+
 /// Generates a [name bind msg](MsgBindNameRequest) that will properly assign the given name value
-/// to a target address.  Assumes the parent name is unrestricted or that the contract has access to
-/// bind a name to the parent name.
+/// to a target address.  The name is validated as a [ProvenanceName] before use.  Assumes the
+/// parent name is unrestricted or that the contract has access to bind a name to the parent name.
 ///
 /// # Parameters
 /// * `name` The dot-qualified name to use on-chain for name binding. Ex: myname.sc.pb will generate
-/// a msg that binds "myname" to the existing parent name "sc.pb".
+///   a msg that binds "myname" to the existing parent name "sc.pb".
 /// * `bind_to_address` The bech32 address to which the name will be bound.
 /// * `restricted` If true, the name will be bound as a restricted name, preventing future name
-/// bindings from using it as a parent name.
+///   bindings from using it as a parent name.
 pub fn msg_bind_name<S1: Into<String>, S2: Into<String>>(
     name: S1,
     bind_to_address: S2,
     restricted: bool,
 ) -> Result<MsgBindNameRequest, ContractError> {
-    let fully_qualified_name = name.into();
-    let mut name_parts = fully_qualified_name.split('.').collect::<Vec<&str>>();
+    let name = ProvenanceName::parse(name)?;
     let bind_address = bind_to_address.into();
-    let bind_record = if let Some(bind) = name_parts.to_owned().first() {
-        if bind.is_empty() {
-            return ContractError::InvalidFormatError {
-                message: format!(
-                    "cannot bind to an empty name string [{}]",
-                    fully_qualified_name
-                ),
-            }
-            .to_err();
-        }
-        Some(NameRecord {
-            name: bind.to_string(),
-            address: bind_address.to_owned(),
-            restricted,
-        })
-    } else {
-        return ContractError::InvalidFormatError {
-            message: format!(
-                "cannot derive bind name from input [{}]",
-                fully_qualified_name
-            ),
-        }
-        .to_err();
-    };
-    let parent_record = if name_parts.len() > 1 {
-        // Trim the first element, because that is the new name to be bound
-        name_parts.remove(0);
-        let parent_name = name_parts.join(".").to_string();
-        Some(NameRecord {
-            name: parent_name.to_owned(),
-            // The parent record must also use the address being bound to as its address in order for
-            // the bind to succeed.  This is the only way in which Provenance accepts a non-restricted
-            // name bind
-            address: bind_address,
-            restricted: false,
-        })
-    } else {
-        None
-    };
+    let bind_record = Some(NameRecord {
+        name: name.leaf().to_string(),
+        address: bind_address.to_owned(),
+        restricted,
+    });
+    let parent_record = name.parent().map(|parent_name| NameRecord {
+        name: parent_name,
+        // The parent record must also use the address being bound to as its address in order for
+        // the bind to succeed.  This is the only way in which Provenance accepts a non-restricted
+        // name bind
+        address: bind_address,
+        restricted: false,
+    });
     MsgBindNameRequest {
         record: bind_record,
         parent: parent_record,
@@ -110,61 +163,334 @@ pub fn msg_bind_name<S1: Into<String>, S2: Into<String>>(
     .to_ok()
 }
 
-fn build_page_request(key: Vec<u8>) -> Option<PageRequest> {
-    Some(PageRequest {
-        key: key,
-        offset: 0,
-        limit: 100,
-        count_total: false,
-        reverse: false,
-    })
+/// The number of attributes requested per page by an [AttributePaginator] unless configured
+/// otherwise.
+pub const DEFAULT_ATTRIBUTE_PAGE_SIZE: u64 = 100;
+
+/// The maximum number of pages an [AttributePaginator] fetches unless configured otherwise.  This
+/// bounds the gas spent on a single account's attributes.
+pub const DEFAULT_MAX_ATTRIBUTE_PAGES: u32 = 10;
+
+/// Lazily pages through an account's [Provenance Blockchain Attributes](https://docs.provenance.io/modules/account),
+/// yielding one attribute at a time and only querying the next page once the current page has been
+/// consumed.  Callers that stop iterating early, for instance as soon as a matching group id is
+/// found, never pay for the remaining pages.  When more than the configured maximum number of pages
+/// would be required, the paginator yields an [AttributePageLimitError](ContractError::AttributePageLimitError)
+/// and stops.
+pub struct AttributePaginator<'a, Q: CustomQuery> {
+    querier: AttributeQuerier<'a, Q>,
+    account: String,
+    name: Option<String>,
+    page_size: u64,
+    max_pages: u32,
+    pages_fetched: u32,
+    next_key: Option<Vec<u8>>,
+    current_page: std::vec::IntoIter<Attribute>,
+    finished: bool,
 }
 
-/// Fetches all attributes for an address, handling paging as appropriate
+impl<'a, Q: CustomQuery> AttributePaginator<'a, Q> {
+    /// Pages through every attribute on the account, regardless of name.
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+    /// * `account` The bech32 address of the account to fetch attributes on.
+    pub fn all<S: Into<String>>(querier: AttributeQuerier<'a, Q>, account: S) -> Self {
+        Self {
+            querier,
+            account: account.into(),
+            name: None,
+            page_size: DEFAULT_ATTRIBUTE_PAGE_SIZE,
+            max_pages: DEFAULT_MAX_ATTRIBUTE_PAGES,
+            pages_fetched: 0,
+            next_key: None,
+            current_page: vec![].into_iter(),
+            finished: false,
+        }
+    }
+
+    /// Pages through only the attributes with the given name.  The attribute module filters by
+    /// name, so the number of queries made does not grow with the account's unrelated attributes.
+    ///
+    /// # Parameters
+    ///
+    /// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+    /// * `account` The bech32 address of the account to fetch attributes on.
+    /// * `name` The attribute name to fetch values for.
+    pub fn by_name<S1: Into<String>, S2: Into<String>>(
+        querier: AttributeQuerier<'a, Q>,
+        account: S1,
+        name: S2,
+    ) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::all(querier, account)
+        }
+    }
+
+    /// Sets the number of attributes requested per page.
+    pub fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets the maximum number of pages that may be fetched before iteration fails.
+    pub fn with_max_pages(mut self, max_pages: u32) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// The number of pages queried from the chain so far.
+    pub fn pages_fetched(&self) -> u32 {
+        self.pages_fetched
+    }
+
+    /// Determines whether any attribute satisfies the predicate, stopping at the first match
+    /// without fetching any further pages.  Query failures and an exceeded page bound are returned
+    /// as errors.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` A check run against each attribute in order.
+    pub fn any_attribute<F: FnMut(&Attribute) -> bool>(
+        &mut self,
+        mut predicate: F,
+    ) -> Result<bool, ContractError> {
+        for attribute in self {
+            if predicate(&attribute?) {
+                return true.to_ok();
+            }
+        }
+        false.to_ok()
+    }
+
+    fn fetch_next_page(&mut self) -> Result<(), ContractError> {
+        let pagination = Some(PageRequest {
+            key: self.next_key.take().unwrap_or_default(),
+            offset: 0,
+            limit: self.page_size,
+            count_total: false,
+            reverse: false,
+        });
+        let (attributes, page_response) = match &self.name {
+            Some(name) => {
+                let response =
+                    self.querier
+                        .attribute(self.account.to_owned(), name.to_owned(), pagination)?;
+                (response.attributes, response.pagination)
+            }
+            None => {
+                let response = self
+                    .querier
+                    .attributes(self.account.to_owned(), pagination)?;
+                (response.attributes, response.pagination)
+            }
+        };
+        self.pages_fetched += 1;
+        self.current_page = attributes.into_iter();
+        self.next_key = page_response
+            .and_then(|page| page.next_key)
+            .filter(|key| !key.is_empty());
+        self.finished = self.next_key.is_none();
+        ().to_ok()
+    }
+}
+
+impl<Q: CustomQuery> Iterator for AttributePaginator<'_, Q> {
+    type Item = Result<Attribute, ContractError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(attribute) = self.current_page.next() {
+                return Some(attribute.to_ok());
+            }
+            if self.finished {
+                return None;
+            }
+            if self.pages_fetched >= self.max_pages {
+                self.finished = true;
+                return Some(
+                    ContractError::AttributePageLimitError {
+                        account: self.account.to_owned(),
+                        max_pages: self.max_pages,
+                    }
+                    .to_err(),
+                );
+            }
+            if let Err(e) = self.fetch_next_page() {
+                self.finished = true;
+                return Some(e.to_err());
+            }
+        }
+    }
+}
+
+/// Determines whether any attribute with the given name on the account satisfies the predicate,
+/// paging lazily through the account's attributes with an [AttributePaginator] and stopping at the
+/// first match.  An exceeded page bound is always returned as an error because the check could not
+/// be completed.  A failed chain query is wrapped in an [AttributeQueryError](ContractError::AttributeQueryError)
+/// in [strict](AttributeQueryMode::Strict) mode, and treated as the account having no matching
+/// attributes in [lenient](AttributeQueryMode::Lenient) mode.
 ///
 /// # Parameters
-/// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes
-/// * `address` The address to fetch attributes on
-pub fn get_all_attributes<Q: CustomQuery, S1: Into<String> + Copy>(
+///
+/// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+/// * `account` The bech32 address of the account to check.
+/// * `name` The attribute name to check values for.
+/// * `mode` Determines how a failed chain query is handled.
+/// * `predicate` A check run against each attribute with the given name.
+pub fn account_has_matching_attribute<Q: CustomQuery, F: FnMut(&Attribute) -> bool>(
     querier: AttributeQuerier<Q>,
-    address: S1,
-) -> Result<Vec<QueryAttributesResponse>, ContractError> {
-    let mut results = vec![];
-    let mut pagination = build_page_request(vec![]);
-    loop {
-        let res = querier.attributes(address.into(), pagination.clone())?;
-        results.push(res.clone());
-        match res.pagination {
-            Some(PageResponse {
-                next_key: Some(..), ..
-            }) => pagination = build_page_request(res.pagination.unwrap().next_key.unwrap()),
-            Some(PageResponse { next_key: None, .. }) => break,
-            None => break,
-        }
+    account: &str,
+    name: &str,
+    mode: AttributeQueryMode,
+    predicate: F,
+) -> Result<bool, ContractError> {
+    check_matching_attribute(querier, account, name, mode, predicate)
+        .map(|found| found.unwrap_or_default())
+}
+
+/// Performs the same check as [account_has_matching_attribute], but produces `None` instead of
+/// `false` when a failed chain query is tolerated in [lenient](AttributeQueryMode::Lenient) mode,
+/// so that callers can distinguish an account without a matching attribute from one whose
+/// attributes could not be fetched.
+///
+/// # Parameters
+///
+/// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+/// * `account` The bech32 address of the account to check.
+/// * `name` The attribute name to check values for.
+/// * `mode` Determines how a failed chain query is handled.
+/// * `predicate` A check run against each attribute with the given name.
+pub fn check_matching_attribute<Q: CustomQuery, F: FnMut(&Attribute) -> bool>(
+    querier: AttributeQuerier<Q>,
+    account: &str,
+    name: &str,
+    mode: AttributeQueryMode,
+    predicate: F,
+) -> Result<Option<bool>, ContractError> {
+    match AttributePaginator::by_name(querier, account, name).any_attribute(predicate) {
+        Ok(found) => Some(found).to_ok(),
+        Err(e) => handle_attribute_query_error(e, account, mode, None),
     }
+}
 
-    Ok(results)
+/// Builds a [delete msg](MsgDeleteDistinctAttributeRequest) for the first attribute with the given
+/// name on the account that satisfies the predicate, or produces `None` if no attribute matches.
+/// The message carries the matched attribute's exact stored bytes, so that approvals written with
+/// a legacy encoding are deleted as well.  Failures are handled in the same manner as
+/// [account_has_matching_attribute].
+///
+/// # Parameters
+///
+/// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+/// * `account` The bech32 address of the account from which the attribute is deleted.
+/// * `name` The attribute name to check values for.
+/// * `mode` Determines how a failed chain query is handled.
+/// * `owner` The bech32 address of the attribute name's owner, which must sign the deletion.
+/// * `predicate` A check run against each attribute with the given name.
+pub fn msg_delete_matching_attribute<Q: CustomQuery, F: FnMut(&Attribute) -> bool>(
+    querier: AttributeQuerier<Q>,
+    account: &str,
+    name: &str,
+    mode: AttributeQueryMode,
+    owner: &str,
+    mut predicate: F,
+) -> Result<Option<MsgDeleteDistinctAttributeRequest>, ContractError> {
+    let mut matched_value = None;
+    account_has_matching_attribute(querier, account, name, mode, |attribute| {
+        let matches = predicate(attribute);
+        if matches {
+            matched_value = Some(attribute.value.to_owned());
+        }
+        matches
+    })?;
+    matched_value
+        .map(|value| MsgDeleteDistinctAttributeRequest {
+            name: name.to_string(),
+            value,
+            account: account.to_string(),
+            owner: owner.to_string(),
+        })
+        .to_ok()
+}
+
+/// Collects every group id approved by the account, paging through its attributes with the given
+/// name with an [AttributePaginator].  Values that cannot be decoded as group ids, including group
+/// policy approvals, are skipped.  Failures are handled in the same manner as
+/// [account_has_matching_attribute], with a failed chain query in [lenient](AttributeQueryMode::Lenient)
+/// mode producing an empty set.
+///
+/// # Parameters
+///
+/// * `querier` The Provenance Blockchain AttributeQuerier to use for fetching pages of attributes.
+/// * `account` The bech32 address of the account to check.
+/// * `name` The attribute name under which group ids are written.
+/// * `mode` Determines how a failed chain query is handled.
+pub fn get_approved_group_ids<Q: CustomQuery>(
+    querier: AttributeQuerier<Q>,
+    account: &str,
+    name: &str,
+    mode: AttributeQueryMode,
+) -> Result<BTreeSet<Uint64>, ContractError> {
+    AttributePaginator::by_name(querier, account, name)
+        .filter_map(|result| {
+            result
+                .map(|attribute| parse_group_id_attribute(&attribute, name))
+                .transpose()
+        })
+        .collect::<Result<BTreeSet<Uint64>, ContractError>>()
+        .or_else(|e| handle_attribute_query_error(e, account, mode, BTreeSet::new()))
+}
+
+fn handle_attribute_query_error<T>(
+    error: ContractError,
+    account: &str,
+    mode: AttributeQueryMode,
+    lenient_value: T,
+) -> Result<T, ContractError> {
+    match error {
+        e @ ContractError::AttributePageLimitError { .. } => e.to_err(),
+        e => match mode {
+            AttributeQueryMode::Strict => ContractError::AttributeQueryError {
+                account: account.to_string(),
+                message: e.to_string(),
+            }
+            .to_err(),
+            AttributeQueryMode::Lenient => lenient_value.to_ok(),
+        },
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::store::contract_state::AttributeQueryMode;
     use crate::{
         test::test_constants::{DEFAULT_CONTRACT_ATTRIBUTE, DEFAULT_GROUP_MEMBER},
         types::core::error::ContractError,
-        util::prov_helpers::{get_group_id_attribute_values, msg_bind_name},
+        util::prov_helpers::{
+            check_matching_attribute, decode_group_id, get_approved_group_ids,
+            get_group_id_attribute_values, group_policy_attribute_value, msg_bind_name,
+            parse_group_id_attribute, parse_group_policy_attribute, AttributePaginator,
+        },
+    };
+    use cosmwasm_std::{
+        from_json, to_json_vec, Binary, ContractResult, Empty, QuerierWrapper, SystemResult, Uint64,
     };
-    use cosmwasm_std::{from_json, to_json_vec, Binary, ContractResult, SystemResult};
     use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
     use provwasm_std::types::{
         cosmos::base::query::v1beta1::{PageRequest, PageResponse},
         provenance::attribute::v1::{
-            Attribute, AttributeQuerier, AttributeType, QueryAttributesRequest,
-            QueryAttributesResponse,
+            Attribute, AttributeQuerier, AttributeType, QueryAttributeRequest,
+            QueryAttributeResponse, QueryAttributesRequest, QueryAttributesResponse,
         },
         tendermint::abci::ResponseQuery,
     };
 
-    use super::get_all_attributes;
+    use crate::test::chain_simulator::{
+        ProvenanceSimulator, ATTRIBUTES_QUERY_PATH, ATTRIBUTE_QUERY_PATH,
+    };
 
     /// Helper function to handle returning mocked responses with pagination, where the key is a json-encoded string of the next page index
     fn mock_paged_attributes(
@@ -179,17 +505,16 @@ mod tests {
                 let idx = match request.pagination {
                     None => 0,
                     Some(PageRequest { key, .. }) => {
-                        if key.len() == 0 {
+                        if key.is_empty() {
                             0
                         } else {
                             from_json(key).unwrap_or(0)
                         }
                     }
                 };
-                let page = pages.get(idx).expect(&format!(
-                    "Expected to be able to fetch page {} of attributes",
-                    idx
-                ));
+                let page = pages.get(idx).unwrap_or_else(|| {
+                    panic!("Expected to be able to fetch page {} of attributes", idx)
+                });
                 let response = QueryAttributesResponse {
                     account: account.into(),
                     attributes: page.to_vec(),
@@ -224,7 +549,7 @@ mod tests {
     }
 
     #[test]
-    fn get_all_attributes_paginates_properly() {
+    fn attribute_paginator_pages_through_all_attributes() {
         let mut deps = mock_provenance_dependencies();
         let address = "someaddress";
         mock_paged_attributes(
@@ -247,25 +572,198 @@ mod tests {
                 }],
             ],
         );
-        let all_attributes =
-            get_all_attributes(AttributeQuerier::new(&deps.as_mut().querier), address)
-                .expect("expected get_all_attributes to successfully respond");
-        assert_eq!(
-            2,
-            all_attributes.len(),
-            "expected two pages for all attributes"
-        );
-        let mut attribute_values = all_attributes
-            .iter()
-            .flat_map(|attrs| attrs.attributes.to_owned())
-            .map(|attr| from_json::<u64>(attr.value).expect("Failed to parse value to integer"))
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        let mut paginator = AttributePaginator::all(AttributeQuerier::new(&querier), address);
+        let mut attribute_values = paginator
+            .by_ref()
+            .map(|attr| {
+                from_json::<u64>(attr.expect("expected each page to be fetched").value)
+                    .expect("Failed to parse value to integer")
+            })
             .collect::<Vec<_>>();
         attribute_values.sort();
         assert_eq!(
             vec![1u64, 2u64],
             attribute_values,
             "Expected all attributes to be present"
+        );
+        assert_eq!(
+            2,
+            paginator.pages_fetched(),
+            "expected two pages for all attributes"
+        );
+    }
+
+    #[test]
+    fn attribute_paginator_uses_configured_page_size() {
+        let simulator = simulator_with_group_ids(25);
+        let querier = QuerierWrapper::<Empty>::new(&simulator.deps.querier);
+        let mut paginator = AttributePaginator::by_name(
+            AttributeQuerier::new(&querier),
+            DEFAULT_GROUP_MEMBER,
+            DEFAULT_CONTRACT_ATTRIBUTE,
         )
+        .with_page_size(10);
+        assert_eq!(
+            (0..25).collect::<Vec<u64>>(),
+            paginator
+                .by_ref()
+                .map(|attr| {
+                    parse_group_id_attribute(
+                        &attr.expect("expected each page to be fetched"),
+                        DEFAULT_CONTRACT_ATTRIBUTE,
+                    )
+                    .expect("expected each attribute to hold a group id")
+                    .u64()
+                })
+                .collect::<Vec<u64>>(),
+            "expected every attribute value to be fetched across pages",
+        );
+        assert_eq!(
+            3,
+            paginator.pages_fetched(),
+            "expected three pages of at most 10 attributes",
+        );
+    }
+
+    #[test]
+    fn attribute_paginator_stops_at_first_match() {
+        let simulator = simulator_with_group_ids(250);
+        let querier = QuerierWrapper::<Empty>::new(&simulator.deps.querier);
+        let mut paginator = AttributePaginator::by_name(
+            AttributeQuerier::new(&querier),
+            DEFAULT_GROUP_MEMBER,
+            DEFAULT_CONTRACT_ATTRIBUTE,
+        );
+        assert!(
+            paginator
+                .any_attribute(
+                    |attr| parse_group_id_attribute(attr, DEFAULT_CONTRACT_ATTRIBUTE)
+                        == Some(Uint64::new(42))
+                )
+                .expect("the search should succeed"),
+            "the group id on the first page should be found",
+        );
+        assert_eq!(
+            1,
+            paginator.pages_fetched(),
+            "no pages beyond the match should be fetched",
+        );
+        assert_eq!(
+            1,
+            simulator.query_count(ATTRIBUTE_QUERY_PATH),
+            "a single query should be made when the first page holds the match",
+        );
+    }
+
+    #[test]
+    fn attribute_paginator_errors_when_page_bound_is_exceeded() {
+        let simulator = simulator_with_group_ids(250);
+        let querier = QuerierWrapper::<Empty>::new(&simulator.deps.querier);
+        let mut paginator = AttributePaginator::by_name(
+            AttributeQuerier::new(&querier),
+            DEFAULT_GROUP_MEMBER,
+            DEFAULT_CONTRACT_ATTRIBUTE,
+        )
+        .with_max_pages(2);
+        match paginator
+            .any_attribute(|_| false)
+            .expect_err("an error should occur when more than two pages are required")
+        {
+            ContractError::AttributePageLimitError { account, max_pages } => {
+                assert_eq!(
+                    DEFAULT_GROUP_MEMBER, account,
+                    "the account should be reported",
+                );
+                assert_eq!(2, max_pages, "the page bound should be reported");
+            }
+            e => panic!("unexpected error: {e:?}"),
+        };
+        assert!(
+            paginator.next().is_none(),
+            "the paginator should stop after reporting the exceeded bound",
+        );
+        assert_eq!(
+            2,
+            simulator.query_count(ATTRIBUTE_QUERY_PATH),
+            "no page beyond the bound should be queried",
+        );
+        let mut exact_paginator = AttributePaginator::by_name(
+            AttributeQuerier::new(&querier),
+            DEFAULT_GROUP_MEMBER,
+            DEFAULT_CONTRACT_ATTRIBUTE,
+        )
+        .with_max_pages(3);
+        assert!(
+            !exact_paginator
+                .any_attribute(|_| false)
+                .expect("exactly reaching the page bound should not be an error"),
+            "no attribute should match",
+        );
+    }
+
+    #[test]
+    fn attribute_paginator_by_name_query_count_ignores_unrelated_attributes() {
+        let mut simulator = ProvenanceSimulator::new();
+        for index in 0..1000u64 {
+            simulator = simulator.with_attribute(Attribute {
+                name: format!("unrelated{index}.pb"),
+                value: to_json_vec(&index).unwrap(),
+                attribute_type: AttributeType::Int.into(),
+                address: DEFAULT_GROUP_MEMBER.into(),
+                expiration_date: None,
+            });
+        }
+        simulator = simulator.with_attribute(Attribute {
+            name: DEFAULT_CONTRACT_ATTRIBUTE.into(),
+            value: to_json_vec(&1u64).unwrap(),
+            attribute_type: AttributeType::Int.into(),
+            address: DEFAULT_GROUP_MEMBER.into(),
+            expiration_date: None,
+        });
+        let querier = QuerierWrapper::<Empty>::new(&simulator.deps.querier);
+        let scanned_group_ids =
+            AttributePaginator::all(AttributeQuerier::new(&querier), DEFAULT_GROUP_MEMBER)
+                .with_max_pages(20)
+                .map(|attr| attr.expect("expected every page to be fetched"))
+                .filter_map(|attr| parse_group_id_attribute(&attr, DEFAULT_CONTRACT_ATTRIBUTE))
+                .collect::<Vec<_>>();
+        let named_group_ids = AttributePaginator::by_name(
+            AttributeQuerier::new(&querier),
+            DEFAULT_GROUP_MEMBER,
+            DEFAULT_CONTRACT_ATTRIBUTE,
+        )
+        .map(|attr| attr.expect("expected every page to be fetched"))
+        .filter_map(|attr| parse_group_id_attribute(&attr, DEFAULT_CONTRACT_ATTRIBUTE))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            11,
+            simulator.query_count(ATTRIBUTES_QUERY_PATH),
+            "scanning every attribute should take a query per page of 100 attributes",
+        );
+        assert_eq!(
+            1,
+            simulator.query_count(ATTRIBUTE_QUERY_PATH),
+            "the by-name lookup should take a single query regardless of unrelated attributes",
+        );
+        assert_eq!(
+            scanned_group_ids, named_group_ids,
+            "both lookups should find the same group ids",
+        );
+    }
+
+    fn simulator_with_group_ids(count: u64) -> ProvenanceSimulator {
+        let mut simulator = ProvenanceSimulator::new();
+        for group_id in 0..count {
+            simulator = simulator.with_attribute(Attribute {
+                name: DEFAULT_CONTRACT_ATTRIBUTE.into(),
+                value: to_json_vec(&group_id).unwrap(),
+                attribute_type: AttributeType::Int.into(),
+                address: DEFAULT_GROUP_MEMBER.into(),
+                expiration_date: None,
+            });
+        }
+        simulator
     }
 
     #[test]
@@ -385,11 +883,11 @@ mod tests {
                     address: "something".to_string(),
                     expiration_date: None,
                 },
-                // Mismatched because the value type qualifier is set incorrectly
+                // Mismatched because the value type qualifier cannot hold a group id
                 Attribute {
                     name: "targetname".to_string(),
                     value: get_json_vector_int(11),
-                    attribute_type: AttributeType::String.into(),
+                    attribute_type: AttributeType::Bytes.into(),
                     address: "something".to_string(),
                     expiration_date: None,
                 },
@@ -463,6 +961,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_group_id_accepts_legacy_encodings() {
+        let attribute = |value: &[u8], attribute_type: AttributeType| Attribute {
+            name: "targetname".to_string(),
+            value: value.to_vec(),
+            attribute_type: attribute_type.into(),
+            address: "something".to_string(),
+            expiration_date: None,
+        };
+        assert_eq!(
+            Ok(7),
+            decode_group_id(&attribute(b"7", AttributeType::Int)),
+            "json integers should be decoded",
+        );
+        assert_eq!(
+            Ok(8),
+            decode_group_id(&attribute(b"\"8\"", AttributeType::Int)),
+            "quoted-string integers should be decoded",
+        );
+        assert_eq!(
+            Ok(9),
+            decode_group_id(&attribute(b" 9 ", AttributeType::String)),
+            "string-typed values holding an integer should be decoded",
+        );
+        assert_eq!(
+            Ok(10),
+            decode_group_id(&attribute(b"\"10\"", AttributeType::String)),
+            "string-typed values holding a quoted integer should be decoded",
+        );
+        assert!(
+            decode_group_id(&attribute(b"-1", AttributeType::Int)).is_err(),
+            "negative values should not be decoded",
+        );
+        assert!(
+            decode_group_id(&attribute(b"ten", AttributeType::String)).is_err(),
+            "non-numeric values should not be decoded",
+        );
+        assert!(
+            decode_group_id(&attribute(&[0xff, 0xfe], AttributeType::String)).is_err(),
+            "values that are not utf-8 should not be decoded",
+        );
+        assert!(
+            decode_group_id(&attribute(
+                &group_policy_attribute_value("12"),
+                AttributeType::String
+            ))
+            .is_err(),
+            "group policy values should not be decoded as group ids",
+        );
+        assert_eq!(
+            Err("attribute type [ATTRIBUTE_TYPE_BYTES] cannot hold a group id".to_string()),
+            decode_group_id(&attribute(b"11", AttributeType::Bytes)),
+            "unsupported attribute types should be described",
+        );
+    }
+
+    #[test]
+    fn test_group_policy_values_are_distinguishable_from_group_ids() {
+        let attributes = [
+            Attribute {
+                name: "targetname".to_string(),
+                value: get_json_vector_int(7),
+                attribute_type: AttributeType::Int.into(),
+                address: "something".to_string(),
+                expiration_date: None,
+            },
+            Attribute {
+                name: "targetname".to_string(),
+                value: group_policy_attribute_value("policy"),
+                attribute_type: AttributeType::String.into(),
+                address: "something".to_string(),
+                expiration_date: None,
+            },
+            // Mismatch because the string value does not use the group policy prefix
+            Attribute {
+                name: "targetname".to_string(),
+                value: b"policy".to_vec(),
+                attribute_type: AttributeType::String.into(),
+                address: "something".to_string(),
+                expiration_date: None,
+            },
+            // Mismatch on name
+            Attribute {
+                name: "othername".to_string(),
+                value: group_policy_attribute_value("other"),
+                attribute_type: AttributeType::String.into(),
+                address: "something".to_string(),
+                expiration_date: None,
+            },
+        ];
+        assert_eq!(
+            vec!["policy".to_string()],
+            attributes
+                .iter()
+                .filter_map(|attr| parse_group_policy_attribute(attr, "targetname"))
+                .collect::<Vec<String>>(),
+            "only prefixed string values for the target name should be derived as policies",
+        );
+        assert_eq!(
+            vec![7],
+            attributes
+                .iter()
+                .filter_map(|attr| parse_group_id_attribute(attr, "targetname"))
+                .map(|id| id.u64())
+                .collect::<Vec<u64>>(),
+            "group policy values should never be derived as group ids",
+        );
+    }
+
     #[test]
     fn msg_bind_name_creates_proper_binding_with_fully_qualified_name() {
         let name = "test.name.bro";
@@ -524,33 +1131,128 @@ mod tests {
 
     #[test]
     fn msg_bind_name_should_properly_guard_against_bad_input() {
-        let _expected_error_message = "cannot derive bind name from input []".to_string();
-        assert!(
-            matches!(
-                msg_bind_name("", "address", true)
-                    .expect_err("an error should occur when no name is specified"),
-                ContractError::InvalidFormatError {
-                    message: _expected_error_message,
-                },
+        for (name, expected_error_message) in [
+            ("", "invalid provenance name []: name must not be empty"),
+            (
+                ".suffix",
+                "invalid provenance name [.suffix]: name must not contain empty segments",
+            ),
+            (
+                "prefix.",
+                "invalid provenance name [prefix.]: name must not contain empty segments",
+            ),
+            (
+                "Upper.pb",
+                "invalid provenance name [Upper.pb]: segment [Upper] contains invalid character [U]",
             ),
-            "unexpected error message when specifying an empty name",
+        ] {
+            match msg_bind_name(name, "address", true)
+                .expect_err("an error should occur when specifying a malformed name")
+            {
+                ContractError::InvalidFormatError { message } => assert_eq!(
+                    expected_error_message, message,
+                    "unexpected error message when specifying malformed name [{name}]",
+                ),
+                e => panic!("unexpected error emitted: {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn get_approved_group_ids_skips_undecodable_values() {
+        let mut deps = mock_provenance_dependencies();
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: DEFAULT_GROUP_MEMBER.to_string(),
+                attributes: [
+                    (get_json_vector_int(3), AttributeType::Int),
+                    (
+                        group_policy_attribute_value("tp1policy"),
+                        AttributeType::String,
+                    ),
+                    (get_json_vector_int(1), AttributeType::Int),
+                ]
+                .into_iter()
+                .map(|(value, attribute_type)| Attribute {
+                    name: DEFAULT_CONTRACT_ATTRIBUTE.to_string(),
+                    value,
+                    attribute_type: attribute_type.into(),
+                    address: DEFAULT_GROUP_MEMBER.to_string(),
+                    expiration_date: None,
+                })
+                .collect(),
+                pagination: None,
+            },
+        );
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        assert_eq!(
+            vec![Uint64::new(1), Uint64::new(3)],
+            get_approved_group_ids(
+                AttributeQuerier::new(&querier),
+                DEFAULT_GROUP_MEMBER,
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                AttributeQueryMode::Strict,
+            )
+            .expect("the group ids should be collected")
+            .into_iter()
+            .collect::<Vec<_>>(),
+            "only the decodable group ids should be collected",
         );
-        let _expected_error_message = "cannot bind to an empty name string [.suffix]".to_string();
+    }
+
+    #[test]
+    fn check_matching_attribute_reports_unknown_in_lenient_mode() {
+        let mut deps = mock_provenance_dependencies();
+        QueryAttributeRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+        assert_eq!(
+            None,
+            check_matching_attribute(
+                AttributeQuerier::new(&querier),
+                DEFAULT_GROUP_MEMBER,
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                AttributeQueryMode::Lenient,
+                |_| true,
+            )
+            .expect("a failed query should be tolerated in lenient mode"),
+            "a failed query should be distinguishable from a missing attribute",
+        );
+    }
+
+    #[test]
+    fn get_approved_group_ids_respects_query_mode() {
+        let mut deps = mock_provenance_dependencies();
+        QueryAttributeRequest::mock_failed_response(&mut deps.querier, "oops".to_string());
+        let querier = QuerierWrapper::<Empty>::new(&deps.querier);
         assert!(
             matches!(
-                msg_bind_name(".suffix", "address", true)
-                    .expect_err("an error should occur when specifying a malformed name"),
-                ContractError::InvalidFormatError {
-                    message: _expected_error_message,
-                },
+                get_approved_group_ids(
+                    AttributeQuerier::new(&querier),
+                    DEFAULT_GROUP_MEMBER,
+                    DEFAULT_CONTRACT_ATTRIBUTE,
+                    AttributeQueryMode::Strict,
+                ),
+                Err(ContractError::AttributeQueryError { .. })
             ),
-            "unexpected error message when specifying a malformed name",
+            "a failed query should be an error in strict mode",
+        );
+        assert!(
+            get_approved_group_ids(
+                AttributeQuerier::new(&querier),
+                DEFAULT_GROUP_MEMBER,
+                DEFAULT_CONTRACT_ATTRIBUTE,
+                AttributeQueryMode::Lenient,
+            )
+            .expect("a failed query should be tolerated in lenient mode")
+            .is_empty(),
+            "a failed query should produce no group ids in lenient mode",
         );
     }
 
     fn get_json_vector_int(value: u64) -> Vec<u8> {
-        to_json_vec(&value).expect(&format!(
-            "Expected value [{value}] to be properly converted to binary",
-        ))
+        to_json_vec(&value).unwrap_or_else(|_| {
+            panic!("Expected value [{value}] to be properly converted to binary")
+        })
     }
 }
@@ -0,0 +1,296 @@
+use crate::types::core::error::ContractError;
+use result_extensions::ResultExtensions;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The minimum number of characters allowed in a single name segment.  Mirrors the default
+/// `min_segment_length` parameter of the [Provenance Name Module](https://docs.provenance.io/modules/name-module).
+pub const MIN_SEGMENT_LENGTH: usize = 2;
+/// The maximum number of characters allowed in a single name segment.  Mirrors the default
+/// `max_segment_length` parameter of the [Provenance Name Module](https://docs.provenance.io/modules/name-module).
+pub const MAX_SEGMENT_LENGTH: usize = 32;
+/// The maximum number of dot-separated segments allowed in a fully-qualified name.  Mirrors the
+/// default `max_name_levels` parameter of the [Provenance Name Module](https://docs.provenance.io/modules/name-module).
+pub const MAX_NAME_LEVELS: usize = 16;
+
+/// A dot-qualified [Provenance Name Module](https://docs.provenance.io/modules/name-module) name
+/// that has been verified to conform to the name module's rules.  A value of this type can only be
+/// constructed via [ProvenanceName::parse], which guarantees that:
+///
+/// * The name contains between one and [MAX_NAME_LEVELS] segments, separated by periods.
+/// * Each segment is either a lowercase hyphenated UUID, or is between [MIN_SEGMENT_LENGTH] and
+///   [MAX_SEGMENT_LENGTH] characters long, consists only of lowercase ascii letters, digits and
+///   hyphens, and contains at most a single hyphen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProvenanceName {
+    segments: Vec<String>,
+}
+impl ProvenanceName {
+    /// Parses and validates the given dot-qualified name, producing an
+    /// [InvalidFormatError](ContractError::InvalidFormatError) describing the first rule violation
+    /// encountered.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` The fully-qualified name to validate. Ex: myname.sc.pb
+    pub fn parse<S: Into<String>>(name: S) -> Result<Self, ContractError> {
+        let name = name.into();
+        if name.is_empty() {
+            return invalid_name(&name, "name must not be empty");
+        }
+        let segments = name.split('.').collect::<Vec<&str>>();
+        if segments.len() > MAX_NAME_LEVELS {
+            return invalid_name(
+                &name,
+                format!(
+                    "name contains {} segments, but at most {MAX_NAME_LEVELS} are allowed",
+                    segments.len(),
+                ),
+            );
+        }
+        for segment in segments.iter() {
+            validate_segment(&name, segment)?;
+        }
+        Self {
+            segments: segments.iter().map(|segment| segment.to_string()).collect(),
+        }
+        .to_ok()
+    }
+
+    /// Returns the first segment of the name, which is the value bound by a name bind. Ex: the
+    /// leaf of myname.sc.pb is myname.
+    pub fn leaf(&self) -> &str {
+        // Parsing guarantees at least one segment is always present
+        &self.segments[0]
+    }
+
+    /// Returns the fully-qualified name of the parent of this name, if this name has more than a
+    /// single segment. Ex: the parent of myname.sc.pb is sc.pb.
+    pub fn parent(&self) -> Option<String> {
+        if self.segments.len() > 1 {
+            Some(self.segments[1..].join("."))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of dot-separated segments in the name.
+    pub fn levels(&self) -> usize {
+        self.segments.len()
+    }
+}
+impl Display for ProvenanceName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.segments.join("."))
+    }
+}
+impl FromStr for ProvenanceName {
+    type Err = ContractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn validate_segment(name: &str, segment: &str) -> Result<(), ContractError> {
+    if segment.is_empty() {
+        return invalid_name(name, "name must not contain empty segments");
+    }
+    // The name module allows UUIDs as segments regardless of the configured segment lengths
+    if is_uuid(segment) {
+        return ().to_ok();
+    }
+    let length = segment.chars().count();
+    if length < MIN_SEGMENT_LENGTH {
+        return invalid_name(
+            name,
+            format!("segment [{segment}] must be at least {MIN_SEGMENT_LENGTH} characters"),
+        );
+    }
+    if length > MAX_SEGMENT_LENGTH {
+        return invalid_name(
+            name,
+            format!("segment [{segment}] must be at most {MAX_SEGMENT_LENGTH} characters"),
+        );
+    }
+    if let Some(invalid) = segment
+        .chars()
+        .find(|c| !c.is_ascii_lowercase() && !c.is_ascii_digit() && *c != '-')
+    {
+        return invalid_name(
+            name,
+            format!("segment [{segment}] contains invalid character [{invalid}]"),
+        );
+    }
+    if segment.matches('-').count() > 1 {
+        return invalid_name(
+            name,
+            format!("segment [{segment}] must contain at most a single hyphen"),
+        );
+    }
+    ().to_ok()
+}
+
+fn is_uuid(segment: &str) -> bool {
+    let groups = segment.split('-').collect::<Vec<&str>>();
+    groups.len() == 5
+        && groups.iter().zip([8, 4, 4, 4, 12]).all(|(group, length)| {
+            group.len() == length
+                && group
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        })
+}
+
+fn invalid_name<T, S: Into<String>>(name: &str, reason: S) -> Result<T, ContractError> {
+    ContractError::InvalidFormatError {
+        message: format!("invalid provenance name [{name}]: {}", reason.into()),
+    }
+    .to_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::core::error::ContractError;
+    use crate::util::provenance_name::{ProvenanceName, MAX_NAME_LEVELS};
+
+    #[test]
+    fn test_valid_names() {
+        let cases: Vec<(&str, &str, Option<&str>, usize)> = vec![
+            ("pb", "pb", None, 1),
+            ("sc.pb", "sc", Some("pb"), 2),
+            ("groupmember.test.pb", "groupmember", Some("test.pb"), 3),
+            ("my-name.sc.pb", "my-name", Some("sc.pb"), 3),
+            ("name123.pb", "name123", Some("pb"), 2),
+            ("123.pb", "123", Some("pb"), 2),
+            (
+                "abcdefghijklmnopqrstuvwxyz012345.pb",
+                "abcdefghijklmnopqrstuvwxyz012345",
+                Some("pb"),
+                2,
+            ),
+            (
+                "0f8fad5b-d9cb-469f-a165-70867728950e.pb",
+                "0f8fad5b-d9cb-469f-a165-70867728950e",
+                Some("pb"),
+                2,
+            ),
+        ];
+        for (input, leaf, parent, levels) in cases {
+            let name = ProvenanceName::parse(input)
+                .unwrap_or_else(|e| panic!("expected [{input}] to be valid, but got: {e:?}"));
+            assert_eq!(leaf, name.leaf(), "unexpected leaf for [{input}]");
+            assert_eq!(
+                parent.map(|p| p.to_string()),
+                name.parent(),
+                "unexpected parent for [{input}]",
+            );
+            assert_eq!(levels, name.levels(), "unexpected levels for [{input}]");
+            assert_eq!(
+                input,
+                name.to_string(),
+                "the name should display as its original input",
+            );
+        }
+    }
+
+    #[test]
+    fn test_maximum_name_levels_is_allowed() {
+        let input = vec!["ab"; MAX_NAME_LEVELS].join(".");
+        let name = ProvenanceName::parse(&input)
+            .expect("a name with exactly the maximum number of levels should be valid");
+        assert_eq!(MAX_NAME_LEVELS, name.levels(), "unexpected levels");
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        let too_many_levels = vec!["ab"; MAX_NAME_LEVELS + 1].join(".");
+        let cases: Vec<(&str, &str)> = vec![
+            ("", "invalid provenance name []: name must not be empty"),
+            (
+                ".",
+                "invalid provenance name [.]: name must not contain empty segments",
+            ),
+            (
+                ".suffix",
+                "invalid provenance name [.suffix]: name must not contain empty segments",
+            ),
+            (
+                "trailing.",
+                "invalid provenance name [trailing.]: name must not contain empty segments",
+            ),
+            (
+                "a..b",
+                "invalid provenance name [a..b]: segment [a] must be at least 2 characters",
+            ),
+            (
+                "ab..cd",
+                "invalid provenance name [ab..cd]: name must not contain empty segments",
+            ),
+            (
+                "x.pb",
+                "invalid provenance name [x.pb]: segment [x] must be at least 2 characters",
+            ),
+            (
+                "abcdefghijklmnopqrstuvwxyz0123456.pb",
+                "invalid provenance name [abcdefghijklmnopqrstuvwxyz0123456.pb]: segment [abcdefghijklmnopqrstuvwxyz0123456] must be at most 32 characters",
+            ),
+            (
+                "Upper.pb",
+                "invalid provenance name [Upper.pb]: segment [Upper] contains invalid character [U]",
+            ),
+            (
+                "some name.pb",
+                "invalid provenance name [some name.pb]: segment [some name] contains invalid character [ ]",
+            ),
+            (
+                "under_score.pb",
+                "invalid provenance name [under_score.pb]: segment [under_score] contains invalid character [_]",
+            ),
+            (
+                "accent\u{e9}.pb",
+                "invalid provenance name [accent\u{e9}.pb]: segment [accent\u{e9}] contains invalid character [\u{e9}]",
+            ),
+            (
+                "two-hyphen-name.pb",
+                "invalid provenance name [two-hyphen-name.pb]: segment [two-hyphen-name] must contain at most a single hyphen",
+            ),
+            (
+                "0F8FAD5B-D9CB-469F-A165-70867728950E.pb",
+                "invalid provenance name [0F8FAD5B-D9CB-469F-A165-70867728950E.pb]: segment [0F8FAD5B-D9CB-469F-A165-70867728950E] must be at most 32 characters",
+            ),
+        ];
+        for (input, expected_message) in cases {
+            match ProvenanceName::parse(input) {
+                Err(ContractError::InvalidFormatError { message }) => assert_eq!(
+                    expected_message, message,
+                    "unexpected error message for [{input}]",
+                ),
+                result => panic!("expected [{input}] to be rejected, but got: {result:?}"),
+            }
+        }
+        match ProvenanceName::parse(&too_many_levels) {
+            Err(ContractError::InvalidFormatError { message }) => assert_eq!(
+                format!("invalid provenance name [{too_many_levels}]: name contains 17 segments, but at most 16 are allowed"),
+                message,
+                "unexpected error message for a name with too many levels",
+            ),
+            result => panic!("expected too many levels to be rejected, but got: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let name = "myname.sc.pb"
+            .parse::<ProvenanceName>()
+            .expect("a valid name should parse via FromStr");
+        assert_eq!("myname", name.leaf(), "unexpected leaf");
+        assert!(
+            matches!(
+                "Bad".parse::<ProvenanceName>(),
+                Err(ContractError::InvalidFormatError { .. })
+            ),
+            "an invalid name should fail to parse via FromStr",
+        );
+    }
+}
@@ -1,7 +1,41 @@
+use crate::store::contract_state::get_contract_state;
 use crate::types::core::error::ContractError;
-use cosmwasm_std::MessageInfo;
+use cosmwasm_std::{MessageInfo, Storage};
 use result_extensions::ResultExtensions;
 
+/// The maximum number of characters allowed in a memo attached to an approval.
+pub const MAX_MEMO_LENGTH: usize = 128;
+
+/// The maximum number of characters allowed in the reason the contract admin provides when
+/// blocking an account or revoking an approval.
+pub const MAX_REASON_LENGTH: usize = 256;
+
+/// Verifies that the account invoking a route is the contract's admin, as defined in the
+/// [ContractState](crate::store::contract_state::ContractState).
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+///   fetches.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn check_sender_is_admin(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+) -> Result<(), ContractError> {
+    if get_contract_state(storage)?.admin != info.sender {
+        ContractError::UnauthorizedError {
+            message: format!(
+                "account [{}] is not the contract admin",
+                info.sender.as_str()
+            ),
+        }
+        .to_err()
+    } else {
+        ().to_ok()
+    }
+}
+
 /// Verifies that the provided info does not include and funds, ensuring that the account invoking
 /// the contract does not accidentally store funds in the contract that cannot be retrieved due to
 /// lack of tracking.
@@ -9,7 +43,7 @@ use result_extensions::ResultExtensions;
 /// # Parameters
 ///
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 pub fn check_funds_are_empty(info: &MessageInfo) -> Result<(), ContractError> {
     if !info.funds.is_empty() {
         ContractError::InvalidFundsError {
@@ -20,3 +54,165 @@ pub fn check_funds_are_empty(info: &MessageInfo) -> Result<(), ContractError> {
         ().to_ok()
     }
 }
+
+/// Validates a free-form memo provided alongside an approval, such as the role in which the member
+/// consents to the group (ex: "signer").  Surrounding whitespace is removed, and the memo must be
+/// non-blank, contain no control characters, and be no longer than [MAX_MEMO_LENGTH] characters.
+/// Returns the normalized memo.
+///
+/// # Parameters
+///
+/// * `memo` The memo provided by the signing account, if any.
+pub fn validate_memo(memo: Option<String>) -> Result<Option<String>, ContractError> {
+    let Some(memo) = memo else {
+        return None.to_ok();
+    };
+    let memo = memo.trim();
+    let reason = if memo.is_empty() {
+        Some("memo must not be blank".to_string())
+    } else if memo.chars().count() > MAX_MEMO_LENGTH {
+        Some(format!(
+            "memo must be at most {MAX_MEMO_LENGTH} characters long"
+        ))
+    } else if memo.chars().any(char::is_control) {
+        Some("memo must not contain control characters".to_string())
+    } else {
+        None
+    };
+    match reason {
+        Some(message) => ContractError::InvalidFormatError { message }.to_err(),
+        None => Some(memo.to_string()).to_ok(),
+    }
+}
+
+/// Validates the free-form reason the contract admin provides when blocking an account or revoking
+/// an approval.  Surrounding whitespace is removed, and the reason must contain no control
+/// characters and be no longer than [MAX_REASON_LENGTH] characters.  Returns the normalized reason,
+/// which the calling route must still reject if it is blank.
+///
+/// # Parameters
+///
+/// * `reason` The reason provided by the contract admin.
+pub fn validate_reason(reason: String) -> Result<String, ContractError> {
+    let reason = reason.trim();
+    if reason.chars().count() > MAX_REASON_LENGTH {
+        ContractError::InvalidFormatError {
+            message: format!("reason must be at most {MAX_REASON_LENGTH} characters long"),
+        }
+        .to_err()
+    } else if reason.chars().any(char::is_control) {
+        ContractError::InvalidFormatError {
+            message: "reason must not contain control characters".to_string(),
+        }
+        .to_err()
+    } else {
+        reason.to_string().to_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_constants::{DEFAULT_CONTRACT_ADMIN, DEFAULT_GROUP_MEMBER};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::core::error::ContractError;
+    use crate::util::route_helpers::{
+        check_sender_is_admin, validate_memo, validate_reason, MAX_MEMO_LENGTH, MAX_REASON_LENGTH,
+    };
+    use cosmwasm_std::testing::message_info;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_check_sender_is_admin() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(deps.as_mut());
+        check_sender_is_admin(
+            &deps.storage,
+            &message_info(&Addr::unchecked(DEFAULT_CONTRACT_ADMIN), &[]),
+        )
+        .expect("the admin should pass the admin check");
+        assert!(
+            matches!(
+                check_sender_is_admin(
+                    &deps.storage,
+                    &message_info(&Addr::unchecked(DEFAULT_GROUP_MEMBER), &[]),
+                ),
+                Err(ContractError::UnauthorizedError { .. }),
+            ),
+            "a non-admin account should fail the admin check",
+        );
+    }
+
+    #[test]
+    fn test_validate_memo() {
+        assert_eq!(
+            None,
+            validate_memo(None).expect("a missing memo should be valid"),
+            "a missing memo should remain missing",
+        );
+        assert_eq!(
+            Some("signer".to_string()),
+            validate_memo(Some("  signer ".to_string())).expect("a short memo should be valid"),
+            "surrounding whitespace should be removed from the memo",
+        );
+        assert_eq!(
+            Some("é".repeat(MAX_MEMO_LENGTH)),
+            validate_memo(Some("é".repeat(MAX_MEMO_LENGTH)))
+                .expect("a memo at the maximum length should be valid"),
+            "the memo length should be measured in characters",
+        );
+        for (memo, expected_message) in [
+            ("   ", "memo must not be blank".to_string()),
+            (
+                &"a".repeat(MAX_MEMO_LENGTH + 1),
+                format!("memo must be at most {MAX_MEMO_LENGTH} characters long"),
+            ),
+            (
+                "signer\nobserver",
+                "memo must not contain control characters".to_string(),
+            ),
+        ] {
+            match validate_memo(Some(memo.to_string())) {
+                Err(ContractError::InvalidFormatError { message }) => assert_eq!(
+                    expected_message, message,
+                    "unexpected error message for memo [{memo}]",
+                ),
+                result => panic!("unexpected result for memo [{memo}]: {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_reason() {
+        assert_eq!(
+            "sanctions case 12".to_string(),
+            validate_reason("  sanctions case 12 ".to_string())
+                .expect("a short reason should be valid"),
+            "surrounding whitespace should be removed from the reason",
+        );
+        assert_eq!(
+            "é".repeat(MAX_REASON_LENGTH),
+            validate_reason("é".repeat(MAX_REASON_LENGTH))
+                .expect("a reason at the maximum length should be valid"),
+            "the reason length should be measured in characters",
+        );
+        for (reason, expected_message) in [
+            (
+                "a".repeat(MAX_REASON_LENGTH + 1).as_str(),
+                format!("reason must be at most {MAX_REASON_LENGTH} characters long"),
+            ),
+            (
+                "offboarded\u{1b}[2J",
+                "reason must not contain control characters".to_string(),
+            ),
+        ] {
+            match validate_reason(reason.to_string()) {
+                Err(ContractError::InvalidFormatError { message }) => assert_eq!(
+                    expected_message, message,
+                    "unexpected error message for reason [{reason}]",
+                ),
+                result => panic!("unexpected result for reason [{reason}]: {result:?}"),
+            }
+        }
+    }
+}